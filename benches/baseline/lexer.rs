@@ -0,0 +1,1263 @@
+//benches/baseline/lexer.rs
+//
+// Copie figee du lexer d'avant l'interner (HashMap de mots-cles, `Peekable<Chars>`,
+// une `String` par identifiant). Sert uniquement de reference au bench `lexer`.
+
+use punk::lexer_error::{LexerError, LexerErrorType, Position};
+use punk::tok::{Delimiters, Keywords, NumberSuffix, Operators, StringKind, TokenType};
+use punk::parser::ast::{FileId, Span};
+use punk::lexer::fstring::split_format_string;
+use punk::lexer::indentation::{IndentationLevel, DEFAULT_TAB_WIDTH};
+use punk::lexer::unicode::{find_confusable, is_identifier_continue, is_identifier_start, normalize_identifier};
+use punk::SyntaxMode;
+use num_bigint::BigInt;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Structure Token,
+/// elle contient le text du token, le type du token, la ligne et la colonne
+/// ainsi que le span (offsets en octets) couvert par le token
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub text: String,
+    pub token_type: TokenType,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    pub(crate) span: Span,
+}
+
+/// Implementation de la structure Token
+#[allow(dead_code)]
+impl Token {
+    pub fn new(text: String, token_type: TokenType, line: usize, column: usize) -> Self {
+        Token {
+            text,
+            token_type,
+            line,
+            column,
+            span: Span::dummy(),
+        }
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// structure Lexer
+#[allow(dead_code)]
+pub struct Lexer<'a> {
+    code_source: &'a str,
+    source: Peekable<Chars<'a>>,
+    current_char: char,
+    keywords: HashMap<String, Keywords>,
+    operators: HashMap<String, Operators>,
+    delimiters: HashMap<String, Delimiters>,
+    current_line: usize,
+    current_column: usize,
+    current_token_text: String,
+    syntax_mode: SyntaxMode,
+    indent_level: Vec<IndentationLevel>,
+    pending_dedents: usize,     // DEDENT restant a emettre quand une ligne ferme plusieurs blocs
+    tab_width: usize,
+    indent_unit: Option<usize>, // largeur du premier bloc, les suivants doivent en etre des multiples
+    at_line_start: bool,
+    current_offset: usize,
+    token_start: (usize, usize, usize), // (offset, ligne, colonne) du debut du token courant
+    file_id: FileId,
+    warnings: Vec<LexerError>, // avertissements non bloquants (caracteres confusables)
+    nesting: Vec<(char, Position)>, // parentheses, crochets et accolades ouverts avec leur position
+}
+
+/// Implementation du lexer avec tous les methodes pour classer les tokens
+#[allow(dead_code)]
+impl<'a> Lexer<'a> {
+    /// Creation d'une nouvelle instance de lexer
+    pub fn new(code_source: &'a str, syntax_mode: SyntaxMode) -> Self {
+        let lexer = Lexer {
+            code_source,
+            source: code_source.chars().peekable(),
+            current_char: '\0',
+            keywords: Self::keywords(),
+            operators: Self::operators(),
+            delimiters: Self::delimiters(),
+            current_line: 1,
+            current_column: 1,
+            current_token_text: String::new(),
+            syntax_mode,
+            indent_level: vec![IndentationLevel::default()],
+            pending_dedents: 0,
+            tab_width: DEFAULT_TAB_WIDTH,
+            indent_unit: None,
+            at_line_start: true,
+            current_offset: 0,
+            token_start: (0, 1, 1),
+            file_id: FileId::default(),
+            warnings: Vec::new(),
+            nesting: Vec::new(),
+        };
+        lexer
+    }
+
+    /// Mode syntaxique utilise par le lexer (a transmettre au parser)
+    pub fn syntax_mode(&self) -> SyntaxMode {
+        self.syntax_mode
+    }
+
+    /// Avertissements emis pendant la lecture (ne produisent pas de token ERROR)
+    pub fn warnings(&self) -> &[LexerError] {
+        &self.warnings
+    }
+
+    /// Largeur d'une tabulation pour la verification de l'unite d'indentation (4 par defaut)
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
+    /// Associe le lexer a un fichier source (utilise dans les spans des tokens)
+    pub fn with_file_id(mut self, file_id: FileId) -> Self {
+        self.file_id = file_id;
+        self
+    }
+
+    /// Memorise la position du debut du prochain token
+    fn mark_token_start(&mut self) {
+        self.token_start = (self.current_offset, self.current_line, self.current_column);
+    }
+
+    /// Span du token courant, du debut marque jusqu'a la position actuelle
+    fn current_span(&self) -> Span {
+        let (start, line, column) = self.token_start;
+        Span::new(self.file_id, start, self.current_offset, line, column)
+    }
+
+    /// La Logique de la Methode pour compter l'indentation a ete trasferer dans la methode get_token()
+    /// Methode pour compter l'indentation
+
+    pub fn count_indentation(&mut self) -> IndentationLevel {
+        let mut level = IndentationLevel::default();
+        while let Some(&ch) = self.source.peek() {
+            match ch {
+                ' ' => level.spaces += 1,
+                '\t' => level.tabs += 1,
+                _ => break,
+            }
+            self.advance();
+        }
+        level
+    }
+
+    /// Compare l'indentation d'une nouvelle ligne au bloc courant.
+    /// Retourne INDENT, le premier DEDENT (les suivants sont mis en attente) ou une erreur.
+    fn indentation_token(&mut self, level: IndentationLevel) -> Option<TokenType> {
+        let position = self.position();
+        let current = *self.indent_level.last().unwrap_or(&IndentationLevel::default());
+
+        match level.compare_strict(&current) {
+            None => Some(TokenType::ERROR(LexerError::tab_error(position))),
+            Some(Ordering::Equal) => None,
+            Some(Ordering::Greater) => {
+                let step = level.width(self.tab_width) - current.width(self.tab_width);
+                let unit = *self.indent_unit.get_or_insert(step);
+                if !step.is_multiple_of(unit) {
+                    let message = format!("indentation step of {} columns is not a multiple of the file's indent unit ({})", step, unit);
+                    return Some(TokenType::ERROR(LexerError::new(LexerErrorType::InvaliIndentation, message, position)));
+                }
+                self.indent_level.push(level);
+                Some(TokenType::INDENT)
+            }
+            Some(Ordering::Less) => {
+                let mut dedents = 0;
+                while self.indent_level.len() > 1 {
+                    let top = self.indent_level[self.indent_level.len() - 1];
+                    match level.compare_strict(&top) {
+                        Some(Ordering::Less) => {
+                            self.indent_level.pop();
+                            dedents += 1;
+                        }
+                        Some(_) => break,
+                        None => return Some(TokenType::ERROR(LexerError::tab_error(position))),
+                    }
+                }
+                if self.indent_level.last() != Some(&level) {
+                    // Le retrait ne retombe sur aucun bloc ouvert
+                    let message = format!("unindent to column {} does not match any outer indentation level", level.width(self.tab_width) + 1);
+                    return Some(TokenType::ERROR(LexerError::new(LexerErrorType::InvaliIndentation, message, position)));
+                }
+                self.pending_dedents = dedents - 1;
+                Some(TokenType::DEDENT)
+            }
+        }
+    }
+
+    /// Creation d'une hashmap pour les mots cles
+    /// c'est plus facile de les stocker les mots cles dans une hashmap pour les retrouver plus facilement
+    fn keywords() -> HashMap<String, Keywords> {
+        let mut keywords = HashMap::new();
+        keywords.insert("and".to_string(), Keywords::AND);
+        keywords.insert("as".to_string(), Keywords::AS);
+        keywords.insert("async".to_string(), Keywords::ASYNC);
+        keywords.insert("await".to_string(), Keywords::AWAIT);
+        keywords.insert("break".to_string(), Keywords::BREAK);
+        keywords.insert("const".to_string(), Keywords::CONST);
+        keywords.insert("continue".to_string(), Keywords::CONTINUE);
+        keywords.insert("class".to_string(), Keywords::CLASS);
+        keywords.insert("def".to_string(), Keywords::DEF);
+        keywords.insert("del".to_string(), Keywords::DEL);
+        keywords.insert("elif".to_string(), Keywords::ELIF);
+        keywords.insert("else".to_string(), Keywords::ELSE);
+        keywords.insert("enum".to_string(), Keywords::ENUM);
+        keywords.insert("except".to_string(), Keywords::EXCEPT);
+        keywords.insert("false".to_string(), Keywords::FALSE);
+        keywords.insert("finally".to_string(), Keywords::FINALLY);
+        keywords.insert("fn".to_string(), Keywords::FN);
+        keywords.insert("for".to_string(), Keywords::FOR);
+        keywords.insert("from".to_string(), Keywords::FROM);
+        keywords.insert("if".to_string(), Keywords::IF);
+        keywords.insert("impl".to_string(), Keywords::IMPL);
+        keywords.insert("import".to_string(), Keywords::IMPORT);
+        keywords.insert("in".to_string(), Keywords::IN);
+        keywords.insert("is".to_string(), Keywords::IS);
+        keywords.insert("lambda".to_string(), Keywords::LAMBDA);
+        keywords.insert("let".to_string(), Keywords::LET);
+        keywords.insert("loop".to_string(), Keywords::LOOP);
+        keywords.insert("match".to_string(), Keywords::MATCH);
+        keywords.insert("mod".to_string(), Keywords::MOD);
+        keywords.insert("mut".to_string(), Keywords::MUT);
+        keywords.insert("none".to_string(), Keywords::NONE);
+        keywords.insert("not".to_string(), Keywords::NOT);
+        keywords.insert("or".to_string(), Keywords::OR);
+        keywords.insert("pub".to_string(), Keywords::PUB);
+        keywords.insert("pass".to_string(), Keywords::PASS);
+        keywords.insert("raise".to_string(), Keywords::RAISE);
+        keywords.insert("return".to_string(), Keywords::RETURN);
+        keywords.insert("self".to_string(), Keywords::SELF);
+        keywords.insert("static".to_string(), Keywords::STATIC);
+        keywords.insert("struct".to_string(), Keywords::STRUCT);
+        keywords.insert("super".to_string(), Keywords::SUPER);
+        keywords.insert("trait".to_string(), Keywords::TRAIT);
+        keywords.insert("true".to_string(), Keywords::TRUE);
+        keywords.insert("try".to_string(), Keywords::TRY);
+        keywords.insert("type".to_string(), Keywords::TYPE);
+        keywords.insert("typeof".to_string(), Keywords::TYPEOF);
+        keywords.insert("use".to_string(), Keywords::USE);
+        keywords.insert("with".to_string(), Keywords::WITH);
+        keywords.insert("where".to_string(), Keywords::WHERE);
+        keywords.insert("while".to_string(), Keywords::WHILE);
+        keywords.insert("yield".to_string(), Keywords::YIELD);
+        //TYPE KEYWORDS
+        keywords.insert("int".to_string(), Keywords::INT);
+        keywords.insert("float".to_string(), Keywords::FLOAT);
+        keywords.insert("str".to_string(), Keywords::STR);
+        keywords.insert("bool".to_string(), Keywords::BOOL);
+        keywords.insert("char".to_string(), Keywords::CHAR);
+
+        return keywords;
+    }
+    /// creation d'une hashmap pour les operateurs
+    fn operators() -> HashMap<String, Operators> {
+        let mut operators = HashMap::new();
+        operators.insert("+".to_string(), Operators::PLUS);
+        operators.insert("-".to_string(), Operators::MINUS);
+        operators.insert("*".to_string(), Operators::STAR);
+        operators.insert("/".to_string(), Operators::SLASH);
+        operators.insert("%".to_string(), Operators::PERCENT);
+        operators.insert("==".to_string(), Operators::EQEQUAL);
+        operators.insert("!=".to_string(), Operators::NOTEQUAL);
+        operators.insert("<".to_string(), Operators::LESS);
+        operators.insert(">".to_string(), Operators::GREATER);
+        operators.insert("<=".to_string(), Operators::LESSEQUAL);
+        operators.insert(">=".to_string(), Operators::GREATEREQUAL);
+        operators.insert("=".to_string(), Operators::EQUAL);
+        operators.insert("++".to_string(), Operators::PLUSEQUAL);
+        operators.insert("--".to_string(), Operators::MINEQUAL);
+        operators.insert("**".to_string(), Operators::DOUBLESTAR);
+        //operators.insert("//".to_string(), Operators::DOUBLESLASH);
+        operators.insert("&&".to_string(), Operators::AND);
+        operators.insert("||".to_string(), Operators::OR);
+        operators.insert("!".to_string(), Operators::EXCLAMATION);
+        operators.insert("&".to_string(), Operators::AMPER);
+        operators.insert("|".to_string(), Operators::VBAR);
+        operators.insert("^".to_string(), Operators::CIRCUMFLEX);
+        operators.insert("<<".to_string(), Operators::LEFTSHIFT);
+        operators.insert(">>".to_string(), Operators::RIGHTSHIFT);
+        operators.insert("~".to_string(), Operators::TILDE);
+        operators.insert("+=".to_string(), Operators::PLUSEQUAL);
+        operators.insert("-=".to_string(), Operators::MINEQUAL);
+        operators.insert("*=".to_string(), Operators::STAREQUAL);
+        operators.insert("/=".to_string(), Operators::SLASHEQUAL);
+        operators.insert("%=".to_string(), Operators::PERCENTEQUAL);
+        operators.insert("&=".to_string(), Operators::AMPEREQUAL);
+        operators.insert("|=".to_string(), Operators::VBAREQUAL);
+        operators.insert("^=".to_string(), Operators::CIRCUMFLEXEQUAL);
+        operators.insert("<<=".to_string(), Operators::LEFTSHIFTEQUAL);
+        operators.insert(">>=".to_string(), Operators::RIGHTSHIFTEQUAL);
+        operators.insert("**=".to_string(), Operators::DOUBLESTAREQUAL);
+        operators.insert("//=".to_string(), Operators::DOUBLESLASHEQUAL);
+        operators.insert("@".to_string(), Operators::AT);
+        operators.insert("@=".to_string(), Operators::ATEQUAL);
+        operators.insert("->".to_string(), Operators::RARROW);
+        operators.insert(":=".to_string(), Operators::COLONEQUAL);
+        operators.insert("*/".to_string(), Operators::STARSLASH);
+        operators.insert("/*".to_string(), Operators::SLASHSTAR);
+        operators.insert("#".to_string(), Operators::DIESE);
+        operators.insert("?".to_string(), Operators::INTERROGATION);
+        operators.insert("_".to_string(), Operators::UNDERSCORE);
+        operators.insert("=>".to_string(), Operators::FATARROW);
+        operators.insert("..".to_string(), Operators::DOTDOT);
+        operators.insert("..=".to_string(), Operators::DOTDOTEQUAL);
+
+
+        return operators;
+    }
+
+    /// Creation d'une hashmap pour les delimiters
+    fn delimiters() -> HashMap<String, Delimiters> {
+        let mut delimiters = HashMap::new();
+        delimiters.insert("(".to_string(), Delimiters::LPAR);
+        delimiters.insert(")".to_string(), Delimiters::RPAR);
+        delimiters.insert("{".to_string(), Delimiters::LCURBRACE);
+        delimiters.insert("}".to_string(), Delimiters::RCURBRACE);
+        delimiters.insert("]".to_string(), Delimiters::RSBRACKET);
+        delimiters.insert("[".to_string(), Delimiters::LSBRACKET);
+        delimiters.insert(";".to_string(), Delimiters::SEMICOLON);
+        delimiters.insert(":".to_string(), Delimiters::COLON);
+        delimiters.insert(",".to_string(), Delimiters::COMMA);
+        delimiters.insert(".".to_string(), Delimiters::DOT);
+        delimiters.insert("...".to_string(), Delimiters::ELLIPSIS);
+        delimiters.insert("::".to_string(), Delimiters::DOUBLECOLON);
+
+        return delimiters;
+    }
+
+    /// Methode pour avancer d'un caractere
+    #[allow(dead_code)]
+    fn next_char(&mut self) -> Option<char> {
+        let ch = self.source.next()?;
+        self.current_char = ch;
+        self.current_offset += ch.len_utf8();
+        if ch == '\n' {
+            self.current_line += 1;
+            self.current_column = 1;
+            self.at_line_start = true;
+        } else {
+            self.current_column += 1;
+            self.at_line_start = false;
+        }
+        Some(ch)
+    }
+
+    /// Methode pour regarder le prochain caractere sans avancer
+    #[allow(dead_code)]
+    fn peek_char(&mut self) -> Option<char> {
+        self.source.peek().copied()
+    }
+    /// Methode pour regarder le 2eme prochain caractere sans avancer
+    #[allow(dead_code)]
+    fn peek_next_char(&mut self) -> Option<char> {
+        self.source.clone().nth(1)
+    }
+
+    fn peek_next(&mut self) -> Option<char> {
+     let mut iter = self.source.clone();
+        iter.next();
+        iter.peek().copied()
+    }
+
+    /// C'est L'une de 2 methode principal avec fonction tokenize() pour obtenir le token
+    /// son role c'est de sauter les espaces et examiner le prochain caractère
+    /// Détermine le type de token en fonction de ce caractère.
+    /// Appelle la méthode appropriée (comme lex_number(), lex_identifier_or_keyword(), etc.) pour analyser le token complet.
+    /// Renvoie une Option<TokenType> représentant un seul token.
+
+    /// methode pour obtenir le token
+
+    pub fn get_token(&mut self) -> Option<TokenType> {
+        self.mark_token_start();
+        if self.pending_dedents > 0 {
+            self.pending_dedents -= 1;
+            return Some(TokenType::DEDENT);
+        }
+        //Gérer l'indentation au début d'une nouvelle ligne
+        if self.at_line_start && self.syntax_mode == SyntaxMode::Indentation {
+            self.at_line_start = false;
+            let current_indent = self.count_indentation();
+            // Une ligne vide ne change pas le niveau d'indentation
+            if !matches!(self.peek_char(), Some('\n') | Some('\r') | None) {
+                if let Some(token) = self.indentation_token(current_indent) {
+                    return Some(token);
+                }
+            }
+            // Si l'indentation est la même, on ne fait rien de spécial
+        }
+
+        // if self.at_line_start && self.syntax_mode == SyntaxMode::Indentation {
+        //     self.at_line_start = false;
+        //     let current_indent = self.count_indentation();
+        //     let previous_indent = *self.indent_level.last().unwrap_or(&0);
+        //
+        //     if current_indent > previous_indent {
+        //         self.indent_level.push(current_indent);
+        //         return Some(TokenType::INDENT);
+        //     } else if current_indent < previous_indent {
+        //         // Stocker le niveau actuel pour le comparer après le pop
+        //         let current = current_indent;
+        //         if let Some(last_level) = self.indent_level.pop() {
+        //             if current < *self.indent_level.last().unwrap_or(&0) {
+        //                 // Remettre le niveau qu'on vient de retirer pour le prochain appel
+        //                 self.indent_level.push(last_level);
+        //             }
+        //             return Some(TokenType::DEDENT);
+        //         }
+        //     }
+        // }
+
+        self.skip_whitespace(); // Sauter les espaces et tabulations
+        self.mark_token_start();
+
+        // Vérifier le prochain caractère
+        match self.peek_char() {
+            Some('\n') => {
+                self.advance(); // Consomme le '\n'
+                // Entre parentheses, crochets ou accolades la ligne continue: ni NEWLINE ni indentation
+                if self.syntax_mode == SyntaxMode::Indentation && !self.nesting.is_empty() {
+                    return self.get_token();
+                }
+                self.at_line_start = true;
+
+
+            //    return Some(TokenType::NEWLINE);
+                // retourn NEWLINE  seulement en  mode Indentation
+                if self.syntax_mode == SyntaxMode::Indentation {
+                    return Some(TokenType::NEWLINE);
+                }else {
+                    // en mode Brace, on ignore le newline et passe au token suivant
+                    //self.current_line += 1; // Incrementer la ligne  mais a teste plus tard
+                    return self.get_token();
+
+                }
+            }
+
+            Some('0'..='9') => Some(self.lex_number()),
+            Some(_) if self.string_prefix().is_some() => {
+                let (prefix, length) = self.string_prefix().unwrap();
+                for _ in 0..length {
+                    self.advance(); // Consomme le prefixe r, b ou f
+                }
+                Some(self.lex_string_with_prefix(prefix))
+            }
+            Some(ch) if is_identifier_start(ch) => Some(self.lex_identifier_or_keyword()),
+            Some('"') | Some('\'') => Some(self.lex_string()),
+            Some('#') => Some(self.lex_comment()),
+            Some('/') => {
+                if let Some(next_char) = self.peek_next_char() {
+                    match next_char {
+                        '/' | '*' => Some(self.lex_comment()),
+                        _ => self.lex_operator(),
+                    }
+                } else {
+                    self.lex_operator()
+                }
+            }
+            // `\` en fin de ligne: continuation explicite
+            Some('\\') if matches!(self.peek_next(), Some('\n' | '\r')) => {
+                self.advance(); // Consomme le '\'
+                if self.peek_char() == Some('\r') {
+                    self.advance();
+                }
+                if self.peek_char() == Some('\n') {
+                    self.advance();
+                }
+                self.get_token()
+            }
+            Some(ch) if self.delimiters.contains_key(&ch.to_string()) => {
+                let position = self.position();
+                let delimiter = self.lex_delimiter();
+                self.track_nesting(&delimiter, position);
+                Some(delimiter)
+            }
+            Some(ch) if !ch.is_alphanumeric() => self.lex_operator(),
+            None => {
+                ////////////à surveiller si c'est correct et pas redondant
+                // Fin du fichier (EOF)
+                if self.syntax_mode == SyntaxMode::Indentation {
+                    if let Some((bracket, position)) = self.nesting.pop() {
+                        // Le plus interne est signale, les autres ne produiraient que du bruit
+                        self.nesting.clear();
+                        return Some(TokenType::ERROR(LexerError::unterminated_bracket(bracket, position)));
+                    }
+                }
+                if self.indent_level.len() > 1 {
+                    self.indent_level.pop();
+                    return Some(TokenType::DEDENT);
+                } else {
+                    return Some(TokenType::EOF);
+                }
+            }
+            // None => {
+            //     if !self.indent_level.is_empty() && self.indent_level.len() > 1 {
+            //         self.indent_level.pop();
+            //         Some(TokenType::DEDENT)
+            //     } else if self.indent_level.len() == 1 {
+            //         // Dernier niveau d'indentation
+            //         self.indent_level.pop();
+            //         Some(TokenType::DEDENT)
+            //     } else {
+            //         Some(TokenType::EOF)
+            //     }
+            // }
+
+            ////////////à surveiller si c'est correct et pas redondant
+
+            // None => Some(TokenType::EOF),   //Ajouter nouvelement
+            Some(ch) => {
+                // Caractere alphanumerique qui ne peut pas commencer un identifiant (`²`, `٣`, ...)
+                self.advance();
+                self.current_token_text = ch.to_string();
+                Some(TokenType::ERROR(LexerError::invalid_character(ch, self.position())))
+            }
+        }
+    }
+
+    /// Methode pour les litteraux numeriques:
+    /// `123`, `1_000`, `0x1F`, `0b1010`, `0o17`, `3.14`, `1e-9`, `2.5e3` et les suffixes `10i32`, `2.5f32`
+    fn lex_number(&mut self) -> TokenType {
+        self.current_token_text.clear();
+
+        let radix = match (self.peek_char(), self.peek_next()) {
+            (Some('0'), Some('x' | 'X')) => 16,
+            (Some('0'), Some('b' | 'B')) => 2,
+            (Some('0'), Some('o' | 'O')) => 8,
+            _ => 10,
+        };
+        if radix != 10 {
+            let zero = self.advance();
+            let prefix = self.advance();
+            self.current_token_text.push(zero);
+            self.current_token_text.push(prefix);
+        }
+
+        let mut error: Option<LexerError> = None;
+        let mut digits = match self.lex_digits(radix, radix != 10) {
+            Ok(digits) => digits,
+            Err(e) => {
+                error.get_or_insert(e);
+                String::new()
+            }
+        };
+        if digits.is_empty() && error.is_none() {
+            let text = self.current_token_text.clone();
+            return match radix {
+                16 => self.create_error(LexerErrorType::InvalidHexadecimal(text)),
+                _ => self.create_error(LexerErrorType::InvalidInteger(text)),
+            };
+        }
+
+        // Partie decimale: le '.' doit etre suivi d'un chiffre (sinon `1..2` ou `x.0.1`)
+        let mut is_float = false;
+        if radix == 10 && self.peek_char() == Some('.') && self.peek_next().is_some_and(|c| c.is_ascii_digit()) {
+            let dot = self.advance();
+            self.current_token_text.push(dot);
+            digits.push('.');
+            is_float = true;
+            match self.lex_digits(10, false) {
+                Ok(fraction) => digits.push_str(&fraction),
+                Err(e) => { error.get_or_insert(e); }
+            }
+        }
+
+        // Exposant: e/E, signe optionnel, au moins un chiffre
+        if radix == 10 && matches!(self.peek_char(), Some('e' | 'E')) {
+            let after = self.peek_next();
+            let signed = matches!(after, Some('+' | '-'));
+            let exponent_digit = if signed { self.source.clone().nth(2) } else { after };
+            if exponent_digit.is_some_and(|c| c.is_ascii_digit()) {
+                let e = self.advance();
+                self.current_token_text.push(e);
+                digits.push('e');
+                if signed {
+                    let sign = self.advance();
+                    self.current_token_text.push(sign);
+                    digits.push(sign);
+                }
+                is_float = true;
+                match self.lex_digits(10, false) {
+                    Ok(exponent) => digits.push_str(&exponent),
+                    Err(e) => { error.get_or_insert(e); }
+                }
+            } else {
+                let position = self.position();
+                let e = self.advance();
+                self.current_token_text.push(e);
+                let error_type = LexerErrorType::InvalidFloat(self.current_token_text.clone());
+                error.get_or_insert(LexerError::new(error_type, "missing exponent digits".to_string(), position));
+            }
+        }
+
+        // Suffixe de type, ou `j` pour un imaginaire
+        let mut suffix = None;
+        let mut is_imaginary = false;
+        if self.peek_char().is_some_and(|c| c.is_alphabetic() || c == '_') {
+            let position = self.position();
+            let mut text = String::new();
+            while let Some(c) = self.peek_char().filter(|c| c.is_alphanumeric() || *c == '_') {
+                self.advance();
+                text.push(c);
+            }
+            self.current_token_text.push_str(&text);
+            match NumberSuffix::from_text(&text) {
+                None if matches!(text.as_str(), "j" | "J") && radix != 10 => {
+                    error.get_or_insert(LexerError::new(
+                        LexerErrorType::InvalidNumberSuffix(text.clone()),
+                        format!("imaginary suffix '{}' is not allowed on a base {} literal", text, radix),
+                        position,
+                    ));
+                }
+                None if matches!(text.as_str(), "j" | "J") => is_imaginary = true,
+                Some(s) if s.is_float() && (radix != 10) => {
+                    error.get_or_insert(LexerError::new(
+                        LexerErrorType::InvalidNumberSuffix(text.clone()),
+                        format!("suffix '{}' is not allowed on a base {} literal", text, radix),
+                        position,
+                    ));
+                }
+                Some(s) if !s.is_float() && is_float => {
+                    error.get_or_insert(LexerError::new(
+                        LexerErrorType::InvalidNumberSuffix(text.clone()),
+                        format!("integer suffix '{}' on a float literal", text),
+                        position,
+                    ));
+                }
+                Some(s) => {
+                    is_float |= s.is_float();
+                    suffix = Some(s);
+                }
+                None => {
+                    let error_type = LexerErrorType::InvalidNumberSuffix(text.clone());
+                    error.get_or_insert(LexerError::new(error_type.clone(), error_type.to_string(), position));
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            return TokenType::ERROR(error);
+        }
+
+        if is_imaginary {
+            return match digits.parse::<f64>() {
+                Ok(value) => TokenType::IMAGINARY { value },
+                Err(_) => self.create_error(LexerErrorType::InvalidFloat(self.current_token_text.clone())),
+            };
+        }
+        if is_float {
+            return match digits.parse::<f64>() {
+                Ok(value) => TokenType::FLOAT { value, suffix },
+                Err(_) => self.create_error(LexerErrorType::InvalidFloat(self.current_token_text.clone())),
+            };
+        }
+        // Entier de taille arbitraire, le debordement est verifie par le type checker
+        match BigInt::parse_bytes(digits.as_bytes(), radix) {
+            Some(value) => TokenType::INTEGER { value, suffix },
+            None => self.create_error(LexerErrorType::InvalidInteger(digits)),
+        }
+    }
+
+    /// Lit une suite de chiffres dans la base donnee, avec `_` comme separateur.
+    /// Un `_` doit etre entre deux chiffres (ou juste apres le prefixe 0x/0b/0o, ou avant un suffixe).
+    /// Un chiffre decimal hors de la base (`0b102`) est une erreur
+    fn lex_digits(&mut self, radix: u32, after_prefix: bool) -> Result<String, LexerError> {
+        let mut digits = String::new();
+        let mut error = None;
+        let mut previous_separator = false;
+        let mut first = true;
+
+        loop {
+            let position = self.position();
+            match self.peek_char() {
+                Some('_') => {
+                    self.advance();
+                    self.current_token_text.push('_');
+                    if previous_separator || (first && !after_prefix) {
+                        error.get_or_insert(LexerError::new(
+                            LexerErrorType::InvalidNumberSeparator(self.current_token_text.clone()),
+                            "'_' must separate two digits".to_string(),
+                            position,
+                        ));
+                    }
+                    previous_separator = true;
+                }
+                Some(c) if c.is_digit(radix) || (radix < 10 && c.is_ascii_digit()) => {
+                    self.advance();
+                    self.current_token_text.push(c);
+                    if !c.is_digit(radix) {
+                        error.get_or_insert(LexerError::new(
+                            LexerErrorType::InvalidDigit { digit: c, radix },
+                            format!("invalid digit '{}' in base {} literal", c, radix),
+                            position,
+                        ));
+                    }
+                    digits.push(c);
+                    previous_separator = false;
+                }
+                _ => break,
+            }
+            first = false;
+        }
+
+        // `0xFF_u8`: un `_` peut preceder le suffixe
+        if previous_separator && !self.peek_char().is_some_and(|c| c.is_alphabetic()) {
+            error.get_or_insert(LexerError::new(
+                LexerErrorType::InvalidNumberSeparator(self.current_token_text.clone()),
+                "'_' must separate two digits".to_string(),
+                self.position(),
+            ));
+        }
+        match error {
+            Some(error) => Err(error),
+            None => Ok(digits),
+        }
+    }
+
+    //fn lex_identifier(){}
+    /// Methode pour les different types de token de Type Identifier ou Keyword
+    fn lex_identifier_or_keyword(&mut self) -> TokenType {
+        self.current_token_text.clear();
+        let position = self.position();
+        while let Some(&ch) = self.source.peek() {
+            if is_identifier_continue(ch) {
+                let ch = self.advance();
+                self.current_token_text.push(ch); // Ajoute le caractère à la chaîne de texte du token
+            } else {
+                break;
+            }
+        }
+
+        // Le texte du token reste celui du source, le nom est compare sous forme NFC
+        let name = normalize_identifier(&self.current_token_text);
+        if let Some((found, expected)) = find_confusable(&name) {
+            let warning = LexerErrorType::ConfusableCharacter { found, expected };
+            let message = format!("identifier '{}': {}", name, warning);
+            self.warnings.push(LexerError::new(warning, message, position));
+        }
+
+        if let Some(keyword) = self.keywords.get(&name) {
+            TokenType::KEYWORD(keyword.clone()) // c'est un mot clé
+        } else {
+            TokenType::IDENTIFIER { name: name.into() } // sinon c'est un identifiant
+        }
+    }
+
+    fn lex_string(&mut self) -> TokenType {
+        self.lex_string_with_prefix(StringPrefix::default())
+    }
+
+    /// Regarde si le token courant est une chaine prefixee (`r"`, `b"`, `f"`, `rb"`, `fr"`, ...)
+    /// et retourne le prefixe avec sa longueur
+    fn string_prefix(&self) -> Option<(StringPrefix, usize)> {
+        let mut prefix = StringPrefix::default();
+        for (index, ch) in self.source.clone().enumerate() {
+            let flag = match ch {
+                '"' | '\'' if index > 0 => return Some((prefix, index)),
+                'r' | 'R' => &mut prefix.raw,
+                'b' | 'B' => &mut prefix.bytes,
+                'f' | 'F' => &mut prefix.formatted,
+                _ => return None,
+            };
+            if *flag || index >= 2 {
+                return None;
+            }
+            *flag = true;
+            if prefix.bytes && prefix.formatted {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Lit une chaine `"..."`, `'...'` ou `"""..."""` avec son prefixe eventuel.
+    /// Les echappements sont verifies pendant la lecture, puis appliques apres
+    /// le retrait de l'indentation des chaines sur plusieurs lignes.
+    fn lex_string_with_prefix(&mut self, prefix: StringPrefix) -> TokenType {
+        self.current_token_text.clear();
+
+        let quote = self.advance(); // Consomme le premier guillemet
+        let triple = self.peek_char() == Some(quote) && self.peek_next_char() == Some(quote);
+        if triple {
+            self.advance();
+            self.advance();
+        }
+
+        // La premiere erreur est retournee une fois la chaine entierement lue
+        let mut content = String::new();
+        let mut error: Option<LexerError> = None;
+        loop {
+            let Some(ch) = self.peek_char() else {
+                // Si nous sortons de la boucle sans avoir trouvé de guillemet fermant
+                return self.create_error(LexerErrorType::UnterminatedString);
+            };
+            let position = Position { line: self.current_line, column: self.current_column };
+            self.advance();
+
+            if ch == '\\' {
+                content.push(ch);
+                if prefix.raw {
+                    // `\"` ne termine pas une chaine brute, les deux caracteres sont gardes
+                    if let Some(next) = self.peek_char().filter(|&c| c == quote || c == '\\') {
+                        self.advance();
+                        content.push(next);
+                    }
+                } else if let Err(escape_error) = self.lex_escape(prefix.bytes, &mut content) {
+                    let message = escape_error.to_string();
+                    error.get_or_insert(LexerError::new(escape_error, message, position));
+                }
+            } else if ch == quote && !triple {
+                break;
+            } else if ch == quote && self.peek_char() == Some(quote) && self.peek_next_char() == Some(quote) {
+                self.advance();
+                self.advance();
+                break;
+            } else if prefix.bytes && !ch.is_ascii() {
+                error.get_or_insert(LexerError::invalid_character(ch, position));
+            } else {
+                content.push(ch);
+            }
+        }
+
+        if let Some(error) = error {
+            return TokenType::ERROR(error);
+        }
+        if triple {
+            content = strip_indentation(&content);
+        }
+        let value = if prefix.raw { content } else { unescape(&content) };
+        self.current_token_text = value.clone();
+
+        if prefix.bytes {
+            // Les caracteres sont ASCII ou viennent d'un `\xHH`, ils tiennent sur un octet
+            return TokenType::BYTES { value: value.chars().map(|c| c as u8).collect() };
+        }
+        if prefix.formatted {
+            let (_, line, column) = self.token_start;
+            return match split_format_string(&value, Position { line, column }, false) {
+                Ok(parts) => TokenType::STRING { value, kind: StringKind::FORMATTED(parts) },
+                Err(error) => TokenType::ERROR(error),
+            };
+        }
+        if prefix.raw {
+            return TokenType::STRING { value, kind: StringKind::RAW };
+        }
+        //But: 'C'->Char, "C"->String
+        if quote == '\'' && !triple && value.chars().count() == 1 {
+            return TokenType::CHAR {
+                value: value.chars().next().unwrap()
+            };
+        }
+        TokenType::STRING {
+            value,
+            kind: StringKind::NORMAL,
+        }
+    }
+
+    /// Verifie la sequence d'echappement qui suit un `\` et la recopie telle quelle dans `content`.
+    /// Echappements acceptes: \n \t \r \0 \\ \" \' \xHH \u{H...} et `\` en fin de ligne
+    fn lex_escape(&mut self, bytes: bool, content: &mut String) -> Result<(), LexerErrorType> {
+        let Some(ch) = self.peek_char() else {
+            return Err(LexerErrorType::InvalidEscape("\\".to_string()));
+        };
+        self.advance();
+        content.push(ch);
+        match ch {
+            'n' | 't' | 'r' | '0' | '\\' | '"' | '\'' | '\n' => Ok(()),
+            'x' => {
+                let mut digits = String::new();
+                while digits.len() < 2 {
+                    match self.peek_char() {
+                        Some(c) if c.is_ascii_hexdigit() => digits.push(self.advance()),
+                        _ => break,
+                    }
+                }
+                content.push_str(&digits);
+                if digits.len() != 2 {
+                    return Err(LexerErrorType::InvalidEscape(format!("\\x{}", digits)));
+                }
+                Ok(())
+            }
+            'u' if !bytes => {
+                let mut sequence = String::from("\\u");
+                if self.peek_char() == Some('{') {
+                    sequence.push(self.advance());
+                    while let Some(c) = self.peek_char().filter(|c| c.is_ascii_hexdigit()) {
+                        self.advance();
+                        sequence.push(c);
+                    }
+                    if self.peek_char() == Some('}') {
+                        sequence.push(self.advance());
+                    }
+                }
+                content.push_str(&sequence[2..]);
+                let digits = sequence.strip_prefix("\\u{").and_then(|s| s.strip_suffix('}'));
+                match digits {
+                    Some(hex) if (1..=6).contains(&hex.len())
+                        && u32::from_str_radix(hex, 16).ok().and_then(char::from_u32).is_some() => Ok(()),
+                    _ => Err(LexerErrorType::InvalidEscape(sequence)),
+                }
+            }
+            _ => Err(LexerErrorType::InvalidEscape(format!("\\{}", ch))),
+        }
+    }
+
+
+    /// Methode pour les differents types de token de Type Operator
+    fn lex_operator(&mut self) -> Option<TokenType> {
+        self.current_token_text.clear();
+
+        // Regardez les deux prochains caractères pour vérifier les opérateurs composés
+        let first_char = self.advance();
+        self.current_token_text.push(first_char);
+        let mut op = self.current_token_text.clone();
+
+        if let Some(&next_char) = self.source.peek() {
+            op.push(next_char);
+            if self.operators.contains_key(&op) {
+                self.advance();
+                self.current_token_text.push(next_char);
+                return Some(TokenType::OPERATOR(self.operators[&op].clone()));
+            }
+        }
+
+        // Si ce n'est pas un opérateur composé, vérifiez l'opérateur simple
+        if let Some(operator) = self.operators.get(&self.current_token_text) {
+            return Some(TokenType::OPERATOR(operator.clone()));
+        }
+
+        // Si l'opérateur n'est pas reconnu, c'est un caractere invalide (`$`, `€`, espace insecable, ...)
+        Some(TokenType::ERROR(LexerError::invalid_character(
+            first_char,
+            Position {
+                line: self.current_line,
+                column: self.current_column,
+            },
+        )))
+    }
+
+
+    /// Met a jour la pile des parentheses, crochets et accolades ouverts
+    fn track_nesting(&mut self, delimiter: &TokenType, position: Position) {
+        let (opening, closing) = match delimiter {
+            TokenType::DELIMITER(Delimiters::LPAR) => (Some('('), None),
+            TokenType::DELIMITER(Delimiters::LSBRACKET) => (Some('['), None),
+            TokenType::DELIMITER(Delimiters::LCURBRACE) => (Some('{'), None),
+            TokenType::DELIMITER(Delimiters::RPAR) => (None, Some('(')),
+            TokenType::DELIMITER(Delimiters::RSBRACKET) => (None, Some('[')),
+            TokenType::DELIMITER(Delimiters::RCURBRACE) => (None, Some('{')),
+            _ => (None, None),
+        };
+        if let Some(bracket) = opening {
+            self.nesting.push((bracket, position));
+        } else if closing.is_some() && self.nesting.last().map(|(bracket, _)| *bracket) == closing {
+            // Une fermeture qui ne correspond pas est laissee au parser
+            self.nesting.pop();
+        }
+    }
+
+    /// Methode pour les differents types de token de Type Delimiter
+    fn lex_delimiter(&mut self) -> TokenType {
+
+        self.current_token_text.clear();
+
+        let first_char = self.advance();
+        self.current_token_text.push(first_char);
+
+        if let Some(&next_char) = self.source.peek(){
+            let mut combined = self.current_token_text.clone();
+            combined.push(next_char);
+
+            // verifie pour "::"
+            if combined == "::"{
+                self.advance();
+                self.current_token_text = combined;
+                return TokenType::DELIMITER(Delimiters::DOUBLECOLON);
+            }
+
+            if first_char == '.' {
+                if let Some(&next_char) = self.source.peek(){
+                    if next_char == '.'{
+                        self.advance();
+                        self.current_token_text.push(next_char);
+
+                        if let Some(&third_char) = self.source.peek(){
+                            if third_char == '.'{
+                                self.advance();
+                                self.current_token_text.push(third_char);
+                                return TokenType::DELIMITER(Delimiters::ELLIPSIS);
+                            }else if third_char == '=' {
+                                self.advance();
+                                self.current_token_text.push(third_char);
+                                return TokenType::OPERATOR(Operators::DOTDOTEQUAL);
+                            }
+                        }
+                        return TokenType::OPERATOR(Operators::DOTDOT);
+                    }else {
+                        return TokenType::DELIMITER(Delimiters::DOT);
+                    }
+                }
+            }
+
+        }
+        if let Some(delimiter) = self.delimiters.get(&self.current_token_text) {
+            return TokenType::DELIMITER(delimiter.clone());
+        } else {
+            return TokenType::UNKNOWN;
+        }
+
+    }
+
+    /// Methode pour les differents types de token de Type Comment # ou // ou /* */
+    fn lex_comment(&mut self) -> TokenType {
+        self.current_token_text.clear();
+        let start_char = self.advance(); // Consomme le '/' ou le '#'
+        let mut comment = String::new();
+
+        match start_char {
+            '#' => {
+                // Commentaire en ligne commençant par '#'
+                while let Some(ch) = self.next_char() {
+                    if ch == '\n' {
+                        break;
+                    }
+                    comment.push(ch);
+                }
+            }
+            '/' => {
+                if let Some(&next_char) = self.source.peek() {
+                    return match next_char {
+                        '/' => {
+                            self.advance(); // Consomme le deuxième '/'
+                            if self.peek_char() == Some('/') {
+                                // C'est un commentaire de type `///`
+                                self.advance(); // Consomme le troisième '/'
+                                while let Some(ch) = self.next_char() {
+                                    if ch == '\n' {
+                                        break;
+                                    }
+                                    comment.push(ch);
+                                }
+                                TokenType::DOCSTRING(comment) // Retourne un DOCSTRING
+                            } else {
+                                // C'est un commentaire normal `//`
+                                while let Some(ch) = self.next_char() {
+                                    if ch == '\n' {
+                                        break;
+                                    }
+                                    comment.push(ch);
+                                }
+                                TokenType::COMMENT(comment)
+                            }
+                        }
+                        '*' => {
+                            // Commentaire multi-lignes
+                            self.advance(); // Consomme le '*'
+                            let mut depth = 1;
+                            while let Some(ch) = self.next_char() {
+                                if ch == '*' && self.peek_char() == Some('/') {
+                                    self.advance(); // Consomme le '/'
+                                    depth -= 1;
+                                    if depth == 0 {
+                                        break;
+                                    }
+                                } else if ch == '/' && self.peek_char() == Some('*') {
+                                    self.advance(); // Consomme le '*'
+                                    depth += 1;
+                                }
+                                comment.push(ch);
+                            }
+                            if depth > 0 {
+                                // Erreur : commentaire multi-lignes non terminé
+                                self.create_error(LexerErrorType::UnterminatedComment)
+                            } else {
+                                TokenType::COMMENT(comment)
+                            }
+                        }
+                        _ => {
+                            // Ce n'est pas un commentaire, c'est probablement un opérateur de division
+                            TokenType::OPERATOR(Operators::SLASH)
+                        }
+                    };
+                }
+            }
+            _ => {
+                // Ce cas ne devrait jamais se produire si la fonction est appelée correctement
+                return TokenType::UNKNOWN;
+            }
+        }
+
+        self.current_token_text = comment.clone();
+        return TokenType::COMMENT(comment);
+    }
+
+    ////////////
+
+    /// Methode pour avancer d'un character
+    fn advance(&mut self) -> char {
+        let ch = self.source.next().unwrap();
+        self.current_offset += ch.len_utf8();
+        if ch == '\n' {
+            self.current_line += 1; // Incrémenter le numéro de ligne
+            self.current_column = 1; // Réinitialiser le numéro de colonne
+        } else {
+            self.current_column += 1; // sinon incrementer le numero de colonne
+        }
+        ch
+    }
+
+    /// Methode pour sauter les espaces
+    fn skip_whitespace(&mut self) {
+        // amelioration pour qu'en  mode brace  le NEWLINE soit ignoré
+        while let Some(&ch) = self.source.peek(){
+            if ch.is_whitespace(){
+                if ch == '\n' {
+                    if self.syntax_mode == SyntaxMode::Braces{
+                        self.advance();
+                        self.at_line_start = true;
+                    }else {
+                        break;
+                    }
+                }else { self.advance();
+                }
+            }else {
+                break;
+            }
+
+        }
+
+    }
+
+    /// C'est la deuxième methode principal avec get_token() pour obtenir les tokens
+    /// Son role c'est de tokeniser le code source
+    /// appel la methode get_token pour obtenir les tokens.
+    /// Elle crée objet Token pour chaque TokenType retourné par get_token()
+    /// elle retourne un vecteur de tokens Vec<Token>
+    /// methode pour tokeniser le code source
+    pub fn tokenize(&mut self) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        while let Some(token_type) = self.get_token() {
+            let token = Token::new(
+                self.current_token_text.clone(),
+                token_type.clone(),
+                self.current_line,
+                self.current_column,
+            ).with_span(self.current_span());
+            tokens.push(token);
+            self.current_token_text.clear();
+            if matches!(token_type, TokenType::EOF) {
+                break;
+            }
+        }
+
+        if self.syntax_mode == SyntaxMode::Indentation {
+            while self.indent_level.len() > 1 {
+                self.indent_level.pop();
+                self.mark_token_start();
+                tokens.push(Token::new(
+                    String::new(),
+                    TokenType::DEDENT,
+                    self.current_line,
+                    self.current_column,
+                ).with_span(self.current_span()));
+            }
+        }
+
+        return tokens;
+    }
+
+    /// Methode pour creer un token de type erreur
+    /// Position courante, utilisee pour situer precisement une erreur
+    fn position(&self) -> Position {
+        Position {
+            line: self.current_line,
+            column: self.current_column,
+        }
+    }
+
+    fn create_error(&self, error: LexerErrorType) -> TokenType {
+        let position = Position {
+            line: self.current_line,
+            column: self.current_column,
+        };
+        TokenType::ERROR(LexerError::new(error.clone(), error.to_string(), position))
+    }
+
+    fn handle_newline(&mut self) -> TokenType {
+        self.advance(); // Consomme le '\n'
+        self.at_line_start = true;
+        TokenType::NEWLINE
+    }
+
+}
+
+/// Prefixe d'une chaine: r (brute), b (octets), f (formatee)
+#[derive(Debug, Default, Clone, Copy)]
+struct StringPrefix {
+    raw: bool,
+    bytes: bool,
+    formatted: bool,
+}
+
+/// Retire l'indentation commune d'une chaine `"""..."""`.
+/// Le saut de ligne qui suit l'ouverture est ignore et les lignes blanches deviennent vides
+fn strip_indentation(content: &str) -> String {
+    let content = content.strip_prefix('\n').unwrap_or(content);
+    let indent_of = |line: &str| line.chars().take_while(|c| *c == ' ' || *c == '\t').count();
+    let common = content.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(indent_of)
+        .min()
+        .unwrap_or(0);
+
+    content.split('\n')
+        .map(|line| if line.trim().is_empty() { "" } else { &line[common..] })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Applique les echappements deja verifies par `Lexer::lex_escape`
+fn unescape(content: &str) -> String {
+    let mut value = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            value.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('t') => value.push('\t'),
+            Some('r') => value.push('\r'),
+            Some('0') => value.push('\0'),
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                value.extend(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32));
+            }
+            Some('u') => {
+                let hex: String = chars.by_ref().skip(1).take_while(|c| *c != '}').collect();
+                value.extend(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32));
+            }
+            Some('\n') => {
+                // Ignorer le saut de ligne après un backslash et l'indentation qui suit
+                while chars.next_if(|c| c.is_whitespace() && *c != '\n').is_some() {}
+            }
+            Some(other) => value.push(other),
+            None => {}
+        }
+    }
+    value
+}
@@ -0,0 +1,84 @@
+//benches/lexer.rs
+//
+// cargo bench --bench lexer
+// Chaque source est lu par le lexer actuel (`current/...`) et par la copie figee
+// de l'ancien lexer (`baseline/...`, voir baseline/lexer.rs), dans le meme groupe.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use punk::{Lexer, SyntaxMode};
+
+#[path = "baseline/lexer.rs"]
+#[allow(dead_code, clippy::all)]
+mod baseline;
+
+/// Fonction type d'un source `.pk` genere, en mode accolades
+const BRACES_UNIT: &str = r#"/// Calcule la moyenne ponderee
+pub fn weighted_{n}(values: [float], weights: [float]) -> float {
+    let mut total = 0.0;
+    let mut count: i64 = 0x1F_u8 as i64;
+    for i in 0..len(values) {
+        total += values[i] * weights[i] ** 2; // carre
+        count = count + 1;
+    }
+    if count == 0 && total != 1e-9 { return 0.0; }
+    let message = f"moyenne {total} sur {count}";
+    print(message, 'c', b"octets\x00", r"brut\n");
+    return total / count;
+}
+
+"#;
+
+/// La meme fonction en mode indentation
+const INDENT_UNIT: &str = r#"## Calcule la moyenne ponderee
+def weighted_{n}(values: [float], weights: [float]) -> float:
+    let mut total = 0.0
+    let mut count: i64 = 0x1F_u8 as i64
+    for i in 0..len(values):
+        total += values[i] * weights[i] ** 2  # carre
+        count = count + 1
+    if count == 0 and total != 1e-9:
+        return 0.0
+    let message = f"moyenne {total} sur {count}"
+    print(message, 'c', b"octets\x00", r"brut\n",
+          (1, 2,
+           3))
+    return total / count
+
+"#;
+
+/// Source d'environ `lines` lignes, chaque fonction a un nom different
+fn generate(unit: &str, lines: usize) -> String {
+    let per_unit = unit.lines().count();
+    (0..lines / per_unit).map(|n| unit.replace("{n}", &n.to_string())).collect()
+}
+
+fn bench_tokenize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tokenize");
+    for lines in [1_000, 20_000] {
+        for (name, unit, mode) in [
+            ("braces", BRACES_UNIT, SyntaxMode::Braces),
+            ("indentation", INDENT_UNIT, SyntaxMode::Indentation),
+        ] {
+            let source = generate(unit, lines);
+            group.throughput(Throughput::Bytes(source.len() as u64));
+            group.bench_with_input(BenchmarkId::new(format!("current/{name}"), lines), &source, |b, source| {
+                b.iter(|| Lexer::new(black_box(source), mode).tokenize())
+            });
+            group.bench_with_input(BenchmarkId::new(format!("baseline/{name}"), lines), &source, |b, source| {
+                b.iter(|| baseline::Lexer::new(black_box(source), mode).tokenize())
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Creation du lexer seule: compte pour les petits fichiers et les tests
+fn bench_new(c: &mut Criterion) {
+    let mut group = c.benchmark_group("new");
+    group.bench_function("current", |b| b.iter(|| Lexer::new(black_box("x"), SyntaxMode::Braces)));
+    group.bench_function("baseline", |b| b.iter(|| baseline::Lexer::new(black_box("x"), SyntaxMode::Braces)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_tokenize, bench_new);
+criterion_main!(benches);
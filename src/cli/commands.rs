@@ -0,0 +1,320 @@
+//src/cli/commands.rs
+
+use crate::cli::format::format_source;
+use crate::cli::json;
+use crate::cli::{Command, Options, OutputFormat, EXIT_ERRORS, EXIT_IO, EXIT_SUCCESS, EXIT_UNSUPPORTED};
+use crate::lexer::indentation::DEFAULT_TAB_WIDTH;
+use crate::lexer::keywords::{detect_keyword_set, translate_keywords, KeywordSet};
+use crate::lexer::lex::{Lexer, SyntaxMode, Token};
+use crate::lexer::syntax_mode::detect_syntax_mode;
+use crate::lexer_error::LexerError;
+use crate::parser::ast::ASTNode;
+use crate::parser::parser::Parser;
+use crate::semantic::analyser::SemanticAnalyzer;
+use crate::tok::TokenType;
+use crate::utils::error::{Diagnostic, Severity};
+
+/// Les differentes etapes du compilateur executees par le CLI
+pub struct Frontend<'a> {
+    pub source: &'a str,
+    pub file_name: &'a str,
+    pub syntax_mode: SyntaxMode,
+    pub keyword_set: KeywordSet,
+    pub tab_width: usize,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Frontend<'a> {
+    pub fn new(source: &'a str, file_name: &'a str, syntax_mode: SyntaxMode) -> Self {
+        Frontend {
+            source,
+            file_name,
+            syntax_mode,
+            keyword_set: KeywordSet::default(),
+            tab_width: DEFAULT_TAB_WIDTH,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn with_keyword_set(mut self, keyword_set: KeywordSet) -> Self {
+        self.keyword_set = keyword_set;
+        self
+    }
+
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.is_error())
+    }
+
+    /// Tokenise le source, chaque erreur lexicale devient un diagnostic.
+    /// Les tokens ERROR ne sont pas transmis au parser
+    pub fn lex(&mut self) -> Vec<Token> {
+        let mut lexer = Lexer::new(self.source, self.syntax_mode)
+            .with_keyword_set(self.keyword_set)
+            .with_tab_width(self.tab_width);
+        let (tokens, errors) = lexer.tokenize_with_errors();
+        self.diagnostics.extend(errors.into_iter().map(Diagnostic::from));
+        for warning in lexer.warnings() {
+            self.diagnostics.push(Diagnostic::from(warning.clone()).with_severity(Severity::Warning));
+        }
+        tokens
+    }
+
+    /// Parse le programme complet en continuant apres chaque erreur
+    pub fn parse(&mut self, tokens: Vec<Token>) -> Vec<ASTNode> {
+        // Le parser ne connait pas les commentaires
+        let tokens: Vec<Token> = tokens.into_iter()
+            .filter(|token| !matches!(token.token_type, TokenType::COMMENT(_) | TokenType::DOCSTRING(_)))
+            .collect();
+
+        let mut parser = Parser::new(tokens, self.syntax_mode).with_keyword_set(self.keyword_set);
+        let (program, errors) = parser.parse_program();
+        self.diagnostics.extend(errors.into_iter().map(Diagnostic::from));
+        match program {
+            ASTNode::Program(nodes) => nodes,
+            node => vec![node],
+        }
+    }
+
+    /// Analyse semantique, les avertissements sont conserves avec la gravite Warning
+    pub fn check(&mut self, nodes: &[ASTNode]) {
+        let mut analyzer = SemanticAnalyzer::new().with_file_name(self.file_name);
+        if let Err(errors) = analyzer.analyze(nodes) {
+            self.diagnostics.extend(errors.into_iter().map(Diagnostic::from));
+        }
+        for warning in analyzer.get_warnings() {
+            self.diagnostics.push(Diagnostic::from(warning.clone()).with_severity(Severity::Warning));
+        }
+    }
+
+    /// Affiche les diagnostics sur la sortie d'erreur
+    fn report(&self, options: &Options) {
+        for diagnostic in &self.diagnostics {
+            if options.color {
+                eprint!("{}", diagnostic.render_colored(self.source, self.file_name));
+            } else {
+                eprint!("{}", diagnostic.render(self.source, self.file_name));
+            }
+        }
+    }
+
+    fn exit_code(&self) -> i32 {
+        if self.has_errors() { EXIT_ERRORS } else { EXIT_SUCCESS }
+    }
+}
+
+/// Execute la sous-commande sur le contenu du fichier
+pub fn execute(options: &Options, source: &str) -> i32 {
+    // Sans --mode, le mode est lu dans le fichier (pragma ou heuristique)
+    let syntax_mode = match options.syntax_mode {
+        Some(mode) => mode,
+        None => match detect_syntax_mode(source) {
+            Ok(mode) => mode,
+            Err(error) => return report_pragma_error(options, source, error),
+        },
+    };
+    // Sans --keywords, le dictionnaire est lu dans le pragma `#mots_cles` (anglais par defaut)
+    let keyword_set = match options.keywords {
+        Some(keyword_set) => keyword_set,
+        None => match detect_keyword_set(source) {
+            Ok(keyword_set) => keyword_set,
+            Err(error) => return report_pragma_error(options, source, error),
+        },
+    };
+    let mut frontend = Frontend::new(source, &options.file, syntax_mode)
+        .with_keyword_set(keyword_set)
+        .with_tab_width(options.tab_width);
+
+    match options.command {
+        Command::Lex => {
+            let tokens = frontend.lex();
+            match options.output {
+                OutputFormat::Json => println!("{}", json::tokens_report(&frontend, &tokens)),
+                OutputFormat::Human => {
+                    for (i, token) in tokens.iter().enumerate() {
+                        let span = token.span();
+                        println!("{:>4} {}:{} {:?} {:?}", i, span.line, span.column, token.token_type, token.text(frontend.source));
+                    }
+                    frontend.report(options);
+                }
+            }
+            frontend.exit_code()
+        }
+
+        Command::Parse => {
+            let tokens = frontend.lex();
+            let nodes = frontend.parse(tokens);
+            match options.output {
+                OutputFormat::Json => println!("{}", json::ast_report(&frontend, &nodes)),
+                OutputFormat::Human => {
+                    for node in &nodes {
+                        println!("{:#?}", node);
+                    }
+                    frontend.report(options);
+                }
+            }
+            frontend.exit_code()
+        }
+
+        Command::Check | Command::Run => {
+            let tokens = frontend.lex();
+            let nodes = frontend.parse(tokens);
+            if !frontend.has_errors() {
+                frontend.check(&nodes);
+            }
+
+            let mut code = frontend.exit_code();
+            if options.command == Command::Run && code == EXIT_SUCCESS {
+                // Le generateur de code (src/codegen) n'est pas encore branche
+                frontend.diagnostics.push(
+                    Diagnostic::error("cannot run program: no execution backend is available yet")
+                        .with_note("the program was checked successfully; use `punk check` to only verify it")
+                );
+                code = EXIT_UNSUPPORTED;
+            }
+
+            match options.output {
+                OutputFormat::Json => println!("{}", json::diagnostics_report(&frontend)),
+                OutputFormat::Human => {
+                    frontend.report(options);
+                    if code == EXIT_SUCCESS {
+                        let warnings = frontend.diagnostics.len();
+                        eprintln!("{}: ok ({} warning{})", frontend.file_name, warnings, if warnings == 1 { "" } else { "s" });
+                    }
+                }
+            }
+            code
+        }
+
+        Command::Fmt => {
+            let tokens = frontend.lex();
+            if frontend.has_errors() {
+                match options.output {
+                    OutputFormat::Json => println!("{}", json::diagnostics_report(&frontend)),
+                    OutputFormat::Human => frontend.report(options),
+                }
+                return EXIT_ERRORS;
+            }
+
+            let formatted = format_source(source, &tokens, syntax_mode);
+            let changed = formatted != source;
+
+            if options.check {
+                if changed {
+                    eprintln!("{}: not formatted", options.file);
+                    return EXIT_ERRORS;
+                }
+                return EXIT_SUCCESS;
+            }
+            if options.write {
+                if changed {
+                    if let Err(error) = std::fs::write(&options.file, &formatted) {
+                        eprintln!("punk: impossible d'ecrire '{}': {}", options.file, error);
+                        return EXIT_IO;
+                    }
+                }
+                return EXIT_SUCCESS;
+            }
+            print!("{}", formatted);
+            EXIT_SUCCESS
+        }
+
+        Command::Translate => {
+            let to = options.translate_to.unwrap_or_default();
+            let translated = match translate_keywords(source, syntax_mode, keyword_set, to) {
+                Ok(translated) => translated,
+                Err(error) => {
+                    frontend.diagnostics.push(Diagnostic::from(error));
+                    match options.output {
+                        OutputFormat::Json => println!("{}", json::diagnostics_report(&frontend)),
+                        OutputFormat::Human => frontend.report(options),
+                    }
+                    return EXIT_ERRORS;
+                }
+            };
+
+            if options.write {
+                if translated != source {
+                    if let Err(error) = std::fs::write(&options.file, &translated) {
+                        eprintln!("punk: impossible d'ecrire '{}': {}", options.file, error);
+                        return EXIT_IO;
+                    }
+                }
+                return EXIT_SUCCESS;
+            }
+            print!("{}", translated);
+            EXIT_SUCCESS
+        }
+    }
+}
+
+/// Pragma invalide en tete de fichier: rien d'autre n'est lu
+fn report_pragma_error(options: &Options, source: &str, error: LexerError) -> i32 {
+    let mut frontend = Frontend::new(source, &options.file, SyntaxMode::Braces);
+    frontend.diagnostics.push(Diagnostic::from(error));
+    match options.output {
+        OutputFormat::Json => println!("{}", json::diagnostics_report(&frontend)),
+        OutputFormat::Human => frontend.report(options),
+    }
+    EXIT_ERRORS
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frontend_collects_parser_errors() {
+        let source = "let x = ;\nlet y = 42;\n";
+        let mut frontend = Frontend::new(source, "main.pk", SyntaxMode::Braces);
+        let tokens = frontend.lex();
+        let nodes = frontend.parse(tokens);
+        assert!(frontend.has_errors());
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn test_frontend_reports_every_lexer_error() {
+        let source = "let a = $;\nlet s = \"ouverte\nlet b = 0b102;\nlet c = 1;\n";
+        let mut frontend = Frontend::new(source, "main.pk", SyntaxMode::Braces);
+        let tokens = frontend.lex();
+        assert_eq!(frontend.diagnostics.iter().filter(|d| d.is_error()).count(), 3);
+        assert!(!tokens.iter().any(|t| matches!(t.token_type, TokenType::ERROR(_))));
+
+        // Le parser ne voit plus les tokens ERROR: `let c = 1;` reste lisible
+        let nodes = frontend.parse(tokens);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn test_frontend_french_keywords() {
+        let source = "soit x: entier = 1;\nsoit mutable y = vrai;\ntantque y { y = faux; }\n";
+        let mut frontend = Frontend::new(source, "main.pk", SyntaxMode::Braces).with_keyword_set(KeywordSet::French);
+        let tokens = frontend.lex();
+        let nodes = frontend.parse(tokens);
+        assert!(!frontend.has_errors());
+        assert_eq!(nodes.len(), 3);
+    }
+
+    #[test]
+    fn test_frontend_checks_inferred_tuple_and_array_literals() {
+        for source in [
+            "let rows = [1, 2, 3];\n",
+            "let pair = (1, 2);\nlet (a, b) = pair;\n",
+            "let rows = [1, 2, 3];\nlet [x, y, z] = rows;\n",
+            "let nested = ((1, 2), 3);\nlet ((a, b), c) = nested;\nlet d: int = a + c;\n",
+            "fn f() { let pair = (1, true); let (a, b) = pair; }\n",
+        ] {
+            let mut frontend = Frontend::new(source, "main.pk", SyntaxMode::Braces);
+            let tokens = frontend.lex();
+            let nodes = frontend.parse(tokens);
+            frontend.check(&nodes);
+            assert!(!frontend.has_errors(), "{:?}: {:?}", source, frontend.diagnostics);
+        }
+    }
+}
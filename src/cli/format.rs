@@ -0,0 +1,138 @@
+//src/cli/format.rs
+
+// Formateur de `punk fmt`: re-indente chaque ligne a partir des tokens
+// (4 espaces par niveau), supprime les espaces en fin de ligne et garantit
+// une seule ligne vide a la fin du fichier. Le contenu des lignes n'est pas modifie.
+
+use crate::lexer::lex::{SyntaxMode, Token};
+use crate::tok::{Delimiters, TokenType};
+
+const INDENT_WIDTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LineKind {
+    /// Ligne sans token (vide ou faite d'espaces)
+    Blank,
+    /// Ligne qui commence par un token, avec son niveau d'indentation
+    Code(usize),
+    /// Ligne a l'interieur d'un token multi-ligne (string, commentaire): laissee telle quelle
+    Verbatim,
+}
+
+/// Reformate le source a partir des tokens produits par le lexer
+pub fn format_source(source: &str, tokens: &[Token], syntax_mode: SyntaxMode) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut kinds = vec![LineKind::Blank; lines.len()];
+
+    let mut blocks = 0usize;   // niveaux INDENT/DEDENT (mode Indentation)
+    let mut nesting = 0usize;  // parentheses, crochets et accolades ouverts
+
+    for token in tokens {
+        let span = token.span();
+        match &token.token_type {
+            TokenType::INDENT => {
+                blocks += 1;
+                continue;
+            }
+            TokenType::DEDENT => {
+                blocks = blocks.saturating_sub(1);
+                continue;
+            }
+            TokenType::NEWLINE | TokenType::EOF => continue,
+            _ => {}
+        }
+
+        if span.line == 0 || span.line > lines.len() {
+            continue;
+        }
+
+        let closes = matches!(
+            token.token_type,
+            TokenType::DELIMITER(Delimiters::RCURBRACE)
+                | TokenType::DELIMITER(Delimiters::RPAR)
+                | TokenType::DELIMITER(Delimiters::RSBRACKET)
+        );
+
+        if kinds[span.line - 1] == LineKind::Blank {
+            let mut level = nesting;
+            if syntax_mode == SyntaxMode::Indentation {
+                level += blocks;
+            }
+            if closes {
+                level = level.saturating_sub(1);
+            }
+            kinds[span.line - 1] = LineKind::Code(level);
+        }
+
+        // Les lignes couvertes par un token multi-ligne ne sont pas touchees
+        let end = span.end.min(source.len());
+        if span.start < end && source.is_char_boundary(span.start) && source.is_char_boundary(end) {
+            let extra_lines = source[span.start..end].matches('\n').count();
+            let last = (span.line + extra_lines).min(lines.len());
+            for kind in kinds.iter_mut().take(last).skip(span.line) {
+                *kind = LineKind::Verbatim;
+            }
+        }
+
+        match token.token_type {
+            TokenType::DELIMITER(Delimiters::LCURBRACE)
+            | TokenType::DELIMITER(Delimiters::LPAR)
+            | TokenType::DELIMITER(Delimiters::LSBRACKET) => nesting += 1,
+            _ if closes => nesting = nesting.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    let mut out = String::with_capacity(source.len());
+    for (line, kind) in lines.iter().zip(kinds) {
+        match kind {
+            LineKind::Blank => {}
+            LineKind::Code(level) => {
+                out.push_str(&" ".repeat(level * INDENT_WIDTH));
+                out.push_str(line.trim());
+            }
+            LineKind::Verbatim => out.push_str(line),
+        }
+        out.push('\n');
+    }
+
+    // Une seule fin de ligne a la fin du fichier
+    let trimmed_len = out.trim_end_matches('\n').len();
+    out.truncate(trimmed_len);
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::lex::Lexer;
+
+    fn format(source: &str, mode: SyntaxMode) -> String {
+        let tokens = Lexer::new(source, mode).tokenize();
+        format_source(source, &tokens, mode)
+    }
+
+    #[test]
+    fn test_format_braces() {
+        let source = "fn add(a: int, b: int) -> int {\n  let x = a + b;   \n\t\treturn x\n    }\n\n\n";
+        let expected = "fn add(a: int, b: int) -> int {\n    let x = a + b;\n    return x\n}\n";
+        assert_eq!(format(source, SyntaxMode::Braces), expected);
+    }
+
+    #[test]
+    fn test_format_indentation() {
+        let source = "fn add(a: int, b: int) -> int:\n  let x = a + b\n  return x\nlet y = 1\n";
+        let expected = "fn add(a: int, b: int) -> int:\n    let x = a + b\n    return x\nlet y = 1\n";
+        assert_eq!(format(source, SyntaxMode::Indentation), expected);
+    }
+
+    #[test]
+    fn test_format_is_idempotent() {
+        let source = "fn add(a: int, b: int) -> int {\n    return a + b\n}\n";
+        assert_eq!(format(source, SyntaxMode::Braces), source);
+    }
+}
@@ -0,0 +1,160 @@
+//src/cli/json.rs
+
+// Sortie JSON du CLI, ecrite a la main pour ne pas ajouter de dependance
+
+use crate::cli::commands::Frontend;
+use crate::lexer::lex::{SyntaxMode, Token};
+use crate::parser::ast::{ASTNode, Declaration, Span, Statement};
+use crate::utils::error::Diagnostic;
+
+/// Echappe une chaine selon la grammaire JSON
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn span(span: &Span) -> String {
+    format!(
+        "{{\"start\":{},\"end\":{},\"line\":{},\"column\":{}}}",
+        span.start, span.end, span.line, span.column
+    )
+}
+
+fn mode(syntax_mode: SyntaxMode) -> &'static str {
+    match syntax_mode {
+        SyntaxMode::Braces => "\"braces\"",
+        SyntaxMode::Indentation => "\"indentation\"",
+    }
+}
+
+pub fn diagnostic(diagnostic: &Diagnostic) -> String {
+    let labels: Vec<String> = diagnostic.labels.iter()
+        .map(|label| format!(
+            "{{\"span\":{},\"message\":{},\"primary\":{}}}",
+            span(&label.span), escape(&label.message), label.primary
+        ))
+        .collect();
+    let notes: Vec<String> = diagnostic.notes.iter().map(|note| escape(note)).collect();
+    format!(
+        "{{\"severity\":{},\"code\":{},\"message\":{},\"labels\":[{}],\"notes\":[{}]}}",
+        escape(&diagnostic.severity.to_string()),
+        diagnostic.code.as_deref().map(escape).unwrap_or_else(|| "null".to_string()),
+        escape(&diagnostic.message),
+        labels.join(","),
+        notes.join(",")
+    )
+}
+
+fn diagnostics(frontend: &Frontend) -> String {
+    let items: Vec<String> = frontend.diagnostics.iter().map(diagnostic).collect();
+    format!("[{}]", items.join(","))
+}
+
+fn header(frontend: &Frontend) -> String {
+    format!(
+        "\"file\":{},\"mode\":{},\"success\":{}",
+        escape(frontend.file_name),
+        mode(frontend.syntax_mode),
+        !frontend.has_errors()
+    )
+}
+
+/// `punk check --json`
+pub fn diagnostics_report(frontend: &Frontend) -> String {
+    format!("{{{},\"diagnostics\":{}}}", header(frontend), diagnostics(frontend))
+}
+
+/// `punk lex --json`
+pub fn tokens_report(frontend: &Frontend, tokens: &[Token]) -> String {
+    let items: Vec<String> = tokens.iter()
+        .map(|token| format!(
+            "{{\"type\":{},\"text\":{},\"span\":{}}}",
+            escape(&format!("{:?}", token.token_type)),
+            escape(token.text(frontend.source)),
+            span(&token.span())
+        ))
+        .collect();
+    format!("{{{},\"tokens\":[{}],\"diagnostics\":{}}}", header(frontend), items.join(","), diagnostics(frontend))
+}
+
+/// `punk parse --json`: chaque noeud de haut niveau avec son genre, son span et son dump
+pub fn ast_report(frontend: &Frontend, nodes: &[ASTNode]) -> String {
+    let items: Vec<String> = nodes.iter()
+        .map(|node| format!(
+            "{{\"kind\":{},\"span\":{},\"debug\":{}}}",
+            escape(node_kind(node)),
+            span(&node.span()),
+            escape(&format!("{:?}", node))
+        ))
+        .collect();
+    format!("{{{},\"ast\":[{}],\"diagnostics\":{}}}", header(frontend), items.join(","), diagnostics(frontend))
+}
+
+fn node_kind(node: &ASTNode) -> &'static str {
+    match node {
+        ASTNode::Program(_) => "Program",
+        ASTNode::Declaration(declaration) => match declaration {
+            Declaration::Variable(_) => "VariableDeclaration",
+            Declaration::Function(_) => "FunctionDeclaration",
+            Declaration::Constante(_) => "ConstDeclaration",
+            Declaration::Structure(_) => "StructDeclaration",
+            Declaration::Class(_) => "ClassDeclaration",
+            Declaration::Enum(_) => "EnumDeclaration",
+            Declaration::Trait(_) => "TraitDeclaration",
+            Declaration::Impl(_) => "ImplDeclaration",
+            Declaration::Module(_) => "ModuleDeclaration",
+            Declaration::Macro(_) => "MacroDeclaration",
+            Declaration::Attributes(_) => "Attribute",
+            Declaration::Constructor(_) => "Constructor",
+        },
+        ASTNode::Statement(statement) => match statement {
+            Statement::Expression(_) => "ExpressionStatement",
+            Statement::ReturnStatement(_) => "ReturnStatement",
+            Statement::UseStatement(_) => "UseStatement",
+            Statement::ImportStatement(_) => "ImportStatement",
+            Statement::ModuleImportStatement(_) => "ModuleImportStatement",
+            Statement::SpecificImportStatement(_) => "SpecificImportStatement",
+            Statement::RaiseStatement(_) => "RaiseStatement",
+            Statement::DelStatement(_) => "DelStatement",
+            Statement::IfStatement(_) => "IfStatement",
+            Statement::WhileStatement(_) => "WhileStatement",
+            Statement::ForStatement(_) => "ForStatement",
+            Statement::LoopStatement(_) => "LoopStatement",
+            Statement::BreakStatement(_) | Statement::Break(_) => "BreakStatement",
+            Statement::ContinueStatement(_) | Statement::Continue(_) => "ContinueStatement",
+            Statement::Pass(_) => "PassStatement",
+            Statement::TryStatement(_) => "TryStatement",
+            Statement::WithStatement(_) => "WithStatement",
+            Statement::YieldStatement(_) => "YieldStatement",
+            Statement::DeclarationStatement(_) => "DeclarationStatement",
+            Statement::Assignment(_, _) => "Assignment",
+            Statement::MatchStatement(_) => "MatchStatement",
+        },
+        ASTNode::Expression(_) => "Expression",
+        ASTNode::Error(_) => "Error",
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+}
@@ -0,0 +1,298 @@
+//src/cli/mod.rs
+
+// Driver en ligne de commande du compilateur:
+//
+//   punk lex   <fichier.pk>   affiche les tokens
+//   punk parse <fichier.pk>   affiche l'AST
+//   punk check <fichier.pk>   lexer + parser + analyse semantique
+//   punk run   <fichier.pk>   verifie puis execute le programme
+//   punk fmt   <fichier.pk>   reformate le fichier
+//   punk translate <fichier.pk> --to fr   traduit les mots cles
+
+mod commands;
+mod format;
+mod json;
+
+use crate::lexer::indentation::DEFAULT_TAB_WIDTH;
+use crate::lexer::keywords::KeywordSet;
+use crate::lexer::lex::SyntaxMode;
+
+pub use commands::Frontend;
+pub use format::format_source;
+
+/// Codes de sortie du CLI
+pub const EXIT_SUCCESS: i32 = 0;
+/// Le programme contient des erreurs (ou `fmt --check` a trouve des differences)
+pub const EXIT_ERRORS: i32 = 1;
+/// Mauvaise utilisation de la ligne de commande
+pub const EXIT_USAGE: i32 = 2;
+/// Fichier illisible ou impossible a ecrire
+pub const EXIT_IO: i32 = 3;
+/// Fonctionnalite pas encore disponible (backend d'execution)
+pub const EXIT_UNSUPPORTED: i32 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Lex,
+    Parse,
+    Check,
+    Run,
+    Fmt,
+    Translate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Human,
+    Json,
+}
+
+/// Options communes a toutes les sous-commandes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub file: String,
+    pub syntax_mode: Option<SyntaxMode>,
+    pub keywords: Option<KeywordSet>,
+    pub tab_width: usize,
+    pub output: OutputFormat,
+    pub color: bool,
+    pub trace: bool,
+    // options de `punk fmt`
+    pub check: bool,
+    pub write: bool,
+    // dictionnaire cible de `punk translate`
+    pub translate_to: Option<KeywordSet>,
+}
+
+const USAGE: &str = "\
+Usage: punk <commande> <fichier.pk> [options]
+
+Commandes:
+    lex      Affiche les tokens du fichier
+    parse    Affiche l'AST du fichier
+    check    Verifie le fichier (lexer, parser, analyse semantique)
+    run      Verifie puis execute le fichier
+    fmt      Reformate le fichier
+    translate  Reecrit les mots cles dans un autre dictionnaire (--to)
+
+Options:
+    --mode <braces|indent>  Mode syntaxique (detecte depuis le fichier par defaut)
+    --keywords <en|fr>      Mots cles anglais ou francais (pragma `#mots_cles` ou anglais par defaut)
+    --tab-width <n>         Largeur d'une tabulation pour l'unite d'indentation (4 par defaut)
+    --json                  Sortie JSON au lieu de la sortie lisible
+    --no-color              Desactive les couleurs dans les diagnostics
+    --trace                 Affiche les traces de debogage du compilateur
+    --check                 (fmt) Echoue si le fichier n'est pas formate
+    -w, --write             (fmt, translate) Reecrit le fichier au lieu d'afficher le resultat
+    --to <en|fr>            (translate) Dictionnaire de mots cles cible
+    -h, --help              Affiche cette aide
+    -V, --version           Affiche la version
+";
+
+/// Resultat de l'analyse des arguments
+#[derive(Debug, PartialEq)]
+pub enum ParsedArgs {
+    Run(Options),
+    Help,
+    Version,
+}
+
+impl Command {
+    fn from_str(name: &str) -> Option<Self> {
+        match name {
+            "lex" => Some(Command::Lex),
+            "parse" => Some(Command::Parse),
+            "check" => Some(Command::Check),
+            "run" => Some(Command::Run),
+            "fmt" => Some(Command::Fmt),
+            "translate" => Some(Command::Translate),
+            _ => None,
+        }
+    }
+}
+
+/// Analyse les arguments (sans le nom du programme)
+pub fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
+    let mut command = None;
+    let mut file = None;
+    let mut syntax_mode = None;
+    let mut keywords = None;
+    let mut tab_width = DEFAULT_TAB_WIDTH;
+    let mut output = OutputFormat::Human;
+    let mut color = true;
+    let mut trace = false;
+    let mut check = false;
+    let mut write = false;
+    let mut translate_to = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(ParsedArgs::Help),
+            "help" if command.is_none() => return Ok(ParsedArgs::Help),
+            "-V" | "--version" => return Ok(ParsedArgs::Version),
+            "--json" => output = OutputFormat::Json,
+            "--no-color" => color = false,
+            "--trace" => trace = true,
+            "--check" => check = true,
+            "-w" | "--write" => write = true,
+            "--mode" => {
+                let value = iter.next().ok_or("--mode attend une valeur (braces ou indent)")?;
+                syntax_mode = Some(parse_mode(value)?);
+            }
+            _ if arg.starts_with("--mode=") => {
+                syntax_mode = Some(parse_mode(&arg["--mode=".len()..])?);
+            }
+            "--keywords" => {
+                let value = iter.next().ok_or("--keywords attend une valeur (en ou fr)")?;
+                keywords = Some(parse_keyword_set(value)?);
+            }
+            _ if arg.starts_with("--keywords=") => {
+                keywords = Some(parse_keyword_set(&arg["--keywords=".len()..])?);
+            }
+            "--to" => {
+                let value = iter.next().ok_or("--to attend une valeur (en ou fr)")?;
+                translate_to = Some(parse_keyword_set(value)?);
+            }
+            _ if arg.starts_with("--to=") => {
+                translate_to = Some(parse_keyword_set(&arg["--to=".len()..])?);
+            }
+            "--tab-width" => {
+                let value = iter.next().ok_or("--tab-width attend une valeur")?;
+                tab_width = parse_tab_width(value)?;
+            }
+            _ if arg.starts_with("--tab-width=") => {
+                tab_width = parse_tab_width(&arg["--tab-width=".len()..])?;
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                return Err(format!("option inconnue '{}'", arg));
+            }
+            _ if command.is_none() => {
+                command = Some(Command::from_str(arg)
+                    .ok_or_else(|| format!("commande inconnue '{}'", arg))?);
+            }
+            _ if file.is_none() => file = Some(arg.clone()),
+            _ => return Err(format!("argument inattendu '{}'", arg)),
+        }
+    }
+
+    let command = command.ok_or("aucune commande donnee")?;
+    let file = file.ok_or("aucun fichier donne")?;
+    if check && command != Command::Fmt {
+        return Err("--check n'est valable qu'avec `punk fmt`".to_string());
+    }
+    if write && !matches!(command, Command::Fmt | Command::Translate) {
+        return Err("--write n'est valable qu'avec `punk fmt` et `punk translate`".to_string());
+    }
+    if (command == Command::Translate) != translate_to.is_some() {
+        return Err("`punk translate` demande --to (et --to n'est valable qu'avec translate)".to_string());
+    }
+
+    Ok(ParsedArgs::Run(Options { command, file, syntax_mode, keywords, tab_width, output, color, trace, check, write, translate_to }))
+}
+
+fn parse_keyword_set(value: &str) -> Result<KeywordSet, String> {
+    KeywordSet::from_name(value)
+        .ok_or_else(|| format!("dictionnaire de mots cles inconnu '{}' (attendu: en ou fr)", value))
+}
+
+fn parse_tab_width(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(width) if width > 0 => Ok(width),
+        _ => Err(format!("largeur de tabulation invalide '{}' (attendu: entier positif)", value)),
+    }
+}
+
+fn parse_mode(value: &str) -> Result<SyntaxMode, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "braces" | "brace" => Ok(SyntaxMode::Braces),
+        "indent" | "indentation" => Ok(SyntaxMode::Indentation),
+        _ => Err(format!("mode syntaxique inconnu '{}' (attendu: braces ou indent)", value)),
+    }
+}
+
+/// Point d'entree du CLI, retourne le code de sortie du processus
+pub fn run(args: &[String]) -> i32 {
+    let options = match parse_args(args) {
+        Ok(ParsedArgs::Run(options)) => options,
+        Ok(ParsedArgs::Help) => {
+            print!("{}", USAGE);
+            return EXIT_SUCCESS;
+        }
+        Ok(ParsedArgs::Version) => {
+            println!("punk {}", env!("CARGO_PKG_VERSION"));
+            return EXIT_SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("punk: {}\n", message);
+            eprint!("{}", USAGE);
+            return EXIT_USAGE;
+        }
+    };
+
+    crate::utils::set_trace(options.trace);
+
+    let source = match std::fs::read_to_string(&options.file) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("punk: impossible de lire '{}': {}", options.file, error);
+            return EXIT_IO;
+        }
+    };
+
+    commands::execute(&options, &source)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = parse_args(&args(&["check", "main.pk", "--json", "--mode", "indent", "--tab-width=8"])).unwrap();
+        match parsed {
+            ParsedArgs::Run(options) => {
+                assert_eq!(options.command, Command::Check);
+                assert_eq!(options.file, "main.pk");
+                assert_eq!(options.output, OutputFormat::Json);
+                assert_eq!(options.syntax_mode, Some(SyntaxMode::Indentation));
+                assert_eq!(options.tab_width, 8);
+                assert_eq!(options.keywords, None);
+            }
+            other => panic!("Expected Run, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_args_keywords() {
+        let parsed = parse_args(&args(&["translate", "main.pk", "--keywords=en", "--to", "fr", "-w"])).unwrap();
+        match parsed {
+            ParsedArgs::Run(options) => {
+                assert_eq!(options.command, Command::Translate);
+                assert_eq!(options.keywords, Some(KeywordSet::English));
+                assert_eq!(options.translate_to, Some(KeywordSet::French));
+                assert!(options.write);
+            }
+            other => panic!("Expected Run, got {:?}", other),
+        }
+        assert!(parse_args(&args(&["translate", "main.pk"])).is_err());
+        assert!(parse_args(&args(&["check", "main.pk", "--to", "fr"])).is_err());
+        assert!(parse_args(&args(&["check", "main.pk", "--keywords", "de"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(&args(&["build", "main.pk"])).is_err());
+        assert!(parse_args(&args(&["check"])).is_err());
+        assert!(parse_args(&args(&["check", "main.pk", "--mode=tabs"])).is_err());
+        assert!(parse_args(&args(&["check", "main.pk", "--write"])).is_err());
+        assert!(parse_args(&args(&["check", "main.pk", "--tab-width", "0"])).is_err());
+        assert_eq!(parse_args(&args(&["--help"])).unwrap(), ParsedArgs::Help);
+    }
+}
@@ -0,0 +1,187 @@
+//src/lexer/fstring.rs
+
+use crate::lexer_error::{FStringErrorType, LexerError, Position};
+use crate::parser::ast::{FileId, Span};
+use crate::tok::FStringPart;
+
+/// Decoupe le contenu d'une f-string en morceaux de texte et en expressions.
+///
+/// `content` est le texte source entre les guillemets: les echappements n'y sont pas
+/// encore appliques, un `\u{...}` n'ouvre donc pas d'expression (sauf si `raw`).
+/// `{{` et `}}` donnent des accolades litterales, `{expr}` ou `{expr:spec}` une expression.
+/// Le span de chaque expression est relatif a `content` (octets, ligne 1 colonne 1 au debut).
+/// La position sert a situer les erreurs (debut de la chaine).
+pub fn split_format_string(content: &str, position: Position, raw: bool) -> Result<Vec<FStringPart>, LexerError> {
+    let error = |e: FStringErrorType| LexerError::fstring_error(e, position.clone());
+    let chars: Vec<char> = content.chars().collect();
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if !raw => {
+                // La sequence est gardee telle quelle, `\u{...}` compris
+                let end = if chars.get(i + 1) == Some(&'u') && chars.get(i + 2) == Some(&'{') {
+                    chars[i..].iter().position(|&c| c == '}').map_or(chars.len(), |p| i + p + 1)
+                } else {
+                    (i + 2).min(chars.len())
+                };
+                literal.extend(&chars[i..end]);
+                i = end;
+            }
+            '{' if chars.get(i + 1) == Some(&'{') => {
+                literal.push('{');
+                i += 2;
+            }
+            '}' if chars.get(i + 1) == Some(&'}') => {
+                literal.push('}');
+                i += 2;
+            }
+            '}' => return Err(error(FStringErrorType::UnopenedRbrace)),
+            '{' => {
+                if !literal.is_empty() {
+                    parts.push(FStringPart::Literal(std::mem::take(&mut literal)));
+                }
+                let (source, format_spec, next) = scan_hole(&chars, i + 1).map_err(error)?;
+                let leading = chars[i + 1..].iter().take_while(|c| c.is_whitespace()).count();
+                let span = relative_span(&chars, i + 1 + leading, source.len());
+                parts.push(FStringPart::Expression { source, format_spec, span });
+                i = next;
+            }
+            ch => {
+                literal.push(ch);
+                i += 1;
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        parts.push(FStringPart::Literal(literal));
+    }
+    Ok(parts)
+}
+
+/// Span de `len` octets commencant au caractere `index`, relatif au debut du contenu
+fn relative_span(chars: &[char], index: usize, len: usize) -> Span {
+    let before = &chars[..index];
+    let start: usize = before.iter().map(|c| c.len_utf8()).sum();
+    let line = 1 + before.iter().filter(|&&c| c == '\n').count();
+    let column = 1 + before.iter().rev().take_while(|&&c| c != '\n').count();
+    Span::new(FileId::default(), start, start + len, line, column)
+}
+
+/// Lit une expression `{...}` a partir de `start` (apres le `{`).
+/// Retourne le source de l'expression, la spec eventuelle et l'index apres le `}`
+fn scan_hole(chars: &[char], start: usize) -> Result<(String, Option<String>, usize), FStringErrorType> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut i = start;
+
+    while i < chars.len() {
+        let ch = chars[i];
+        if let Some(q) = quote {
+            if ch == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+        match ch {
+            '"' | '\'' => quote = Some(ch),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' if depth > 0 => depth -= 1,
+            '}' if depth > 0 => depth -= 1,
+            // `::` appartient a l'expression (chemin de variante)
+            ':' if chars.get(i + 1) == Some(&':') => i += 1,
+            ':' | '}' if depth == 0 => {
+                let source: String = chars[start..i].iter().collect();
+                if source.trim().is_empty() {
+                    return Err(FStringErrorType::EmptyExpression);
+                }
+                if ch == '}' {
+                    return Ok((source.trim().to_string(), None, i + 1));
+                }
+                let spec_start = i + 1;
+                let mut end = spec_start;
+                while end < chars.len() && chars[end] != '}' {
+                    if chars[end] == '{' {
+                        let spec: String = chars[spec_start..=end].iter().collect();
+                        return Err(FStringErrorType::InvalidFormatSpec(spec));
+                    }
+                    end += 1;
+                }
+                if end == chars.len() {
+                    return Err(FStringErrorType::UnclosedLbrace);
+                }
+                let spec: String = chars[spec_start..end].iter().collect();
+                validate_format_spec(&spec)?;
+                return Ok((source.trim().to_string(), Some(spec), end + 1));
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    Err(FStringErrorType::UnclosedLbrace)
+}
+
+/// Verifie une spec de la forme `[[fill]align][sign][#][0][width][,|_][.precision][type]`
+pub fn validate_format_spec(spec: &str) -> Result<(), FStringErrorType> {
+    let invalid = || FStringErrorType::InvalidFormatSpec(spec.to_string());
+    let chars: Vec<char> = spec.chars().collect();
+    let is_align = |c: char| matches!(c, '<' | '>' | '^' | '=');
+    let mut i = 0;
+
+    if chars.len() >= 2 && is_align(chars[1]) {
+        i = 2;
+    } else if !chars.is_empty() && is_align(chars[0]) {
+        i = 1;
+    }
+    if i < chars.len() && matches!(chars[i], '+' | '-' | ' ') {
+        i += 1;
+    }
+    if i < chars.len() && chars[i] == '#' {
+        i += 1;
+    }
+    while i < chars.len() && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i < chars.len() && matches!(chars[i], ',' | '_') {
+        i += 1;
+    }
+    if i < chars.len() && chars[i] == '.' {
+        i += 1;
+        let digits = i;
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == digits {
+            return Err(invalid());
+        }
+    }
+    if i < chars.len() && format_type_class(chars[i]).is_some() {
+        i += 1;
+    }
+    if i != chars.len() {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// Categorie de valeur attendue par le type de presentation d'une spec
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FormatTypeClass {
+    Integer,
+    Numeric,
+    Text,
+}
+
+/// Categorie du caractere de type (`d`, `x`, `f`, `s`, ...) d'une spec
+pub fn format_type_class(ch: char) -> Option<FormatTypeClass> {
+    match ch {
+        'b' | 'c' | 'd' | 'o' | 'x' | 'X' => Some(FormatTypeClass::Integer),
+        'e' | 'E' | 'f' | 'F' | 'g' | 'G' | 'n' | '%' => Some(FormatTypeClass::Numeric),
+        's' => Some(FormatTypeClass::Text),
+        _ => None,
+    }
+}
@@ -0,0 +1,31 @@
+//src/lexer/indentation.rs
+
+use std::cmp::Ordering;
+
+/// Largeur d'une tabulation par defaut (en colonnes)
+pub const DEFAULT_TAB_WIDTH: usize = 4;
+
+/// Indentation d'une ligne, tabulations et espaces comptes separement
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct IndentationLevel {
+    pub tabs: usize,
+    pub spaces: usize,
+}
+
+impl IndentationLevel {
+    /// Largeur en colonnes pour une largeur de tabulation donnee
+    pub fn width(&self, tab_width: usize) -> usize {
+        self.tabs * tab_width + self.spaces
+    }
+
+    /// Compare deux indentations sans supposer de largeur de tabulation.
+    /// `None` si l'ordre depend de cette largeur (ex: une tabulation contre quatre espaces).
+    pub fn compare_strict(&self, other: &IndentationLevel) -> Option<Ordering> {
+        match self.tabs.cmp(&other.tabs) {
+            Ordering::Less if self.spaces <= other.spaces => Some(Ordering::Less),
+            Ordering::Greater if self.spaces >= other.spaces => Some(Ordering::Greater),
+            Ordering::Equal => Some(self.spaces.cmp(&other.spaces)),
+            _ => None,
+        }
+    }
+}
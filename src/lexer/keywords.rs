@@ -0,0 +1,273 @@
+//src/lexer/keywords.rs
+
+use crate::lexer::lex::{Lexer, SyntaxMode, Token};
+use crate::lexer::syntax_mode::{find_pragma, value_column};
+use crate::lexer_error::{LexerError, LexerErrorType, Position};
+use crate::tok::{FStringPart, Keywords, StringKind, TokenType};
+
+/// Dictionnaire de mots cles lu par le lexer.
+/// Chaque ecriture donne le meme `Keywords`, le parser ne voit pas la difference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeywordSet {
+    #[default]
+    English,
+    French,
+}
+
+/// Cles du pragma `#mots_cles = francais` en tete de fichier
+const PRAGMA_KEYS: &[&str] = &["mots_cles", "mots_clés", "keywords"];
+
+/// Mots cles anglais, dans une table de hachage parfaite construite a la compilation
+static ENGLISH: phf::Map<&'static str, Keywords> = phf::phf_map! {
+    "and" => Keywords::AND,
+    "as" => Keywords::AS,
+    "async" => Keywords::ASYNC,
+    "await" => Keywords::AWAIT,
+    "break" => Keywords::BREAK,
+    "const" => Keywords::CONST,
+    "continue" => Keywords::CONTINUE,
+    "class" => Keywords::CLASS,
+    "def" => Keywords::DEF,
+    "del" => Keywords::DEL,
+    "elif" => Keywords::ELIF,
+    "else" => Keywords::ELSE,
+    "enum" => Keywords::ENUM,
+    "except" => Keywords::EXCEPT,
+    "false" => Keywords::FALSE,
+    "finally" => Keywords::FINALLY,
+    "fn" => Keywords::FN,
+    "for" => Keywords::FOR,
+    "from" => Keywords::FROM,
+    "if" => Keywords::IF,
+    "impl" => Keywords::IMPL,
+    "import" => Keywords::IMPORT,
+    "in" => Keywords::IN,
+    "is" => Keywords::IS,
+    "lambda" => Keywords::LAMBDA,
+    "let" => Keywords::LET,
+    "loop" => Keywords::LOOP,
+    "match" => Keywords::MATCH,
+    "mod" => Keywords::MOD,
+    "mut" => Keywords::MUT,
+    "none" => Keywords::NONE,
+    "not" => Keywords::NOT,
+    "or" => Keywords::OR,
+    "pub" => Keywords::PUB,
+    "pass" => Keywords::PASS,
+    "raise" => Keywords::RAISE,
+    "return" => Keywords::RETURN,
+    "self" => Keywords::SELF,
+    "static" => Keywords::STATIC,
+    "struct" => Keywords::STRUCT,
+    "super" => Keywords::SUPER,
+    "trait" => Keywords::TRAIT,
+    "true" => Keywords::TRUE,
+    "try" => Keywords::TRY,
+    "type" => Keywords::TYPE,
+    "typeof" => Keywords::TYPEOF,
+    "use" => Keywords::USE,
+    "with" => Keywords::WITH,
+    "where" => Keywords::WHERE,
+    "while" => Keywords::WHILE,
+    "yield" => Keywords::YIELD,
+    // mots cles de type
+    "int" => Keywords::INT,
+    "float" => Keywords::FLOAT,
+    "str" => Keywords::STR,
+    "bool" => Keywords::BOOL,
+    "char" => Keywords::CHAR,
+};
+
+/// Mots cles francais, sans accents pour rester faciles a taper
+static FRENCH: phf::Map<&'static str, Keywords> = phf::phf_map! {
+    "et" => Keywords::AND,
+    "comme" => Keywords::AS,
+    "asynchrone" => Keywords::ASYNC,
+    "attendre" => Keywords::AWAIT,
+    "arreter" => Keywords::BREAK,
+    "constante" => Keywords::CONST,
+    "continuer" => Keywords::CONTINUE,
+    "classe" => Keywords::CLASS,
+    "definir" => Keywords::DEF,
+    "supprimer" => Keywords::DEL,
+    "sinonsi" => Keywords::ELIF,
+    "sinon" => Keywords::ELSE,
+    "enumeration" => Keywords::ENUM,
+    "sauf" => Keywords::EXCEPT,
+    "faux" => Keywords::FALSE,
+    "finalement" => Keywords::FINALLY,
+    "fonction" => Keywords::FN,
+    "pour" => Keywords::FOR,
+    "depuis" => Keywords::FROM,
+    "si" => Keywords::IF,
+    "implementer" => Keywords::IMPL,
+    "importer" => Keywords::IMPORT,
+    "dans" => Keywords::IN,
+    "est" => Keywords::IS,
+    "lambda" => Keywords::LAMBDA,
+    "soit" => Keywords::LET,
+    "boucle" => Keywords::LOOP,
+    "selon" => Keywords::MATCH,
+    "module" => Keywords::MOD,
+    "mutable" => Keywords::MUT,
+    "rien" => Keywords::NONE,
+    "non" => Keywords::NOT,
+    "ou" => Keywords::OR,
+    "public" => Keywords::PUB,
+    "passer" => Keywords::PASS,
+    "lever" => Keywords::RAISE,
+    "retourner" => Keywords::RETURN,
+    "soi" => Keywords::SELF,
+    "statique" => Keywords::STATIC,
+    "structure" => Keywords::STRUCT,
+    "parent" => Keywords::SUPER,
+    "trait" => Keywords::TRAIT,
+    "vrai" => Keywords::TRUE,
+    "essayer" => Keywords::TRY,
+    "type" => Keywords::TYPE,
+    "typede" => Keywords::TYPEOF,
+    "utiliser" => Keywords::USE,
+    "avec" => Keywords::WITH,
+    "quand" => Keywords::WHERE,
+    "tantque" => Keywords::WHILE,
+    "produire" => Keywords::YIELD,
+    // mots cles de type
+    "entier" => Keywords::INT,
+    "flottant" => Keywords::FLOAT,
+    "chaine" => Keywords::STR,
+    "booleen" => Keywords::BOOL,
+    "caractere" => Keywords::CHAR,
+};
+
+impl KeywordSet {
+    /// Nom donne au pragma ou a l'option `--keywords` (`en`, `fr`, `anglais`, `francais`, ...)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "en" | "english" | "anglais" => Some(KeywordSet::English),
+            "fr" | "french" | "francais" | "français" => Some(KeywordSet::French),
+            _ => None,
+        }
+    }
+
+    /// Nom ecrit dans le pragma par l'outil de traduction
+    pub fn name(self) -> &'static str {
+        match self {
+            KeywordSet::English => "english",
+            KeywordSet::French => "francais",
+        }
+    }
+
+    fn table(self) -> &'static phf::Map<&'static str, Keywords> {
+        match self {
+            KeywordSet::English => &ENGLISH,
+            KeywordSet::French => &FRENCH,
+        }
+    }
+
+    /// Mot cle ecrit `word` dans ce dictionnaire
+    pub fn lookup(self, word: &str) -> Option<Keywords> {
+        self.table().get(word).cloned()
+    }
+
+    /// Ecriture du mot cle dans ce dictionnaire
+    pub fn spelling(self, keyword: &Keywords) -> &'static str {
+        self.table()
+            .entries()
+            .find(|(_, k)| *k == keyword)
+            .map(|(word, _)| *word)
+            .expect("every keyword has a spelling in each keyword set")
+    }
+}
+
+/// Dictionnaire choisi par le pragma `#mots_cles = ...`, anglais sans pragma
+pub fn detect_keyword_set(source: &str) -> Result<KeywordSet, LexerError> {
+    let Some((index, line, value)) = find_pragma(source, PRAGMA_KEYS) else {
+        return Ok(KeywordSet::default());
+    };
+    KeywordSet::from_name(value).ok_or_else(|| LexerError::new(
+        LexerErrorType::InvalidPragma(value.to_string()),
+        format!("invalid keyword set '{}' in pragma (expected english or francais)", value),
+        Position { line: index + 1, column: value_column(line) },
+    ))
+}
+
+/// Reecrit les mots cles de `source` dans un autre dictionnaire a partir des tokens:
+/// commentaires, chaines et espaces sont recopies tels quels, sauf les trous des f-strings.
+/// Le pragma `#mots_cles` est mis a jour, ou ajoute en tete si la cible n'est pas l'anglais.
+/// Echoue sur une erreur lexicale ou si un identifiant est un mot cle dans la cible.
+pub fn translate_keywords(source: &str, syntax_mode: SyntaxMode, from: KeywordSet, to: KeywordSet) -> Result<String, LexerError> {
+    let tokens = Lexer::new(source, syntax_mode).with_keyword_set(from).tokenize();
+    let mut translation = Translation { source, syntax_mode, from, to, translated: String::with_capacity(source.len()), copied: 0 };
+    translation.translate(0, &tokens)?;
+    let Translation { mut translated, copied, .. } = translation;
+    translated.push_str(&source[copied..]);
+
+    Ok(set_keyword_pragma(&translated, to))
+}
+
+/// Etat de `translate_keywords`: texte produit et offset deja recopie de `source`
+struct Translation<'a> {
+    source: &'a str,
+    syntax_mode: SyntaxMode,
+    from: KeywordSet,
+    to: KeywordSet,
+    translated: String,
+    copied: usize,
+}
+
+impl Translation<'_> {
+    /// Traduit les tokens lus a partir de l'offset `base` de `source`.
+    /// Les trous des f-strings sont relus avec le meme dictionnaire; leurs erreurs sont laissees au parser
+    fn translate(&mut self, base: usize, tokens: &[Token]) -> Result<(), LexerError> {
+        for token in tokens {
+            let span = token.span();
+            let (start, end) = (base + span.start, base + span.end);
+            match &token.token_type {
+                TokenType::ERROR(error) if base == 0 => return Err(error.clone()),
+                TokenType::KEYWORD(keyword) => {
+                    self.translated.push_str(&self.source[self.copied..start]);
+                    self.translated.push_str(self.to.spelling(keyword));
+                    self.copied = end;
+                }
+                TokenType::IDENTIFIER { name } if self.to.lookup(name).is_some() => {
+                    let error = LexerErrorType::KeywordCollision(name.to_string());
+                    let message = format!("identifier '{}' is a keyword in {}, rename it before translating", name, self.to.name());
+                    return Err(LexerError::new(error, message, position_at(self.source, start)));
+                }
+                TokenType::STRING { kind: StringKind::FORMATTED(parts), .. } => {
+                    for part in parts {
+                        if let FStringPart::Expression { span: hole, .. } = part {
+                            let (hole_start, hole_end) = (base + hole.start, base + hole.end);
+                            let hole_tokens = Lexer::new(&self.source[hole_start..hole_end], self.syntax_mode)
+                                .with_keyword_set(self.from)
+                                .tokenize();
+                            self.translate(hole_start, &hole_tokens)?;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Ligne et colonne de l'offset `offset` dans `source`
+fn position_at(source: &str, offset: usize) -> Position {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    Position { line: before.matches('\n').count() + 1, column: before[line_start..].chars().count() + 1 }
+}
+
+/// Met la valeur du pragma de mots cles a `keyword_set`
+fn set_keyword_pragma(source: &str, keyword_set: KeywordSet) -> String {
+    match find_pragma(source, PRAGMA_KEYS) {
+        Some((_, _, value)) => {
+            // `value` est une sous-chaine de `source`
+            let start = value.as_ptr() as usize - source.as_ptr() as usize;
+            format!("{}{}{}", &source[..start], keyword_set.name(), &source[start + value.len()..])
+        }
+        None if keyword_set != KeywordSet::English => format!("#mots_cles = {}\n{}", keyword_set.name(), source),
+        None => source.to_string(),
+    }
+}
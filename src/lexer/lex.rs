@@ -1,8 +1,15 @@
 use crate::lexer_error::{LexerError, LexerErrorType, Position};
-use crate::tok::{Delimiters, Keywords, Operators, StringKind, TokenType};
-use std::collections::HashMap;
-use std::iter::Peekable;
-use std::str::Chars;
+use crate::tok::{Delimiters, FStringPart, Keywords, NumberSuffix, Operators, StringKind, TokenType};
+use crate::parser::ast::{FileId, Span};
+use crate::lexer::syntax_mode::detect_syntax_mode;
+use crate::lexer::fstring::split_format_string;
+use crate::lexer::trivia::{attach_trivia, LosslessToken};
+use crate::lexer::indentation::{IndentationLevel, DEFAULT_TAB_WIDTH};
+use crate::lexer::interner::Interner;
+use crate::lexer::keywords::{detect_keyword_set, KeywordSet};
+use crate::lexer::unicode::{find_confusable, is_identifier_continue, is_identifier_start, normalize_identifier};
+use num_bigint::BigInt;
+use std::cmp::Ordering;
 //use crate::lexer::error::{LexerError, LexerErrorType, Position};
 
 //#[allow(dead_code)]
@@ -13,44 +20,132 @@ pub enum SyntaxMode {
 }
 
 /// Structure Token,
-/// elle contient le text du token, le type du token, la ligne et la colonne
+/// elle contient le type du token et le span (offsets en octets, ligne et colonne) qu'il couvre.
+/// Le texte n'est pas copie: `text()` le relit dans le source
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Token {
-    pub text: String,
     pub token_type: TokenType,
-    pub(crate) line: usize,
-    pub(crate) column: usize,
+    pub(crate) span: Span,
 }
 
 /// Implementation de la structure Token
 #[allow(dead_code)]
 impl Token {
-    pub fn new(text: String, token_type: TokenType, line: usize, column: usize) -> Self {
-        Token {
-            text,
-            token_type,
-            line,
-            column,
-        }
+    pub fn new(token_type: TokenType, span: Span) -> Self {
+        Token { token_type, span }
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Texte du token tel qu'il est ecrit dans `source` (le source donne au lexer)
+    pub fn text<'s>(&self, source: &'s str) -> &'s str {
+        source.get(self.span.start..self.span.end).unwrap_or("")
     }
 }
 
+/// Operateur correspondant au texte (un ou deux caracteres lus par `lex_operator`)
+fn operator(text: &str) -> Option<Operators> {
+    let operator = match text {
+        "+" => Operators::PLUS,
+        "-" => Operators::MINUS,
+        "*" => Operators::STAR,
+        "/" => Operators::SLASH,
+        "%" => Operators::PERCENT,
+        "==" => Operators::EQEQUAL,
+        "!=" => Operators::NOTEQUAL,
+        "<" => Operators::LESS,
+        ">" => Operators::GREATER,
+        "<=" => Operators::LESSEQUAL,
+        ">=" => Operators::GREATEREQUAL,
+        "=" => Operators::EQUAL,
+        "++" => Operators::PLUSEQUAL,
+        "--" => Operators::MINEQUAL,
+        "**" => Operators::DOUBLESTAR,
+        "&&" => Operators::AND,
+        "||" => Operators::OR,
+        "!" => Operators::EXCLAMATION,
+        "&" => Operators::AMPER,
+        "|" => Operators::VBAR,
+        "^" => Operators::CIRCUMFLEX,
+        "<<" => Operators::LEFTSHIFT,
+        ">>" => Operators::RIGHTSHIFT,
+        "~" => Operators::TILDE,
+        "+=" => Operators::PLUSEQUAL,
+        "-=" => Operators::MINEQUAL,
+        "*=" => Operators::STAREQUAL,
+        "/=" => Operators::SLASHEQUAL,
+        "%=" => Operators::PERCENTEQUAL,
+        "&=" => Operators::AMPEREQUAL,
+        "|=" => Operators::VBAREQUAL,
+        "^=" => Operators::CIRCUMFLEXEQUAL,
+        "<<=" => Operators::LEFTSHIFTEQUAL,
+        ">>=" => Operators::RIGHTSHIFTEQUAL,
+        "**=" => Operators::DOUBLESTAREQUAL,
+        "//=" => Operators::DOUBLESLASHEQUAL,
+        "@" => Operators::AT,
+        "@=" => Operators::ATEQUAL,
+        "->" => Operators::RARROW,
+        ":=" => Operators::COLONEQUAL,
+        "*/" => Operators::STARSLASH,
+        "/*" => Operators::SLASHSTAR,
+        "#" => Operators::DIESE,
+        "?" => Operators::INTERROGATION,
+        "_" => Operators::UNDERSCORE,
+        "=>" => Operators::FATARROW,
+        ".." => Operators::DOTDOT,
+        "..=" => Operators::DOTDOTEQUAL,
+        _ => return None,
+    };
+    Some(operator)
+}
+
+/// Delimiteur d'un caractere (`::` et `...` sont reconnus par `lex_delimiter`)
+fn delimiter(ch: char) -> Option<Delimiters> {
+    let delimiter = match ch {
+        '(' => Delimiters::LPAR,
+        ')' => Delimiters::RPAR,
+        '{' => Delimiters::LCURBRACE,
+        '}' => Delimiters::RCURBRACE,
+        ']' => Delimiters::RSBRACKET,
+        '[' => Delimiters::LSBRACKET,
+        ';' => Delimiters::SEMICOLON,
+        ':' => Delimiters::COLON,
+        ',' => Delimiters::COMMA,
+        '.' => Delimiters::DOT,
+        _ => return None,
+    };
+    Some(delimiter)
+}
+
 /// structure Lexer
 #[allow(dead_code)]
 pub struct Lexer<'a> {
-    source: Peekable<Chars<'a>>,
-    current_char: char,
-    keywords: HashMap<String, Keywords>,
-    operators: HashMap<String, Operators>,
-    delimiters: HashMap<String, Delimiters>,
+    code_source: &'a str,
+    bytes: &'a [u8],
     current_line: usize,
     current_column: usize,
-    current_token_text: String,
     syntax_mode: SyntaxMode,
-    indent_level: Vec<usize>,
+    keyword_set: KeywordSet,
+    indent_level: Vec<IndentationLevel>,
+    pending_dedents: usize,     // DEDENT restant a emettre quand une ligne ferme plusieurs blocs
+    tab_width: usize,
+    indent_unit: Option<usize>, // largeur du premier bloc, les suivants doivent en etre des multiples
     at_line_start: bool,
-    // nesting :usize
+    current_offset: usize,
+    token_start: (usize, usize, usize), // (offset, ligne, colonne) du debut du token courant
+    file_id: FileId,
+    warnings: Vec<LexerError>, // avertissements non bloquants (caracteres confusables)
+    errors: Vec<LexerError>,   // erreurs lexicales, dans l'ordre du source
+    nesting: Vec<(char, Position)>, // parentheses, crochets et accolades ouverts avec leur position
+    interner: Interner,
 }
 
 /// Implementation du lexer avec tous les methodes pour classer les tokens
@@ -59,209 +154,189 @@ impl<'a> Lexer<'a> {
     /// Creation d'une nouvelle instance de lexer
     pub fn new(code_source: &'a str, syntax_mode: SyntaxMode) -> Self {
         let lexer = Lexer {
-            source: code_source.chars().peekable(),
-            current_char: '\0',
-            keywords: Self::keywords(),
-            operators: Self::operators(),
-            delimiters: Self::delimiters(),
+            code_source,
+            bytes: code_source.as_bytes(),
             current_line: 1,
             current_column: 1,
-            current_token_text: String::new(),
             syntax_mode,
-            indent_level: vec![0],
+            keyword_set: KeywordSet::default(),
+            indent_level: vec![IndentationLevel::default()],
+            pending_dedents: 0,
+            tab_width: DEFAULT_TAB_WIDTH,
+            indent_unit: None,
             at_line_start: true,
-            //  nesting: 0,
+            current_offset: 0,
+            token_start: (0, 1, 1),
+            file_id: FileId::default(),
+            warnings: Vec::new(),
+            errors: Vec::new(),
+            nesting: Vec::new(),
+            interner: Interner::new(),
         };
         lexer
     }
 
+    /// Creation d'un lexer dont le mode syntaxique et les mots cles sont lus dans le fichier
+    /// (pragmas `#Syntaxe_mode = ...` et `#mots_cles = ...`, voir syntax_mode.rs et keywords.rs)
+    pub fn auto(code_source: &'a str) -> Result<Self, LexerError> {
+        let syntax_mode = detect_syntax_mode(code_source)?;
+        let keyword_set = detect_keyword_set(code_source)?;
+        Ok(Self::new(code_source, syntax_mode).with_keyword_set(keyword_set))
+    }
+
+    /// Mode syntaxique utilise par le lexer (a transmettre au parser)
+    pub fn syntax_mode(&self) -> SyntaxMode {
+        self.syntax_mode
+    }
+
+    /// Noms des identifiants lus, chacun interne une seule fois
+    pub fn interner(&self) -> &Interner {
+        &self.interner
+    }
+
+    /// Avertissements emis pendant la lecture (ne produisent pas de token ERROR)
+    pub fn warnings(&self) -> &[LexerError] {
+        &self.warnings
+    }
+
+    /// Erreurs des tokens ERROR deja produits
+    pub fn errors(&self) -> &[LexerError] {
+        &self.errors
+    }
+
+    /// Dictionnaire de mots cles (anglais par defaut)
+    pub fn with_keyword_set(mut self, keyword_set: KeywordSet) -> Self {
+        self.keyword_set = keyword_set;
+        self
+    }
+
+    pub fn keyword_set(&self) -> KeywordSet {
+        self.keyword_set
+    }
+
+    /// Largeur d'une tabulation pour la verification de l'unite d'indentation (4 par defaut)
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
+    /// Associe le lexer a un fichier source (utilise dans les spans des tokens)
+    pub fn with_file_id(mut self, file_id: FileId) -> Self {
+        self.file_id = file_id;
+        self
+    }
+
+    /// Memorise la position du debut du prochain token
+    fn mark_token_start(&mut self) {
+        self.token_start = (self.current_offset, self.current_line, self.current_column);
+    }
+
+    /// Texte du token courant dans le source, du debut marque jusqu'a la position actuelle
+    fn token_text(&self) -> &'a str {
+        &self.code_source[self.token_start.0..self.current_offset]
+    }
+
+    /// Span du token courant, du debut marque jusqu'a la position actuelle
+    fn current_span(&self) -> Span {
+        let (start, line, column) = self.token_start;
+        Span::new(self.file_id, start, self.current_offset, line, column)
+    }
+
     /// La Logique de la Methode pour compter l'indentation a ete trasferer dans la methode get_token()
     /// Methode pour compter l'indentation
 
-    pub fn count_indentation(&mut self) -> usize {
-        let mut count = 0;
-        while let Some(&ch) = self.source.peek() {
-            match ch {
-                ' ' => count += 1,
-                '\t' => count += 4, // ou une autre valeur selon votre convention
+    pub fn count_indentation(&mut self) -> IndentationLevel {
+        let mut level = IndentationLevel::default();
+        while let Some(&byte) = self.bytes.get(self.current_offset) {
+            match byte {
+                b' ' => level.spaces += 1,
+                b'\t' => level.tabs += 1,
                 _ => break,
             }
             self.advance();
         }
-        count
-    }
-
-    /// Creation d'une hashmap pour les mots cles
-    /// c'est plus facile de les stocker les mots cles dans une hashmap pour les retrouver plus facilement
-    fn keywords() -> HashMap<String, Keywords> {
-        let mut keywords = HashMap::new();
-        keywords.insert("and".to_string(), Keywords::AND);
-        keywords.insert("as".to_string(), Keywords::AS);
-        keywords.insert("async".to_string(), Keywords::ASYNC);
-        keywords.insert("await".to_string(), Keywords::AWAIT);
-        keywords.insert("break".to_string(), Keywords::BREAK);
-        keywords.insert("const".to_string(), Keywords::CONST);
-        keywords.insert("continue".to_string(), Keywords::CONTINUE);
-        keywords.insert("class".to_string(), Keywords::CLASS);
-        keywords.insert("def".to_string(), Keywords::DEF);
-        keywords.insert("del".to_string(), Keywords::DEL);
-        keywords.insert("elif".to_string(), Keywords::ELIF);
-        keywords.insert("else".to_string(), Keywords::ELSE);
-        keywords.insert("enum".to_string(), Keywords::ENUM);
-        keywords.insert("except".to_string(), Keywords::EXCEPT);
-        keywords.insert("false".to_string(), Keywords::FALSE);
-        keywords.insert("finally".to_string(), Keywords::FINALLY);
-        keywords.insert("fn".to_string(), Keywords::FN);
-        keywords.insert("for".to_string(), Keywords::FOR);
-        keywords.insert("from".to_string(), Keywords::FROM);
-        keywords.insert("if".to_string(), Keywords::IF);
-        keywords.insert("impl".to_string(), Keywords::IMPL);
-        keywords.insert("import".to_string(), Keywords::IMPORT);
-        keywords.insert("in".to_string(), Keywords::IN);
-        keywords.insert("is".to_string(), Keywords::IS);
-        keywords.insert("lambda".to_string(), Keywords::LAMBDA);
-        keywords.insert("let".to_string(), Keywords::LET);
-        keywords.insert("loop".to_string(), Keywords::LOOP);
-        keywords.insert("match".to_string(), Keywords::MATCH);
-        keywords.insert("mod".to_string(), Keywords::MOD);
-        keywords.insert("mut".to_string(), Keywords::MUT);
-        keywords.insert("none".to_string(), Keywords::NONE);
-        keywords.insert("not".to_string(), Keywords::NOT);
-        keywords.insert("or".to_string(), Keywords::OR);
-        keywords.insert("pub".to_string(), Keywords::PUB);
-        keywords.insert("pass".to_string(), Keywords::PASS);
-        keywords.insert("raise".to_string(), Keywords::RAISE);
-        keywords.insert("return".to_string(), Keywords::RETURN);
-        keywords.insert("self".to_string(), Keywords::SELF);
-        keywords.insert("static".to_string(), Keywords::STATIC);
-        keywords.insert("struct".to_string(), Keywords::STRUCT);
-        keywords.insert("super".to_string(), Keywords::SUPER);
-        keywords.insert("trait".to_string(), Keywords::TRAIT);
-        keywords.insert("true".to_string(), Keywords::TRUE);
-        keywords.insert("try".to_string(), Keywords::TRY);
-        keywords.insert("type".to_string(), Keywords::TYPE);
-        keywords.insert("typeof".to_string(), Keywords::TYPEOF);
-        keywords.insert("use".to_string(), Keywords::USE);
-        keywords.insert("with".to_string(), Keywords::WITH);
-        keywords.insert("where".to_string(), Keywords::WHERE);
-        keywords.insert("while".to_string(), Keywords::WHILE);
-        keywords.insert("yield".to_string(), Keywords::YIELD);
-        //TYPE KEYWORDS
-        keywords.insert("int".to_string(), Keywords::INT);
-        keywords.insert("float".to_string(), Keywords::FLOAT);
-        keywords.insert("str".to_string(), Keywords::STR);
-        keywords.insert("bool".to_string(), Keywords::BOOL);
-        keywords.insert("char".to_string(), Keywords::CHAR);
-
-        return keywords;
-    }
-    /// creation d'une hashmap pour les operateurs
-    fn operators() -> HashMap<String, Operators> {
-        let mut operators = HashMap::new();
-        operators.insert("+".to_string(), Operators::PLUS);
-        operators.insert("-".to_string(), Operators::MINUS);
-        operators.insert("*".to_string(), Operators::STAR);
-        operators.insert("/".to_string(), Operators::SLASH);
-        operators.insert("%".to_string(), Operators::PERCENT);
-        operators.insert("==".to_string(), Operators::EQEQUAL);
-        operators.insert("!=".to_string(), Operators::NOTEQUAL);
-        operators.insert("<".to_string(), Operators::LESS);
-        operators.insert(">".to_string(), Operators::GREATER);
-        operators.insert("<=".to_string(), Operators::LESSEQUAL);
-        operators.insert(">=".to_string(), Operators::GREATEREQUAL);
-        operators.insert("=".to_string(), Operators::EQUAL);
-        operators.insert("++".to_string(), Operators::PLUSEQUAL);
-        operators.insert("--".to_string(), Operators::MINEQUAL);
-        operators.insert("**".to_string(), Operators::DOUBLESTAR);
-        //operators.insert("//".to_string(), Operators::DOUBLESLASH);
-        operators.insert("&&".to_string(), Operators::AND);
-        operators.insert("||".to_string(), Operators::OR);
-        operators.insert("!".to_string(), Operators::EXCLAMATION);
-        operators.insert("&".to_string(), Operators::AMPER);
-        operators.insert("|".to_string(), Operators::VBAR);
-        operators.insert("^".to_string(), Operators::CIRCUMFLEX);
-        operators.insert("<<".to_string(), Operators::LEFTSHIFT);
-        operators.insert(">>".to_string(), Operators::RIGHTSHIFT);
-        operators.insert("~".to_string(), Operators::TILDE);
-        operators.insert("+=".to_string(), Operators::PLUSEQUAL);
-        operators.insert("-=".to_string(), Operators::MINEQUAL);
-        operators.insert("*=".to_string(), Operators::STAREQUAL);
-        operators.insert("/=".to_string(), Operators::SLASHEQUAL);
-        operators.insert("%=".to_string(), Operators::PERCENTEQUAL);
-        operators.insert("&=".to_string(), Operators::AMPEREQUAL);
-        operators.insert("|=".to_string(), Operators::VBAREQUAL);
-        operators.insert("^=".to_string(), Operators::CIRCUMFLEXEQUAL);
-        operators.insert("<<=".to_string(), Operators::LEFTSHIFTEQUAL);
-        operators.insert(">>=".to_string(), Operators::RIGHTSHIFTEQUAL);
-        operators.insert("**=".to_string(), Operators::DOUBLESTAREQUAL);
-        operators.insert("//=".to_string(), Operators::DOUBLESLASHEQUAL);
-        operators.insert("@".to_string(), Operators::AT);
-        operators.insert("@=".to_string(), Operators::ATEQUAL);
-        operators.insert("->".to_string(), Operators::RARROW);
-        operators.insert(":=".to_string(), Operators::COLONEQUAL);
-        operators.insert("*/".to_string(), Operators::STARSLASH);
-        operators.insert("/*".to_string(), Operators::SLASHSTAR);
-        operators.insert("#".to_string(), Operators::DIESE);
-        operators.insert("?".to_string(), Operators::INTERROGATION);
-        operators.insert("_".to_string(), Operators::UNDERSCORE);
-        operators.insert("=>".to_string(), Operators::FATARROW);
-        operators.insert("..".to_string(), Operators::DOTDOT);
-        operators.insert("..=".to_string(), Operators::DOTDOTEQUAL);
-
-
-        return operators;
-    }
-
-    /// Creation d'une hashmap pour les delimiters
-    fn delimiters() -> HashMap<String, Delimiters> {
-        let mut delimiters = HashMap::new();
-        delimiters.insert("(".to_string(), Delimiters::LPAR);
-        delimiters.insert(")".to_string(), Delimiters::RPAR);
-        delimiters.insert("{".to_string(), Delimiters::LCURBRACE);
-        delimiters.insert("}".to_string(), Delimiters::RCURBRACE);
-        delimiters.insert("]".to_string(), Delimiters::RSBRACKET);
-        delimiters.insert("[".to_string(), Delimiters::LSBRACKET);
-        delimiters.insert(";".to_string(), Delimiters::SEMICOLON);
-        delimiters.insert(":".to_string(), Delimiters::COLON);
-        delimiters.insert(",".to_string(), Delimiters::COMMA);
-        delimiters.insert(".".to_string(), Delimiters::DOT);
-        delimiters.insert("...".to_string(), Delimiters::ELLIPSIS);
-        delimiters.insert("::".to_string(), Delimiters::DOUBLECOLON);
-
-        return delimiters;
+        level
+    }
+
+    /// Compare l'indentation d'une nouvelle ligne au bloc courant.
+    /// Retourne INDENT, le premier DEDENT (les suivants sont mis en attente) ou une erreur.
+    fn indentation_token(&mut self, level: IndentationLevel) -> Option<TokenType> {
+        let position = self.position();
+        let current = *self.indent_level.last().unwrap_or(&IndentationLevel::default());
+
+        match level.compare_strict(&current) {
+            None => Some(TokenType::ERROR(LexerError::tab_error(position))),
+            Some(Ordering::Equal) => None,
+            Some(Ordering::Greater) => {
+                let step = level.width(self.tab_width) - current.width(self.tab_width);
+                let unit = *self.indent_unit.get_or_insert(step);
+                if !step.is_multiple_of(unit) {
+                    let message = format!("indentation step of {} columns is not a multiple of the file's indent unit ({})", step, unit);
+                    return Some(TokenType::ERROR(LexerError::new(LexerErrorType::InvaliIndentation, message, position)));
+                }
+                self.indent_level.push(level);
+                Some(TokenType::INDENT)
+            }
+            Some(Ordering::Less) => {
+                let mut dedents = 0;
+                while self.indent_level.len() > 1 {
+                    let top = self.indent_level[self.indent_level.len() - 1];
+                    match level.compare_strict(&top) {
+                        Some(Ordering::Less) => {
+                            self.indent_level.pop();
+                            dedents += 1;
+                        }
+                        Some(_) => break,
+                        None => return Some(TokenType::ERROR(LexerError::tab_error(position))),
+                    }
+                }
+                if self.indent_level.last() != Some(&level) {
+                    // Le retrait ne retombe sur aucun bloc ouvert
+                    let message = format!("unindent to column {} does not match any outer indentation level", level.width(self.tab_width) + 1);
+                    return Some(TokenType::ERROR(LexerError::new(LexerErrorType::InvaliIndentation, message, position)));
+                }
+                self.pending_dedents = dedents - 1;
+                Some(TokenType::DEDENT)
+            }
+        }
     }
 
     /// Methode pour avancer d'un caractere
     #[allow(dead_code)]
     fn next_char(&mut self) -> Option<char> {
-        let ch = self.source.next()?;
-        self.current_char = ch;
-        if ch == '\n' {
-            self.current_line += 1;
-            self.current_column = 1;
-            self.at_line_start = true;
+        self.peek_char()?;
+        let ch = self.advance();
+        self.at_line_start = ch == '\n';
+        Some(ch)
+    }
+
+    /// Caractere qui commence a l'offset donne, decode seulement s'il n'est pas ASCII
+    fn char_at(&self, offset: usize) -> Option<char> {
+        let byte = *self.bytes.get(offset)?;
+        if byte.is_ascii() {
+            Some(byte as char)
         } else {
-            self.current_column += 1;
-            self.at_line_start = false;
+            self.code_source[offset..].chars().next()
         }
-        Some(ch)
     }
 
     /// Methode pour regarder le prochain caractere sans avancer
-    #[allow(dead_code)]
-    fn peek_char(&mut self) -> Option<char> {
-        self.source.peek().copied()
+    fn peek_char(&self) -> Option<char> {
+        self.char_at(self.current_offset)
     }
+
     /// Methode pour regarder le 2eme prochain caractere sans avancer
-    #[allow(dead_code)]
-    fn peek_next_char(&mut self) -> Option<char> {
-        self.source.clone().nth(1)
+    fn peek_next(&self) -> Option<char> {
+        self.peek_nth(1)
     }
 
-    fn peek_next(&mut self) -> Option<char> {
-     let mut iter = self.source.clone();
-        iter.next();
-        iter.peek().copied()
+    /// Regarde le caractere `n` positions plus loin (0 pour le prochain)
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        let mut offset = self.current_offset;
+        for _ in 0..n {
+            offset += self.char_at(offset)?.len_utf8();
+        }
+        self.char_at(offset)
     }
 
     /// C'est L'une de 2 methode principal avec fonction tokenize() pour obtenir le token
@@ -271,29 +346,31 @@ impl<'a> Lexer<'a> {
     /// Renvoie une Option<TokenType> représentant un seul token.
 
     /// methode pour obtenir le token
+    /// Chaque token ERROR est aussi garde dans `errors()`, la lecture continue apres l'erreur
 
     pub fn get_token(&mut self) -> Option<TokenType> {
+        let token = self.scan_token();
+        if let Some(TokenType::ERROR(error)) = &token {
+            self.errors.push(error.clone());
+        }
+        token
+    }
+
+    /// Lit le prochain token (voir get_token)
+    fn scan_token(&mut self) -> Option<TokenType> {
+        self.mark_token_start();
+        if self.pending_dedents > 0 {
+            self.pending_dedents -= 1;
+            return Some(TokenType::DEDENT);
+        }
         //Gérer l'indentation au début d'une nouvelle ligne
         if self.at_line_start && self.syntax_mode == SyntaxMode::Indentation {
             self.at_line_start = false;
             let current_indent = self.count_indentation();
-            let previous_indent = *self.indent_level.last().unwrap_or(&0);
-
-            if current_indent > previous_indent {
-                self.indent_level.push(current_indent);
-                return Some(TokenType::INDENT);
-            } else if current_indent < previous_indent {
-                while current_indent < *self.indent_level.last().unwrap_or(&0) {
-                    self.indent_level.pop();
-                    return Some(TokenType::DEDENT);
-                }
-                if current_indent != *self.indent_level.last().unwrap_or(&0) {
-                    return Some(TokenType::ERROR(LexerError::invalid_indentation(
-                        Position {
-                            line: self.current_line,
-                            column: self.current_column,
-                        },
-                    )));
+            // Une ligne vide ne change pas le niveau d'indentation
+            if !matches!(self.peek_char(), Some('\n') | Some('\r') | None) {
+                if let Some(token) = self.indentation_token(current_indent) {
+                    return Some(token);
                 }
             }
             // Si l'indentation est la même, on ne fait rien de spécial
@@ -321,11 +398,16 @@ impl<'a> Lexer<'a> {
         // }
 
         self.skip_whitespace(); // Sauter les espaces et tabulations
+        self.mark_token_start();
 
         // Vérifier le prochain caractère
         match self.peek_char() {
             Some('\n') => {
                 self.advance(); // Consomme le '\n'
+                // Entre parentheses, crochets ou accolades la ligne continue: ni NEWLINE ni indentation
+                if self.syntax_mode == SyntaxMode::Indentation && !self.nesting.is_empty() {
+                    return self.scan_token();
+                }
                 self.at_line_start = true;
 
 
@@ -336,17 +418,25 @@ impl<'a> Lexer<'a> {
                 }else {
                     // en mode Brace, on ignore le newline et passe au token suivant
                     //self.current_line += 1; // Incrementer la ligne  mais a teste plus tard
-                    return self.get_token();
+                    return self.scan_token();
 
                 }
             }
 
             Some('0'..='9') => Some(self.lex_number()),
-            Some('a'..='z') | Some('A'..='Z') | Some('_') => Some(self.lex_identifier_or_keyword()),
+            Some(_) if self.string_prefix().is_some() => {
+                let (prefix, length) = self.string_prefix().unwrap();
+                for _ in 0..length {
+                    self.advance(); // Consomme le prefixe r, b ou f
+                }
+                Some(self.lex_string_with_prefix(prefix))
+            }
+            Some(ch) if is_identifier_start(ch) => Some(self.lex_identifier_or_keyword()),
+            Some('\'') if self.lifetime_follows() => Some(self.lex_lifetime()),
             Some('"') | Some('\'') => Some(self.lex_string()),
             Some('#') => Some(self.lex_comment()),
             Some('/') => {
-                if let Some(next_char) = self.peek_next_char() {
+                if let Some(next_char) = self.peek_next() {
                     match next_char {
                         '/' | '*' => Some(self.lex_comment()),
                         _ => self.lex_operator(),
@@ -355,12 +445,35 @@ impl<'a> Lexer<'a> {
                     self.lex_operator()
                 }
             }
-            Some(ch) if self.delimiters.contains_key(&ch.to_string()) => Some(self.lex_delimiter()),
+            // `\` en fin de ligne: continuation explicite
+            Some('\\') if matches!(self.peek_next(), Some('\n' | '\r')) => {
+                self.advance(); // Consomme le '\'
+                if self.peek_char() == Some('\r') {
+                    self.advance();
+                }
+                if self.peek_char() == Some('\n') {
+                    self.advance();
+                }
+                self.scan_token()
+            }
+            Some(ch) if delimiter(ch).is_some() => {
+                let position = self.position();
+                let delimiter = self.lex_delimiter();
+                self.track_nesting(&delimiter, position);
+                Some(delimiter)
+            }
             Some(ch) if !ch.is_alphanumeric() => self.lex_operator(),
             None => {
                 ////////////à surveiller si c'est correct et pas redondant
                 // Fin du fichier (EOF)
-                if !self.indent_level.is_empty() && *self.indent_level.last().unwrap() > 0 {
+                if self.syntax_mode == SyntaxMode::Indentation {
+                    if let Some((bracket, position)) = self.nesting.pop() {
+                        // Le plus interne est signale, les autres ne produiraient que du bruit
+                        self.nesting.clear();
+                        return Some(TokenType::ERROR(LexerError::unterminated_bracket(bracket, position)));
+                    }
+                }
+                if self.indent_level.len() > 1 {
                     self.indent_level.pop();
                     return Some(TokenType::DEDENT);
                 } else {
@@ -383,218 +496,523 @@ impl<'a> Lexer<'a> {
             ////////////à surveiller si c'est correct et pas redondant
 
             // None => Some(TokenType::EOF),   //Ajouter nouvelement
-            _ => Some(self.lex_unknown()),
+            Some(ch) => {
+                // Caractere alphanumerique qui ne peut pas commencer un identifiant (`²`, `٣`, ...)
+                self.advance();
+                Some(TokenType::ERROR(LexerError::invalid_character(ch, self.position())))
+            }
         }
     }
 
+    /// Methode pour les litteraux numeriques:
+    /// `123`, `1_000`, `0x1F`, `0b1010`, `0o17`, `3.14`, `1e-9`, `2.5e3` et les suffixes `10i32`, `2.5f32`
     fn lex_number(&mut self) -> TokenType {
-        self.current_token_text.clear();
-
-        if self.peek_char() == Some('0') {
-            if let Some(next_char) = self.peek_next() {
-                if next_char == 'x' || next_char == 'X' {
-                    let ch1 = self.advance(); // '0'
-                    let ch2 = self.advance(); // 'x' ou 'X'
-                    self.current_token_text.push(ch1);
-                    self.current_token_text.push(ch2);
-                    return self.lex_hexadecimal();
-                }
-            }
-        }
-
-        let mut number = String::new();
-        let mut dot_count = 0;
-
-        while let Some(&ch) = self.source.peek() {
-            if ch.is_digit(10) {
-                let digit = self.advance();
-                number.push(digit);
-                self.current_token_text.push(digit);
-            } else if ch == '.' {
-                // Vérifier si le '.' est suivi d'un chiffre pour un nombre flottant
-                if dot_count == 0 {
-                    if let Some(next_ch) = self.peek_next_char() {
-                        if next_ch.is_digit(10) {
-                            let dot = self.advance();
-                            number.push(dot);
-                            self.current_token_text.push(dot);
-                            dot_count += 1;
-                        } else {
-                            // Le '.' n'est pas suivi d'un chiffre, il pourrait faire partie d'un opérateur
-                            break;
-                        }
-                    } else {
-                        // Fin de l'entrée après '.', ce qui est une erreur pour un float
-                        break;
-                    }
-                } else {
-                    // Deuxième point trouvé, c'est une erreur pour un float
-                    break;
-                }
-            } else {
-                break;
+        let radix = match (self.peek_char(), self.peek_next()) {
+            (Some('0'), Some('x' | 'X')) => 16,
+            (Some('0'), Some('b' | 'B')) => 2,
+            (Some('0'), Some('o' | 'O')) => 8,
+            _ => 10,
+        };
+        if radix != 10 {
+            self.advance();
+            self.advance();
+        }
+
+        let mut error: Option<LexerError> = None;
+        let mut digits = match self.lex_digits(radix, radix != 10) {
+            Ok(digits) => digits,
+            Err(e) => {
+                error.get_or_insert(e);
+                String::new()
             }
+        };
+        if digits.is_empty() && error.is_none() {
+            let text = self.token_text().to_string();
+            return match radix {
+                16 => self.create_error(LexerErrorType::InvalidHexadecimal(text)),
+                _ => self.create_error(LexerErrorType::InvalidInteger(text)),
+            };
         }
 
-        if number.is_empty() {
-            return self.create_error(LexerErrorType::InvalidInteger(number));
+        // Partie decimale: le '.' doit etre suivi d'un chiffre (sinon `1..2` ou `x.0.1`)
+        let mut is_float = false;
+        if radix == 10 && self.peek_char() == Some('.') && self.peek_next().is_some_and(|c| c.is_ascii_digit()) {
+            self.advance();
+            digits.push('.');
+            is_float = true;
+            match self.lex_digits(10, false) {
+                Ok(fraction) => digits.push_str(&fraction),
+                Err(e) => { error.get_or_insert(e); }
+            }
         }
 
-        if dot_count > 0 {
-            match number.parse::<f64>() {
-                Ok(value) => TokenType::FLOAT { value },
-                Err(_) => self.create_error(LexerErrorType::InvalidFloat(number)),
+        // Exposant: e/E, signe optionnel, au moins un chiffre
+        if radix == 10 && matches!(self.peek_char(), Some('e' | 'E')) {
+            let after = self.peek_next();
+            let signed = matches!(after, Some('+' | '-'));
+            let exponent_digit = if signed { self.peek_nth(2) } else { after };
+            if exponent_digit.is_some_and(|c| c.is_ascii_digit()) {
+                self.advance();
+                digits.push('e');
+                if signed {
+                    let sign = self.advance();
+                    digits.push(sign);
+                }
+                is_float = true;
+                match self.lex_digits(10, false) {
+                    Ok(exponent) => digits.push_str(&exponent),
+                    Err(e) => { error.get_or_insert(e); }
+                }
+            } else {
+                let position = self.position();
+                self.advance();
+                let error_type = LexerErrorType::InvalidFloat(self.token_text().to_string());
+                error.get_or_insert(LexerError::new(error_type, "missing exponent digits".to_string(), position));
             }
-        } else {
-            match number.parse::<i64>() {
-                Ok(value) => TokenType::INTEGER {
-                    value: value.into(),
-                },
-                Err(_) => self.create_error(LexerErrorType::InvalidInteger(number)),
+        }
+
+        // Suffixe de type, ou `j` pour un imaginaire
+        let mut suffix = None;
+        let mut is_imaginary = false;
+        if self.peek_char().is_some_and(|c| c.is_alphabetic() || c == '_') {
+            let position = self.position();
+            let mut text = String::new();
+            while let Some(c) = self.peek_char().filter(|c| c.is_alphanumeric() || *c == '_') {
+                self.advance();
+                text.push(c);
+            }
+            match NumberSuffix::from_text(&text) {
+                None if matches!(text.as_str(), "j" | "J") && radix != 10 => {
+                    error.get_or_insert(LexerError::new(
+                        LexerErrorType::InvalidNumberSuffix(text.clone()),
+                        format!("imaginary suffix '{}' is not allowed on a base {} literal", text, radix),
+                        position,
+                    ));
+                }
+                None if matches!(text.as_str(), "j" | "J") => is_imaginary = true,
+                Some(s) if s.is_float() && (radix != 10) => {
+                    error.get_or_insert(LexerError::new(
+                        LexerErrorType::InvalidNumberSuffix(text.clone()),
+                        format!("suffix '{}' is not allowed on a base {} literal", text, radix),
+                        position,
+                    ));
+                }
+                Some(s) if !s.is_float() && is_float => {
+                    error.get_or_insert(LexerError::new(
+                        LexerErrorType::InvalidNumberSuffix(text.clone()),
+                        format!("integer suffix '{}' on a float literal", text),
+                        position,
+                    ));
+                }
+                Some(s) => {
+                    is_float |= s.is_float();
+                    suffix = Some(s);
+                }
+                None => {
+                    let error_type = LexerErrorType::InvalidNumberSuffix(text.clone());
+                    error.get_or_insert(LexerError::new(error_type.clone(), error_type.to_string(), position));
+                }
             }
         }
-    }
 
-    // savoir si c'est un  hexdigit
-    fn is_hex_digit(ch: char) -> bool {
-        ch.is_digit(16)
-    }
+        if let Some(error) = error {
+            return TokenType::ERROR(error);
+        }
 
-    fn lex_hexadecimal(&mut self) -> TokenType {
-        let mut hex_number = self.current_token_text.clone(); // Déjà contient "0x" ou "0X"
+        if is_imaginary {
+            return match digits.parse::<f64>() {
+                Ok(value) => TokenType::IMAGINARY { value },
+                Err(_) => self.create_error(LexerErrorType::InvalidFloat(self.token_text().to_string())),
+            };
+        }
+        if is_float {
+            return match digits.parse::<f64>() {
+                Ok(value) => TokenType::FLOAT { value, suffix },
+                Err(_) => self.create_error(LexerErrorType::InvalidFloat(self.token_text().to_string())),
+            };
+        }
+        // Entier de taille arbitraire, le debordement est verifie par le type checker
+        match BigInt::parse_bytes(digits.as_bytes(), radix) {
+            Some(value) => TokenType::INTEGER { value, suffix },
+            None => self.create_error(LexerErrorType::InvalidInteger(digits)),
+        }
+    }
 
-        while let Some(&ch) = self.source.peek() {
-            if Self::is_hex_digit(ch) {
-                hex_number.push(self.advance());
-            } else {
-                break;
+    /// Lit une suite de chiffres dans la base donnee, avec `_` comme separateur.
+    /// Un `_` doit etre entre deux chiffres (ou juste apres le prefixe 0x/0b/0o, ou avant un suffixe).
+    /// Un chiffre decimal hors de la base (`0b102`) est une erreur
+    fn lex_digits(&mut self, radix: u32, after_prefix: bool) -> Result<String, LexerError> {
+        let mut digits = String::new();
+        let mut error = None;
+        let mut previous_separator = false;
+        let mut first = true;
+
+        loop {
+            let position = self.position();
+            match self.peek_char() {
+                Some('_') => {
+                    self.advance();
+                    if previous_separator || (first && !after_prefix) {
+                        error.get_or_insert(LexerError::new(
+                            LexerErrorType::InvalidNumberSeparator(self.token_text().to_string()),
+                            "'_' must separate two digits".to_string(),
+                            position,
+                        ));
+                    }
+                    previous_separator = true;
+                }
+                Some(c) if c.is_digit(radix) || (radix < 10 && c.is_ascii_digit()) => {
+                    self.advance();
+                    if !c.is_digit(radix) {
+                        error.get_or_insert(LexerError::new(
+                            LexerErrorType::InvalidDigit { digit: c, radix },
+                            format!("invalid digit '{}' in base {} literal", c, radix),
+                            position,
+                        ));
+                    }
+                    digits.push(c);
+                    previous_separator = false;
+                }
+                _ => break,
             }
+            first = false;
         }
 
-        self.current_token_text = hex_number.clone();
-
-        if hex_number.len() == 2 {
-            // Seulement "0x" ou "0X"
-            self.create_error(LexerErrorType::InvalidHexadecimal(hex_number))
-        } else {
-            match u64::from_str_radix(&hex_number[2..], 16) {
-                // Skip "0x"
-                Ok(value) => TokenType::HEXADECIMAL { value },
-                Err(_) => self.create_error(LexerErrorType::InvalidHexadecimal(hex_number)),
-            }
+        // `0xFF_u8`: un `_` peut preceder le suffixe
+        if previous_separator && !self.peek_char().is_some_and(|c| c.is_alphabetic()) {
+            error.get_or_insert(LexerError::new(
+                LexerErrorType::InvalidNumberSeparator(self.token_text().to_string()),
+                "'_' must separate two digits".to_string(),
+                self.position(),
+            ));
+        }
+        match error {
+            Some(error) => Err(error),
+            None => Ok(digits),
         }
     }
 
     //fn lex_identifier(){}
     /// Methode pour les different types de token de Type Identifier ou Keyword
     fn lex_identifier_or_keyword(&mut self) -> TokenType {
-        self.current_token_text.clear();
-        while let Some(&ch) = self.source.peek() {
-            if ch.is_alphanumeric() || ch == '_' {
-                let ch = self.advance();
-                self.current_token_text.push(ch); // Ajoute le caractère à la chaîne de texte du token
+        let position = self.position();
+        // Les caracteres ASCII sont lus octet par octet, les autres decodes au besoin
+        while let Some(&byte) = self.bytes.get(self.current_offset) {
+            if byte.is_ascii_alphanumeric() || byte == b'_' {
+                self.current_offset += 1;
+                self.current_column += 1;
+            } else if !byte.is_ascii() && self.peek_char().is_some_and(is_identifier_continue) {
+                self.advance();
             } else {
                 break;
             }
         }
 
-        if let Some(keyword) = self.keywords.get(&self.current_token_text) {
-            TokenType::KEYWORD(keyword.clone()) // c'est un mot clé
+        let text = self.token_text();
+        let normalized;
+        let name = if text.is_ascii() {
+            text
         } else {
-            TokenType::IDENTIFIER {
-                name: self.current_token_text.clone(),
-            } // sinon c'est un identifiant
+            normalized = normalize_identifier(text);
+            &normalized
+        };
+        if let Some(keyword) = self.keyword_set.lookup(name) {
+            return TokenType::KEYWORD(keyword); // c'est un mot clé
+        }
+
+        // Seul un nom non ASCII peut contenir un caractere confusable
+        if !name.is_ascii() {
+            if let Some((found, expected)) = find_confusable(name) {
+                let warning = LexerErrorType::ConfusableCharacter { found, expected };
+                let message = format!("identifier '{}': {}", name, warning);
+                self.warnings.push(LexerError::new(warning, message, position));
+            }
         }
+        let symbol = self.interner.intern(name);
+        TokenType::IDENTIFIER { name: self.interner.name(symbol).clone() } // sinon c'est un identifiant
+    }
+
+    /// Au `'`: vrai si c'est un lifetime et non une chaine ou un caractere.
+    /// Le nom ne doit pas etre suivi d'un `'`; `'static` est toujours un lifetime, sinon
+    /// il faut un `&`, `<` ou `+` avant et une fin de borne ou un type apres,
+    /// un `,` ou `:` avant et `,` `>` `+` `)` `;` `{` `:` apres, ou `where` avant et `:` apres
+    fn lifetime_follows(&self) -> bool {
+        let rest = &self.code_source[self.current_offset + 1..];
+        if !rest.chars().next().is_some_and(is_identifier_start) {
+            return false;
+        }
+        let length = rest.find(|ch: char| !is_identifier_continue(ch)).unwrap_or(rest.len());
+        let after = rest[length..].trim_start_matches([' ', '\t']);
+        if after.starts_with('\'') {
+            return false; // 'a' ou 'hello '
+        }
+        let before = self.code_source[..self.current_offset].trim_end_matches([' ', '\t']);
+        let closes_bound = after.is_empty() || after.starts_with([',', '>', '+', ')', ';', '{', ':', '\n', '\r']);
+        match before.chars().last() {
+            _ if &rest[..length] == "static" => true,
+            // `&'a str`, `T + 'a where`: un mot peut suivre, sauf si la ligne ferme une chaine (`'hello world'`)
+            Some('&' | '<' | '+') => closes_bound
+                || (after.starts_with(|ch: char| is_identifier_start(ch) || "[(&*".contains(ch)) && !closes_on_line(after)),
+            // `<'a, 'b: 'a>`: le lifetime peut porter une borne
+            Some(',' | ':') => after.starts_with([',', '>', '+', ')', ';', '{', ':']),
+            // `where 'a: 'b`
+            _ if self.keyword_set.lookup(last_word(before)) == Some(Keywords::WHERE) => after.starts_with(':'),
+            _ => false,
+        }
+    }
+
+    /// Lit un lifetime `'a` (l'apostrophe est retiree du nom)
+    fn lex_lifetime(&mut self) -> TokenType {
+        self.advance(); // Consomme l'apostrophe
+        while self.peek_char().is_some_and(is_identifier_continue) {
+            self.advance();
+        }
+        let name = &self.token_text()[1..];
+        let name = if name.is_ascii() { name.to_string() } else { normalize_identifier(name) };
+        TokenType::LIFETIME { name }
     }
 
     fn lex_string(&mut self) -> TokenType {
-        self.current_token_text.clear();
+        self.lex_string_with_prefix(StringPrefix::default())
+    }
+
+    /// Regarde si le token courant est une chaine prefixee (`r"`, `b"`, `f"`, `rb"`, `fr"`, ...)
+    /// et retourne le prefixe avec sa longueur
+    fn string_prefix(&self) -> Option<(StringPrefix, usize)> {
+        let mut prefix = StringPrefix::default();
+        for (index, ch) in self.code_source[self.current_offset..].chars().enumerate() {
+            let flag = match ch {
+                '"' | '\'' if index > 0 => return Some((prefix, index)),
+                'r' | 'R' => &mut prefix.raw,
+                'b' | 'B' => &mut prefix.bytes,
+                'f' | 'F' => &mut prefix.formatted,
+                _ => return None,
+            };
+            if *flag || index >= 2 {
+                return None;
+            }
+            *flag = true;
+            if prefix.bytes && prefix.formatted {
+                return None;
+            }
+        }
+        None
+    }
 
+    /// Lit une chaine `"..."`, `'...'` ou `"""..."""` avec son prefixe eventuel.
+    /// Les echappements sont verifies pendant la lecture, puis appliques apres
+    /// le retrait de l'indentation des chaines sur plusieurs lignes.
+    fn lex_string_with_prefix(&mut self, prefix: StringPrefix) -> TokenType {
         let quote = self.advance(); // Consomme le premier guillemet
-        let mut value = String::new();
-        let mut is_escaped = false;
-
-        while let Some(&ch) = self.source.peek() {
-            self.advance(); // Consomme le caractère actuel
-            if is_escaped {
-                match ch {
-                    'n' => value.push('\n'),
-                    't' => value.push('\t'),
-                    'r' => value.push('\r'),
-                    '\\' => value.push('\\'),
-                    '"' => value.push('"'),
-                    '\'' => value.push('\''),
-                    '\n' => {
-                        // Ignorer le saut de ligne après un backslash
-                        while let Some(&next_ch) = self.source.peek() {
-                            if next_ch.is_whitespace() && next_ch != '\n' {
-                                self.advance();
-                            } else {
-                                break;
-                            }
-                        }
+        let triple = self.peek_char() == Some(quote) && self.peek_next() == Some(quote);
+        if triple {
+            self.advance();
+            self.advance();
+        }
+        let content_start = self.current_offset;
+
+        // La premiere erreur est retournee une fois la chaine entierement lue
+        let mut content = String::new();
+        let mut error: Option<LexerError> = None;
+        let mut first_line_end = None;
+        loop {
+            let Some(ch) = self.peek_char() else {
+                // Si nous sortons de la boucle sans avoir trouvé de guillemet fermant
+                return self.unterminated_string(first_line_end);
+            };
+            if ch == '\n' && !triple {
+                // Une chaine simple ne continue pas sur la ligne suivante: la lecture reprend au `\n`
+                return self.unterminated_string(None);
+            }
+            if ch == '\n' && first_line_end.is_none() {
+                first_line_end = Some((self.current_offset, self.current_line, self.current_column));
+            }
+            let position = Position { line: self.current_line, column: self.current_column };
+            self.advance();
+
+            if ch == '\\' {
+                content.push(ch);
+                if prefix.raw {
+                    // `\"` ne termine pas une chaine brute, les deux caracteres sont gardes
+                    if let Some(next) = self.peek_char().filter(|&c| c == quote || c == '\\') {
+                        self.advance();
+                        content.push(next);
                     }
-                    _ => value.push(ch),
+                } else if let Err(escape_error) = self.lex_escape(prefix.bytes, &mut content) {
+                    let message = escape_error.to_string();
+                    error.get_or_insert(LexerError::new(escape_error, message, position));
                 }
-                is_escaped = false;
-            } else if ch == '\\' {
-                is_escaped = true;
+            } else if ch == quote && !triple {
+                break;
+            } else if ch == quote && self.peek_char() == Some(quote) && self.peek_next() == Some(quote) {
+                self.advance();
+                self.advance();
+                break;
+            } else if prefix.bytes && !ch.is_ascii() {
+                error.get_or_insert(LexerError::invalid_character(ch, position));
+            } else {
+                content.push(ch);
             }
-                //But: 'C'->Char, "C"->String
-            else if ch == '\'' && value.len() == 1 {        //Note !!!!!!
-                // petit probleme  ici^  :Si  value.len() == 1 , c'est un caractère est parsé, mais ne
-                //s'affiche pas et si value.len() == 3  le caractère est parsé et s'affiche
+        }
+
+        if let Some(error) = error {
+            return TokenType::ERROR(error);
+        }
+        if prefix.formatted {
+            return self.format_string_token(&content, content_start, triple, prefix.raw);
+        }
+        if triple {
+            content = strip_indentation(&content);
+        }
+        let value = if prefix.raw { content } else { unescape(&content) };
 
-                //probleme resolu!!! la ligne suivante resoud le probleme
+        if prefix.bytes {
+            // Les caracteres sont ASCII ou viennent d'un `\xHH`, ils tiennent sur un octet
+            return TokenType::BYTES { value: value.chars().map(|c| c as u8).collect() };
+        }
+        if prefix.raw {
+            return TokenType::STRING { value, kind: StringKind::RAW };
+        }
+        //But: 'C'->Char, "C"->String
+        if quote == '\'' && !triple && value.chars().count() == 1 {
+            return TokenType::CHAR {
+                value: value.chars().next().unwrap()
+            };
+        }
+        TokenType::STRING {
+            value,
+            kind: StringKind::NORMAL,
+        }
+    }
+
+    /// Decoupe une f-string lue. Le decoupage se fait sur le texte source (echappements
+    /// non appliques) pour que chaque expression garde sa position exacte dans le fichier.
+    fn format_string_token(&self, content: &str, content_start: usize, triple: bool, raw: bool) -> TokenType {
+        let (text, offsets) = if triple {
+            strip_indentation_with_offsets(content)
+        } else {
+            (content.to_string(), (0..=content.len()).collect())
+        };
+        let (_, line, column) = self.token_start;
+        let parts = match split_format_string(&text, Position { line, column }, raw) {
+            Ok(parts) => parts,
+            Err(error) => return TokenType::ERROR(error),
+        };
+
+        let apply_escapes = |text: &str| if raw { text.to_string() } else { unescape(text) };
+        let parts = parts.into_iter()
+            .map(|part| match part {
+                FStringPart::Literal(literal) => FStringPart::Literal(apply_escapes(&literal)),
+                FStringPart::Expression { source, format_spec, span } => FStringPart::Expression {
+                    source: apply_escapes(&source),
+                    format_spec,
+                    span: self.source_span(content_start + offsets[span.start], content_start + offsets[span.end]),
+                },
+            })
+            .collect();
+        TokenType::STRING { value: apply_escapes(&text), kind: StringKind::FORMATTED(parts) }
+    }
+
+    /// Span d'un morceau du token courant, situe par ses offsets dans le source
+    fn source_span(&self, start: usize, end: usize) -> Span {
+        let (token_offset, line, column) = self.token_start;
+        let before = &self.code_source[token_offset..start];
+        let (line, column) = match before.rfind('\n') {
+            Some(newline) => (line + before.matches('\n').count(), before[newline + 1..].chars().count() + 1),
+            None => (line, column + before.chars().count()),
+        };
+        Span::new(self.file_id, start, end, line, column)
+    }
+
+    /// Chaine sans guillemet fermant: l'erreur est placee sur l'ouverture et la lecture
+    /// reprend a la fin de la ligne ouvrante, pour ne pas avaler le reste du fichier.
+    /// `first_line_end` ramene une chaine triple non fermee a la fin de sa premiere ligne
+    fn unterminated_string(&mut self, first_line_end: Option<(usize, usize, usize)>) -> TokenType {
+        if let Some((offset, line, column)) = first_line_end {
+            self.current_offset = offset;
+            self.current_line = line;
+            self.current_column = column;
+        }
+        let (_, line, column) = self.token_start;
+        let error = LexerErrorType::UnterminatedString;
+        TokenType::ERROR(LexerError::new(error.clone(), error.to_string(), Position { line, column }))
+    }
 
-                self.current_token_text = value.clone();
-                return TokenType::CHAR {
-                    value: value.chars().next().unwrap()
+    /// Verifie la sequence d'echappement qui suit un `\` et la recopie telle quelle dans `content`.
+    /// Echappements acceptes: \n \t \r \0 \\ \" \' \xHH \u{H...} et `\` en fin de ligne
+    fn lex_escape(&mut self, bytes: bool, content: &mut String) -> Result<(), LexerErrorType> {
+        let Some(ch) = self.peek_char() else {
+            return Err(LexerErrorType::InvalidEscape("\\".to_string()));
+        };
+        self.advance();
+        content.push(ch);
+        match ch {
+            'n' | 't' | 'r' | '0' | '\\' | '"' | '\'' | '\n' => Ok(()),
+            'x' => {
+                let mut digits = String::new();
+                while digits.len() < 2 {
+                    match self.peek_char() {
+                        Some(c) if c.is_ascii_hexdigit() => digits.push(self.advance()),
+                        _ => break,
+                    }
+                }
+                content.push_str(&digits);
+                if digits.len() != 2 {
+                    return Err(LexerErrorType::InvalidEscape(format!("\\x{}", digits)));
+                }
+                // Au-dela de \x7F, seules les chaines d'octets acceptent \xHH; ailleurs il faut \u{...}
+                if !bytes && u8::from_str_radix(&digits, 16).is_ok_and(|byte| byte > 0x7F) {
+                    return Err(LexerErrorType::InvalidEscape(format!("\\x{}", digits)));
                 }
+                Ok(())
             }
-
-            else if ch == quote {
-                self.current_token_text = value.clone();
-                return TokenType::STRING {
-                    value,
-                    kind: StringKind::NORMAL,
-                };
-            } else {
-                value.push(ch);
+            'u' if !bytes => {
+                let mut sequence = String::from("\\u");
+                if self.peek_char() == Some('{') {
+                    sequence.push(self.advance());
+                    while let Some(c) = self.peek_char().filter(|c| c.is_ascii_hexdigit()) {
+                        self.advance();
+                        sequence.push(c);
+                    }
+                    if self.peek_char() == Some('}') {
+                        sequence.push(self.advance());
+                    }
+                }
+                content.push_str(&sequence[2..]);
+                let digits = sequence.strip_prefix("\\u{").and_then(|s| s.strip_suffix('}'));
+                match digits {
+                    Some(hex) if (1..=6).contains(&hex.len())
+                        && u32::from_str_radix(hex, 16).ok().and_then(char::from_u32).is_some() => Ok(()),
+                    _ => Err(LexerErrorType::InvalidEscape(sequence)),
+                }
             }
+            _ => Err(LexerErrorType::InvalidEscape(format!("\\{}", ch))),
         }
-
-        // Si nous sortons de la boucle sans avoir trouvé de guillemet fermant
-        self.create_error(LexerErrorType::UnterminatedString)
     }
 
 
     /// Methode pour les differents types de token de Type Operator
     fn lex_operator(&mut self) -> Option<TokenType> {
-        self.current_token_text.clear();
-
-        // Regardez les deux prochains caractères pour vérifier les opérateurs composés
+        let start = self.current_offset;
         let first_char = self.advance();
-        self.current_token_text.push(first_char);
-        let mut op = self.current_token_text.clone();
 
-        if let Some(&next_char) = self.source.peek() {
-            op.push(next_char);
-            if self.operators.contains_key(&op) {
+        // Regardez le prochain caractère pour vérifier les opérateurs composés
+        if let Some(next_char) = self.peek_char() {
+            let end = self.current_offset + next_char.len_utf8();
+            if let Some(operator) = operator(&self.code_source[start..end]) {
                 self.advance();
-                self.current_token_text.push(next_char);
-                return Some(TokenType::OPERATOR(self.operators[&op].clone()));
+                return Some(TokenType::OPERATOR(operator));
             }
         }
 
         // Si ce n'est pas un opérateur composé, vérifiez l'opérateur simple
-        if let Some(operator) = self.operators.get(&self.current_token_text) {
-            return Some(TokenType::OPERATOR(operator.clone()));
+        if let Some(operator) = operator(&self.code_source[start..self.current_offset]) {
+            return Some(TokenType::OPERATOR(operator));
         }
 
-        // Si l'opérateur n'est pas reconnu, retournez une erreur
-        Some(TokenType::ERROR(LexerError::invalid_token(
-            &self.current_token_text,
+        // Si l'opérateur n'est pas reconnu, c'est un caractere invalide (`$`, `€`, espace insecable, ...)
+        Some(TokenType::ERROR(LexerError::invalid_character(
+            first_char,
             Position {
                 line: self.current_line,
                 column: self.current_column,
@@ -603,61 +1021,53 @@ impl<'a> Lexer<'a> {
     }
 
 
+    /// Met a jour la pile des parentheses, crochets et accolades ouverts
+    fn track_nesting(&mut self, delimiter: &TokenType, position: Position) {
+        let (opening, closing) = match delimiter {
+            TokenType::DELIMITER(Delimiters::LPAR) => (Some('('), None),
+            TokenType::DELIMITER(Delimiters::LSBRACKET) => (Some('['), None),
+            TokenType::DELIMITER(Delimiters::LCURBRACE) => (Some('{'), None),
+            TokenType::DELIMITER(Delimiters::RPAR) => (None, Some('(')),
+            TokenType::DELIMITER(Delimiters::RSBRACKET) => (None, Some('[')),
+            TokenType::DELIMITER(Delimiters::RCURBRACE) => (None, Some('{')),
+            _ => (None, None),
+        };
+        if let Some(bracket) = opening {
+            self.nesting.push((bracket, position));
+        } else if closing.is_some() && self.nesting.last().map(|(bracket, _)| *bracket) == closing {
+            // Une fermeture qui ne correspond pas est laissee au parser
+            self.nesting.pop();
+        }
+    }
+
     /// Methode pour les differents types de token de Type Delimiter
     fn lex_delimiter(&mut self) -> TokenType {
-
-        self.current_token_text.clear();
-
         let first_char = self.advance();
-        self.current_token_text.push(first_char);
-
-        if let Some(&next_char) = self.source.peek(){
-            let mut combined = self.current_token_text.clone();
-            combined.push(next_char);
-
-            // verifie pour "::"
-            if combined == "::"{
+        match (first_char, self.peek_char()) {
+            (':', Some(':')) => {
                 self.advance();
-                self.current_token_text = combined;
-                return TokenType::DELIMITER(Delimiters::DOUBLECOLON);
+                TokenType::DELIMITER(Delimiters::DOUBLECOLON)
             }
-
-            if first_char == '.' {
-                if let Some(&next_char) = self.source.peek(){
-                    if next_char == '.'{
+            ('.', Some('.')) => {
+                self.advance();
+                match self.peek_char() {
+                    Some('.') => {
                         self.advance();
-                        self.current_token_text.push(next_char);
-
-                        if let Some(&third_char) = self.source.peek(){
-                            if third_char == '.'{
-                                self.advance();
-                                self.current_token_text.push(third_char);
-                                return TokenType::DELIMITER(Delimiters::ELLIPSIS);
-                            }else if third_char == '=' {
-                                self.advance();
-                                self.current_token_text.push(third_char);
-                                return TokenType::OPERATOR(Operators::DOTDOTEQUAL);
-                            }
-                        }
-                        return TokenType::OPERATOR(Operators::DOTDOT);
-                    }else {
-                        return TokenType::DELIMITER(Delimiters::DOT);
+                        TokenType::DELIMITER(Delimiters::ELLIPSIS)
+                    }
+                    Some('=') => {
+                        self.advance();
+                        TokenType::OPERATOR(Operators::DOTDOTEQUAL)
                     }
+                    _ => TokenType::OPERATOR(Operators::DOTDOT),
                 }
             }
-
-        }
-        if let Some(delimiter) = self.delimiters.get(&self.current_token_text) {
-            return TokenType::DELIMITER(delimiter.clone());
-        } else {
-            return TokenType::UNKNOWN;
+            _ => delimiter(first_char).map_or(TokenType::UNKNOWN, TokenType::DELIMITER),
         }
-
     }
 
     /// Methode pour les differents types de token de Type Comment # ou // ou /* */
     fn lex_comment(&mut self) -> TokenType {
-        self.current_token_text.clear();
         let start_char = self.advance(); // Consomme le '/' ou le '#'
         let mut comment = String::new();
 
@@ -672,7 +1082,7 @@ impl<'a> Lexer<'a> {
                 }
             }
             '/' => {
-                if let Some(&next_char) = self.source.peek() {
+                if let Some(next_char) = self.peek_char() {
                     return match next_char {
                         '/' => {
                             self.advance(); // Consomme le deuxième '/'
@@ -734,7 +1144,6 @@ impl<'a> Lexer<'a> {
             }
         }
 
-        self.current_token_text = comment.clone();
         return TokenType::COMMENT(comment);
     }
 
@@ -742,7 +1151,8 @@ impl<'a> Lexer<'a> {
 
     /// Methode pour avancer d'un character
     fn advance(&mut self) -> char {
-        let ch = self.source.next().unwrap();
+        let ch = self.peek_char().unwrap();
+        self.current_offset += ch.len_utf8();
         if ch == '\n' {
             self.current_line += 1; // Incrémenter le numéro de ligne
             self.current_column = 1; // Réinitialiser le numéro de colonne
@@ -755,7 +1165,7 @@ impl<'a> Lexer<'a> {
     /// Methode pour sauter les espaces
     fn skip_whitespace(&mut self) {
         // amelioration pour qu'en  mode brace  le NEWLINE soit ignoré
-        while let Some(&ch) = self.source.peek(){
+        while let Some(ch) = self.peek_char() {
             if ch.is_whitespace(){
                 if ch == '\n' {
                     if self.syntax_mode == SyntaxMode::Braces{
@@ -783,15 +1193,9 @@ impl<'a> Lexer<'a> {
     pub fn tokenize(&mut self) -> Vec<Token> {
         let mut tokens = Vec::new();
         while let Some(token_type) = self.get_token() {
-            let token = Token::new(
-                self.current_token_text.clone(),
-                token_type.clone(),
-                self.current_line,
-                self.current_column,
-            );
-            tokens.push(token);
-            self.current_token_text.clear();
-            if matches!(token_type, TokenType::EOF) {
+            let is_eof = matches!(token_type, TokenType::EOF);
+            tokens.push(Token::new(token_type, self.current_span()));
+            if is_eof {
                 break;
             }
         }
@@ -799,27 +1203,38 @@ impl<'a> Lexer<'a> {
         if self.syntax_mode == SyntaxMode::Indentation {
             while self.indent_level.len() > 1 {
                 self.indent_level.pop();
-                tokens.push(Token::new(
-                    String::new(),
-                    TokenType::DEDENT,
-                    self.current_line,
-                    self.current_column,
-                ));
+                self.mark_token_start();
+                tokens.push(Token::new(TokenType::DEDENT, self.current_span()));
             }
         }
 
         return tokens;
     }
 
-    /// methode pour les differents types de token de Type Unknown
+    /// Tokenise tout le source en continuant apres chaque erreur.
+    /// Les tokens ERROR sont retires du flux et toutes les erreurs sont retournees a part
+    pub fn tokenize_with_errors(&mut self) -> (Vec<Token>, Vec<LexerError>) {
+        let mut tokens = self.tokenize();
+        tokens.retain(|token| !matches!(token.token_type, TokenType::ERROR(_)));
+        (tokens, self.errors.clone())
+    }
 
-    fn lex_unknown(&mut self) -> TokenType {
-        let ch = self.advance();
-        self.current_token_text = ch.to_string();
-        TokenType::UNKNOWN
+    /// Tokenise en gardant espaces, commentaires et sauts de ligne comme trivia.
+    /// `to_source` sur le resultat redonne le source a l'identique.
+    pub fn tokenize_lossless(&mut self) -> Vec<LosslessToken> {
+        let tokens = self.tokenize();
+        attach_trivia(self.code_source, tokens)
     }
 
     /// Methode pour creer un token de type erreur
+    /// Position courante, utilisee pour situer precisement une erreur
+    fn position(&self) -> Position {
+        Position {
+            line: self.current_line,
+            column: self.current_column,
+        }
+    }
+
     fn create_error(&self, error: LexerErrorType) -> TokenType {
         let position = Position {
             line: self.current_line,
@@ -878,3 +1293,98 @@ pub enum Tok {
     DIV,
     NUMBER(i64),
 }
+
+/// Prefixe d'une chaine: r (brute), b (octets), f (formatee)
+#[derive(Debug, Default, Clone, Copy)]
+struct StringPrefix {
+    raw: bool,
+    bytes: bool,
+    formatted: bool,
+}
+
+/// Dernier mot de `text` (vide si `text` ne finit pas par un identifiant)
+fn last_word(text: &str) -> &str {
+    let start = text.char_indices().rev()
+        .find(|(_, ch)| !is_identifier_continue(*ch))
+        .map_or(0, |(index, ch)| index + ch.len_utf8());
+    &text[start..]
+}
+
+/// Vrai si la ligne ferme une chaine apres quelques mots (`world'` dans `'hello world'`).
+/// Une apostrophe suivie d'un nom ouvre un autre lifetime (`&'a T where 'a: 'b`)
+fn closes_on_line(text: &str) -> bool {
+    let line = text.split('\n').next().unwrap_or_default();
+    line.find('\'').is_some_and(|end| {
+        line[..end].chars().all(|ch| is_identifier_continue(ch) || ch == ' ' || ch == '\t')
+            && !line[end + 1..].starts_with(is_identifier_start)
+    })
+}
+
+/// Retire l'indentation commune d'une chaine `"""..."""`.
+/// Le saut de ligne qui suit l'ouverture est ignore et les lignes blanches deviennent vides
+fn strip_indentation(content: &str) -> String {
+    strip_indentation_with_offsets(content).0
+}
+
+/// Comme `strip_indentation`, avec pour chaque octet du resultat son offset dans `content`
+/// (plus un dernier element pour la fin)
+fn strip_indentation_with_offsets(content: &str) -> (String, Vec<usize>) {
+    let skipped = usize::from(content.starts_with('\n'));
+    let body = &content[skipped..];
+    let indent_of = |line: &str| line.chars().take_while(|c| *c == ' ' || *c == '\t').count();
+    let common = body.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(indent_of)
+        .min()
+        .unwrap_or(0);
+
+    let mut text = String::with_capacity(body.len());
+    let mut offsets = Vec::with_capacity(body.len() + 1);
+    let mut line_start = skipped;
+    for (index, line) in body.split('\n').enumerate() {
+        if index > 0 {
+            text.push('\n');
+            offsets.push(line_start - 1);
+        }
+        if !line.trim().is_empty() {
+            text.push_str(&line[common..]);
+            offsets.extend(line_start + common..line_start + line.len());
+        }
+        line_start += line.len() + 1;
+    }
+    offsets.push(content.len());
+    (text, offsets)
+}
+
+/// Applique les echappements deja verifies par `Lexer::lex_escape`
+fn unescape(content: &str) -> String {
+    let mut value = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            value.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('t') => value.push('\t'),
+            Some('r') => value.push('\r'),
+            Some('0') => value.push('\0'),
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                value.extend(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32));
+            }
+            Some('u') => {
+                let hex: String = chars.by_ref().skip(1).take_while(|c| *c != '}').collect();
+                value.extend(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32));
+            }
+            Some('\n') => {
+                // Ignorer le saut de ligne après un backslash et l'indentation qui suit
+                while chars.next_if(|c| c.is_whitespace() && *c != '\n').is_some() {}
+            }
+            Some(other) => value.push(other),
+            None => {}
+        }
+    }
+    value
+}
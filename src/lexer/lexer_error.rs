@@ -37,6 +37,37 @@ pub enum LexerErrorType {
     UnterminatedString,
     UnterminatedComment,
     InvaliIndentation,
+    InvalidPragma(String),
+    MixedSyntaxMode,
+    FStringError(FStringErrorType),
+    InvalidEscape(String),
+    InvalidDigit { digit: char, radix: u32 },
+    InvalidNumberSeparator(String),
+    InvalidNumberSuffix(String),
+    ConfusableCharacter { found: char, expected: char },
+    TabError,
+    UnterminatedBracket(char),
+    KeywordCollision(String),
+}
+
+/// Erreurs propres aux f-strings (`f"... {expr:spec} ..."`)
+#[derive(Debug, PartialEq, Clone)]
+pub enum FStringErrorType {
+    UnclosedLbrace,
+    UnopenedRbrace,
+    EmptyExpression,
+    InvalidFormatSpec(String),
+}
+
+impl Display for FStringErrorType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FStringErrorType::UnclosedLbrace => write!(f, "unclosed '{{' in f-string"),
+            FStringErrorType::UnopenedRbrace => write!(f, "single '}}' is not allowed in f-string"),
+            FStringErrorType::EmptyExpression => write!(f, "empty expression in f-string"),
+            FStringErrorType::InvalidFormatSpec(spec) => write!(f, "invalid format spec '{}'", spec),
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -83,6 +114,46 @@ impl Display for LexerErrorType {
             LexerErrorType::UnterminatedString => write!(f, "Unterminated string"),
             LexerErrorType::UnterminatedComment => write!(f, "Unterminated comment"),
             LexerErrorType::InvaliIndentation => write!(f, "Invalid indentation"),
+            LexerErrorType::InvalidPragma(p) => write!(f, "Invalid syntax mode pragma: {}", p),
+            LexerErrorType::MixedSyntaxMode => write!(f, "Mixed block styles"),
+            LexerErrorType::FStringError(e) => write!(f, "FString error: {}", e),
+            LexerErrorType::InvalidEscape(e) => write!(f, "Invalid escape sequence: {}", e),
+            LexerErrorType::InvalidDigit { digit, radix } => write!(f, "Invalid digit '{}' for base {}", digit, radix),
+            LexerErrorType::InvalidNumberSeparator(n) => write!(f, "Invalid digit separator in: {}", n),
+            LexerErrorType::InvalidNumberSuffix(s) => write!(f, "Invalid number suffix: {}", s),
+            LexerErrorType::ConfusableCharacter { found, expected } => {
+                write!(f, "Character '{}' (U+{:04X}) can be confused with '{}'", found, *found as u32, expected)
+            }
+            LexerErrorType::TabError => write!(f, "Inconsistent use of tabs and spaces in indentation"),
+            LexerErrorType::UnterminatedBracket(b) => write!(f, "Unterminated bracket: {}", b),
+            LexerErrorType::KeywordCollision(name) => write!(f, "Identifier '{}' is a keyword in the target keyword set", name),
+        }
+    }
+}
+
+impl LexerErrorType {
+    /// Code stable de l'erreur, les erreurs du lexer sont dans la plage E01xx
+    pub fn code(&self) -> &'static str {
+        match self {
+            LexerErrorType::InvalidCharacter(_) => "E0100",
+            LexerErrorType::InvalidToken(_) => "E0101",
+            LexerErrorType::InvalidFloat(_) => "E0102",
+            LexerErrorType::InvalidInteger(_) => "E0103",
+            LexerErrorType::InvalidHexadecimal(_) => "E0104",
+            LexerErrorType::UnterminatedString => "E0105",
+            LexerErrorType::UnterminatedComment => "E0106",
+            LexerErrorType::InvaliIndentation => "E0107",
+            LexerErrorType::InvalidPragma(_) => "E0108",
+            LexerErrorType::MixedSyntaxMode => "E0109",
+            LexerErrorType::FStringError(_) => "E0110",
+            LexerErrorType::InvalidEscape(_) => "E0111",
+            LexerErrorType::InvalidDigit { .. } => "E0112",
+            LexerErrorType::InvalidNumberSeparator(_) => "E0113",
+            LexerErrorType::InvalidNumberSuffix(_) => "E0114",
+            LexerErrorType::ConfusableCharacter { .. } => "E0115",
+            LexerErrorType::TabError => "E0116",
+            LexerErrorType::UnterminatedBracket(_) => "E0117",
+            LexerErrorType::KeywordCollision(_) => "E0118",
         }
     }
 }
@@ -98,7 +169,7 @@ impl LexerError {
     pub fn invalid_character(c: char, position: Position) -> Self {
         Self::new(
             LexerErrorType::InvalidCharacter(c),
-            format!("Invalid character: {}", c),
+            format!("Invalid character: '{}' (U+{:04X})", c, c as u32),
             position,
         )
     }
@@ -152,6 +223,27 @@ impl LexerError {
             position,
         )
     }
+    pub fn tab_error(position: Position) -> Self {
+        Self::new(
+            LexerErrorType::TabError,
+            "Inconsistent use of tabs and spaces in indentation".to_string(),
+            position,
+        )
+    }
+    pub fn unterminated_bracket(bracket: char, position: Position) -> Self {
+        Self::new(
+            LexerErrorType::UnterminatedBracket(bracket),
+            format!("'{}' was never closed", bracket),
+            position,
+        )
+    }
+    pub fn fstring_error(error: FStringErrorType, position: Position) -> Self {
+        Self::new(
+            LexerErrorType::FStringError(error.clone()),
+            format!("FString error: {}", error),
+            position,
+        )
+    }
 }
 
 //by YmC
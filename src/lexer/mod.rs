@@ -1,8 +1,14 @@
 pub mod lex;
+pub mod fstring;
+pub mod indentation;
+pub mod interner;
+pub mod keywords;
+pub mod unicode;
 
 pub mod lexer_error;
-//mod syntax_mode;
+pub mod syntax_mode;
 pub mod tok;
+pub mod trivia;
 
 //pub mod token;
 //mod lex_2;
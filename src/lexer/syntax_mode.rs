@@ -0,0 +1,199 @@
+//src/lexer/syntax_mode.rs
+
+use crate::lexer::keywords::KeywordSet;
+use crate::lexer::lex::SyntaxMode;
+use crate::lexer_error::{LexerError, LexerErrorType, Position};
+use crate::tok::Keywords;
+
+/// Detection du mode syntaxique d'un fichier source.
+///
+/// 1. Pragma en tete de fichier (avant tout code):  `#Syntaxe_mode = Indentation`
+///    La cle accepte aussi `syntax_mode`, la valeur `Indentation`/`Indent` ou `Braces`/`Brace`.
+///    Le pragma decide seul, l'heuristique n'est pas appliquee.
+/// 2. Sinon heuristique: blocs ouverts par `{` contre blocs ouverts par `:` suivis d'une indentation.
+/// 3. Sans aucun bloc, le mode Braces est utilise.
+///
+/// Sans pragma, un fichier qui melange les deux styles de blocs est refuse.
+pub fn detect_syntax_mode(source: &str) -> Result<SyntaxMode, LexerError> {
+    if let Some(mode) = parse_pragma(source)? {
+        return Ok(mode);
+    }
+
+    let (brace_block, colon_block) = find_block_openers(source);
+    if let (Some(brace_line), Some(colon_line)) = (brace_block, colon_block) {
+        let line = brace_line.max(colon_line);
+        return Err(LexerError::new(
+            LexerErrorType::MixedSyntaxMode,
+            format!(
+                "mixed block styles: '{{' block at line {} and ':' indented block at line {}",
+                brace_line, colon_line
+            ),
+            Position { line, column: 1 },
+        ));
+    }
+
+    Ok(if colon_block.is_some() { SyntaxMode::Indentation } else { SyntaxMode::Braces })
+}
+
+/// Cherche le pragma de mode dans les commentaires en tete de fichier
+pub fn parse_pragma(source: &str) -> Result<Option<SyntaxMode>, LexerError> {
+    let Some((index, line, value)) = find_pragma(source, &["syntaxe_mode", "syntax_mode"]) else {
+        return Ok(None);
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "indentation" | "indent" => Ok(Some(SyntaxMode::Indentation)),
+        "braces" | "brace" => Ok(Some(SyntaxMode::Braces)),
+        _ => Err(LexerError::new(
+            LexerErrorType::InvalidPragma(value.to_string()),
+            format!("invalid syntax mode '{}' in pragma (expected Indentation or Braces)", value),
+            Position { line: index + 1, column: value_column(line) },
+        )),
+    }
+}
+
+/// Cherche un pragma `#cle = valeur` dans les commentaires en tete de fichier.
+/// Retourne l'index de la ligne, la ligne et la valeur (sous-chaines de `source`)
+pub(crate) fn find_pragma<'s>(source: &'s str, keys: &[&str]) -> Option<(usize, &'s str, &'s str)> {
+    for (index, line) in source.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("//") {
+            continue;
+        }
+        // Le pragma doit preceder le code
+        let comment = trimmed.strip_prefix('#')?;
+
+        let Some((key, value)) = comment.split_once('=') else {
+            continue;
+        };
+        if keys.contains(&key.trim().to_lowercase().as_str()) {
+            return Some((index, line, value.trim()));
+        }
+    }
+    None
+}
+
+/// Colonne (a partir de 1) de la valeur du pragma, apres le '='
+pub(crate) fn value_column(line: &str) -> usize {
+    let after_equal = line.find('=').map_or(0, |i| i + 1);
+    let rest = &line[after_equal..];
+    after_equal + (rest.len() - rest.trim_start().len()) + 1
+}
+
+/// Mots-cles qui ouvrent un bloc d'instructions.
+/// `struct` et `enum` n'y sont pas: leurs accolades contiennent des declarations
+const BLOCK_KEYWORDS: &[&str] = &[
+    "fn", "if", "elif", "else", "while", "for", "loop", "match",
+    "class", "trait", "impl", "try", "except", "finally", "with", "mod", "async",
+];
+
+/// Mot cle ouvrant un bloc, dans l'un ou l'autre dictionnaire (`si`, `tantque`, ...)
+fn is_block_keyword(word: &str) -> bool {
+    [KeywordSet::English, KeywordSet::French].into_iter()
+        .filter_map(|keyword_set| keyword_set.lookup(word))
+        .any(|keyword| BLOCK_KEYWORDS.contains(&KeywordSet::English.spelling(&keyword)))
+}
+
+/// `pub` dans l'un ou l'autre dictionnaire
+fn is_visibility(word: &str) -> bool {
+    [KeywordSet::English, KeywordSet::French].into_iter().any(|keyword_set| keyword_set.lookup(word) == Some(Keywords::PUB))
+}
+
+/// Retourne la premiere ligne (a partir de 1) ouvrant un bloc avec '{', puis avec ':'
+fn find_block_openers(source: &str) -> (Option<usize>, Option<usize>) {
+    let lines: Vec<(usize, String)> = code_lines(source);
+    let mut brace_block = None;
+    let mut colon_block = None;
+
+    for (position, (line_number, code)) in lines.iter().enumerate() {
+        // `pub fn` est classe par le mot qui suit `pub`
+        let first_word = code.trim_start()
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .find(|word| !word.is_empty() && !is_visibility(word))
+            .unwrap_or("");
+        if !is_block_keyword(first_word) {
+            continue;
+        }
+
+        let code = code.trim_end();
+        if code.ends_with('{') && brace_block.is_none() {
+            brace_block = Some(*line_number);
+        } else if code.ends_with(':') && colon_block.is_none() {
+            // Le bloc ':' doit etre suivi d'une ligne plus indentee
+            let indent = indentation(code);
+            if let Some((_, next)) = lines.get(position + 1) {
+                if indentation(next) > indent {
+                    colon_block = Some(*line_number);
+                }
+            }
+        }
+    }
+
+    (brace_block, colon_block)
+}
+
+/// `'a` (lifetime) et non `'a'` ou `'abc'` (caractere, chaine)
+fn is_lifetime(mut rest: impl Iterator<Item = char>) -> bool {
+    let mut length = 0;
+    for ch in rest.by_ref() {
+        if ch.is_alphanumeric() || ch == '_' {
+            length += 1;
+        } else {
+            return length > 0 && ch != '\'';
+        }
+    }
+    length > 0
+}
+
+fn indentation(line: &str) -> usize {
+    line.chars().take_while(|c| *c == ' ' || *c == '\t').count()
+}
+
+/// Lignes de code sans les commentaires ni le contenu des chaines (lignes vides exclues)
+fn code_lines(source: &str) -> Vec<(usize, String)> {
+    let mut result = Vec::new();
+    let mut in_block_comment = false;
+
+    for (index, line) in source.lines().enumerate() {
+        let mut code = String::new();
+        let mut chars = line.chars().peekable();
+        let mut quote: Option<char> = None;
+
+        while let Some(ch) = chars.next() {
+            if in_block_comment {
+                if ch == '*' && chars.peek() == Some(&'/') {
+                    chars.next();
+                    in_block_comment = false;
+                }
+                continue;
+            }
+            if let Some(q) = quote {
+                if ch == '\\' {
+                    chars.next();
+                } else if ch == q {
+                    quote = None;
+                    code.push(ch);
+                }
+                continue;
+            }
+            match ch {
+                '\'' if is_lifetime(chars.clone()) => code.push(ch),
+                '"' | '\'' => {
+                    quote = Some(ch);
+                    code.push(ch);
+                }
+                '#' => break,
+                '/' if chars.peek() == Some(&'/') => break,
+                '/' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    in_block_comment = true;
+                }
+                _ => code.push(ch),
+            }
+        }
+
+        if !code.trim().is_empty() {
+            result.push((index + 1, code));
+        }
+    }
+    result
+}
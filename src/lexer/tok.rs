@@ -1,15 +1,19 @@
+use crate::lexer::interner::Name;
 use crate::lexer_error::LexerError;
+use crate::parser::ast::Span;
 use num_bigint::BigInt;
 
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Clone)]
 pub enum TokenType {
-    IDENTIFIER { name: String },
-    INTEGER { value: BigInt },
-    FLOAT { value: f64 },
-    HEXADECIMAL { value: u64 },
+    IDENTIFIER { name: Name },       // nom interne par le lexer, partage entre les tokens
+    INTEGER { value: BigInt, suffix: Option<NumberSuffix> },
+    FLOAT { value: f64, suffix: Option<NumberSuffix> },
+    IMAGINARY { value: f64 },        // `3j`, `2.5j`
     STRING { value: String, kind: StringKind },
+    BYTES { value: Vec<u8> },
     CHAR { value: char },
+    LIFETIME { name: String },       // `'a`, `'static` (nom sans l'apostrophe)
     EOF,
     NEWLINE,
     OPERATOR(Operators),
@@ -166,8 +170,102 @@ pub enum Delimiters {
 #[derive(Debug, PartialEq, Clone)]
 pub enum StringKind {
     NORMAL,
-    FORMATTED, // f-string implementation plus tard
+    FORMATTED(Vec<FStringPart>), // f-string, morceaux decoupes par lexer::fstring::split_format_string
     UNICODE,   // u-string implementation plus tard
+    RAW,       // r-string, sans echappements
+}
+
+/// Suffixe de type d'un litteral numerique (`10i32`, `255u8`, `2.5f32`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NumberSuffix {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    F32,
+    F64,
+}
+
+impl NumberSuffix {
+    pub fn from_text(text: &str) -> Option<Self> {
+        match text {
+            "i8" => Some(NumberSuffix::I8),
+            "i16" => Some(NumberSuffix::I16),
+            "i32" => Some(NumberSuffix::I32),
+            "i64" => Some(NumberSuffix::I64),
+            "i128" => Some(NumberSuffix::I128),
+            "u8" => Some(NumberSuffix::U8),
+            "u16" => Some(NumberSuffix::U16),
+            "u32" => Some(NumberSuffix::U32),
+            "u64" => Some(NumberSuffix::U64),
+            "u128" => Some(NumberSuffix::U128),
+            "f32" => Some(NumberSuffix::F32),
+            "f64" => Some(NumberSuffix::F64),
+            _ => None,
+        }
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, NumberSuffix::F32 | NumberSuffix::F64)
+    }
+
+    /// Bornes (min, max) d'un type entier de taille fixe, None pour f32/f64
+    pub fn integer_bounds(&self) -> Option<(BigInt, BigInt)> {
+        let (bits, signed) = match self {
+            NumberSuffix::I8 => (8, true),
+            NumberSuffix::I16 => (16, true),
+            NumberSuffix::I32 => (32, true),
+            NumberSuffix::I64 => (64, true),
+            NumberSuffix::I128 => (128, true),
+            NumberSuffix::U8 => (8, false),
+            NumberSuffix::U16 => (16, false),
+            NumberSuffix::U32 => (32, false),
+            NumberSuffix::U64 => (64, false),
+            NumberSuffix::U128 => (128, false),
+            NumberSuffix::F32 | NumberSuffix::F64 => return None,
+        };
+        let one = BigInt::from(1);
+        if signed {
+            let limit: BigInt = &one << (bits - 1);
+            Some((-limit.clone(), limit - one))
+        } else {
+            Some((BigInt::from(0), (&one << bits) - one))
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NumberSuffix::I8 => "i8",
+            NumberSuffix::I16 => "i16",
+            NumberSuffix::I32 => "i32",
+            NumberSuffix::I64 => "i64",
+            NumberSuffix::I128 => "i128",
+            NumberSuffix::U8 => "u8",
+            NumberSuffix::U16 => "u16",
+            NumberSuffix::U32 => "u32",
+            NumberSuffix::U64 => "u64",
+            NumberSuffix::U128 => "u128",
+            NumberSuffix::F32 => "f32",
+            NumberSuffix::F64 => "f64",
+        }
+    }
+}
+
+/// Morceau d'une f-string: texte brut ou expression `{expr:spec}`
+#[derive(Debug, PartialEq, Clone)]
+pub enum FStringPart {
+    Literal(String),
+    Expression {
+        source: String,
+        format_spec: Option<String>,
+        span: Span, // position de `source` dans le fichier
+    },
 }
 
 ///////////////////////////////by YmC///////////////////////////////
@@ -0,0 +1,204 @@
+//src/lexer/trivia.rs
+
+use crate::lexer::lex::Token;
+use crate::parser::ast::{FileId, Span};
+use crate::tok::TokenType;
+
+/// Categorie d'un morceau de source sans signification pour le parser
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TriviaKind {
+    Whitespace,
+    Newline,
+    LineContinuation, // `\` en fin de ligne, saut de ligne compris
+    Comment,
+    Docstring,
+    Skipped,          // texte non reconnu, garde pour ne rien perdre
+}
+
+/// Trivia: texte exact du source avec sa position
+#[derive(Debug, PartialEq, Clone)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub text: String,
+    pub span: Span,
+}
+
+/// Token du mode sans perte: le texte exact du token entoure de ses trivia.
+/// La trivia de fin va jusqu'au premier saut de ligne inclus, le reste precede le token suivant.
+#[derive(Debug, Clone)]
+pub struct LosslessToken {
+    pub token: Token,
+    pub text: String,
+    pub leading_trivia: Vec<Trivia>,
+    pub trailing_trivia: Vec<Trivia>,
+}
+
+impl LosslessToken {
+    /// Span du token seul (sans les trivia)
+    pub fn span(&self) -> Span {
+        self.token.span()
+    }
+
+    /// Span du token avec ses trivia
+    pub fn full_span(&self) -> Span {
+        let span = self.span();
+        let first = self.leading_trivia.first().map_or(span, |trivia| trivia.span);
+        let last = self.trailing_trivia.last().map_or(span, |trivia| trivia.span);
+        Span { end: last.end, ..first }
+    }
+
+    /// Ajoute le texte source complet (trivia comprises) a `out`
+    pub fn write_source(&self, out: &mut String) {
+        for trivia in &self.leading_trivia {
+            out.push_str(&trivia.text);
+        }
+        out.push_str(&self.text);
+        for trivia in &self.trailing_trivia {
+            out.push_str(&trivia.text);
+        }
+    }
+}
+
+/// Reconstruit le source octet par octet a partir des tokens sans perte
+pub fn to_source(tokens: &[LosslessToken]) -> String {
+    let mut out = String::new();
+    for token in tokens {
+        token.write_source(&mut out);
+    }
+    out
+}
+
+/// Element du source dans l'ordre: un token significatif ou une trivia
+enum Piece {
+    Token(Token, String),
+    Trivia(Trivia),
+}
+
+/// Parcourt le source en gardant la ligne et la colonne du curseur
+struct Cursor<'a> {
+    source: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+    file_id: FileId,
+}
+
+impl<'a> Cursor<'a> {
+    /// Avance jusqu'a `end` et retourne le texte et le span parcourus
+    fn take(&mut self, end: usize) -> (String, Span) {
+        let text = &self.source[self.offset..end];
+        let span = Span::new(self.file_id, self.offset, end, self.line, self.column);
+        for ch in text.chars() {
+            if ch == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.offset = end;
+        (text.to_string(), span)
+    }
+
+    fn trivia(&mut self, kind: TriviaKind, end: usize) -> Trivia {
+        let (text, span) = self.take(end);
+        Trivia { kind, text, span }
+    }
+
+    /// Decoupe le texte entre deux tokens en espaces, sauts de ligne et continuations
+    fn gap(&mut self, end: usize, pieces: &mut Vec<Piece>) {
+        while self.offset < end {
+            let rest = &self.source[self.offset..end];
+            let (kind, length) = if rest.starts_with("\r\n") {
+                (TriviaKind::Newline, 2)
+            } else if rest.starts_with('\n') {
+                (TriviaKind::Newline, 1)
+            } else if rest.starts_with("\\\r\n") {
+                (TriviaKind::LineContinuation, 3)
+            } else if rest.starts_with("\\\n") {
+                (TriviaKind::LineContinuation, 2)
+            } else {
+                let whitespace: usize = rest.chars()
+                    .take_while(|ch| ch.is_whitespace() && *ch != '\n' && *ch != '\r')
+                    .map(char::len_utf8)
+                    .sum();
+                match whitespace {
+                    0 => (TriviaKind::Skipped, rest.chars().next().map_or(1, char::len_utf8)),
+                    length => (TriviaKind::Whitespace, length),
+                }
+            };
+            pieces.push(Piece::Trivia(self.trivia(kind, self.offset + length)));
+        }
+    }
+}
+
+/// Rattache au flux de tokens les trivia qu'il a perdues.
+/// `source` doit etre le texte lu par le lexer qui a produit `tokens`.
+pub fn attach_trivia(source: &str, tokens: Vec<Token>) -> Vec<LosslessToken> {
+    let file_id = tokens.first().map(|token| token.span().file_id).unwrap_or_default();
+    let mut cursor = Cursor { source, offset: 0, line: 1, column: 1, file_id };
+    let mut pieces = Vec::new();
+
+    for token in tokens {
+        let span = token.span();
+        let end = span.end.clamp(cursor.offset, source.len());
+        // INDENT, DEDENT et les erreurs d'indentation couvrent l'indentation: elle reste une trivia
+        let leading_blanks: usize = source[span.start.min(end)..end].chars()
+            .take_while(|ch| *ch == ' ' || *ch == '\t')
+            .count();
+        let start = (span.start + leading_blanks).clamp(cursor.offset, end);
+        cursor.gap(start, &mut pieces);
+
+        match &token.token_type {
+            TokenType::COMMENT(_) | TokenType::DOCSTRING(_) => {
+                let kind = if matches!(token.token_type, TokenType::DOCSTRING(_)) { TriviaKind::Docstring } else { TriviaKind::Comment };
+                // Un commentaire de ligne consomme son saut de ligne, qui reste une trivia a part
+                let text = &source[start..end];
+                let newline = if text.ends_with("\r\n") { 2 } else if text.ends_with('\n') { 1 } else { 0 };
+                pieces.push(Piece::Trivia(cursor.trivia(kind, end - newline)));
+                cursor.gap(end, &mut pieces);
+            }
+            _ => {
+                let (text, span) = cursor.take(end);
+                pieces.push(Piece::Token(token.with_span(span), text));
+            }
+        }
+    }
+    cursor.gap(source.len(), &mut pieces);
+
+    group_trivia(pieces)
+}
+
+/// Distribue les trivia: fin de ligne du token precedent, sinon debut du token suivant
+fn group_trivia(pieces: Vec<Piece>) -> Vec<LosslessToken> {
+    let mut tokens: Vec<LosslessToken> = Vec::new();
+    let mut leading = Vec::new();
+    let mut trailing_open = false;
+
+    for piece in pieces {
+        match piece {
+            Piece::Token(token, text) => {
+                trailing_open = !matches!(token.token_type, TokenType::NEWLINE | TokenType::EOF);
+                tokens.push(LosslessToken {
+                    token,
+                    text,
+                    leading_trivia: std::mem::take(&mut leading),
+                    trailing_trivia: Vec::new(),
+                });
+            }
+            Piece::Trivia(trivia) => match tokens.last_mut() {
+                Some(last) if trailing_open => {
+                    trailing_open = !matches!(trivia.kind, TriviaKind::Newline);
+                    last.trailing_trivia.push(trivia);
+                }
+                _ => leading.push(trivia),
+            },
+        }
+    }
+
+    // Trivia apres le dernier token (source sans EOF)
+    if let Some(last) = tokens.last_mut() {
+        last.trailing_trivia.append(&mut leading);
+    }
+    tokens
+}
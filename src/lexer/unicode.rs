@@ -0,0 +1,45 @@
+//src/lexer/unicode.rs
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_xid::UnicodeXID;
+
+/// Un identifiant commence par `_` ou un caractere XID_Start (`é`, `π`, `変`, ...)
+pub fn is_identifier_start(ch: char) -> bool {
+    ch == '_' || UnicodeXID::is_xid_start(ch)
+}
+
+/// Les caracteres suivants sont XID_Continue (lettres, chiffres, marques combinantes)
+pub fn is_identifier_continue(ch: char) -> bool {
+    UnicodeXID::is_xid_continue(ch)
+}
+
+/// Forme NFC de l'identifiant: `café` compose ou decompose donne le meme nom
+pub fn normalize_identifier(name: &str) -> String {
+    name.nfc().collect()
+}
+
+/// Lettre ASCII avec laquelle un caractere peut etre confondu (cyrillique ou grec)
+pub fn confusable_ascii(ch: char) -> Option<char> {
+    let ascii = match ch {
+        'а' => 'a', 'е' => 'e', 'о' | 'ο' => 'o', 'р' | 'ρ' => 'p', 'с' => 'c', 'у' => 'y',
+        'х' => 'x', 'і' => 'i', 'ј' => 'j', 'ѕ' => 's', 'ԁ' => 'd', 'ɡ' => 'g',
+        'А' | 'Α' => 'A', 'В' | 'Β' => 'B', 'Е' | 'Ε' => 'E', 'Ζ' => 'Z', 'Н' | 'Η' => 'H',
+        'І' | 'Ι' => 'I', 'Ј' => 'J', 'К' | 'Κ' => 'K', 'М' | 'Μ' => 'M', 'Ν' => 'N',
+        'О' | 'Ο' => 'O', 'Р' | 'Ρ' => 'P', 'С' => 'C', 'Ѕ' => 'S', 'Т' | 'Τ' => 'T',
+        'Х' | 'Χ' => 'X', 'Υ' => 'Y',
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Premier caractere trompeur d'un identifiant, avec la lettre ASCII imitee.
+/// Un nom entierement cyrillique ou grec (`сон`, `αβ`) n'est signale que s'il
+/// peut passer pour un nom ASCII ou s'il melange les alphabets (`pаth`).
+pub fn find_confusable(name: &str) -> Option<(char, char)> {
+    let first = name.chars().find_map(|ch| confusable_ascii(ch).map(|ascii| (ch, ascii)))?;
+    let has_ascii_letter = name.chars().any(|ch| ch.is_ascii_alphabetic());
+    let all_confusable = name.chars()
+        .filter(|ch| ch.is_alphabetic())
+        .all(|ch| ch.is_ascii_alphabetic() || confusable_ascii(ch).is_some());
+    (has_ascii_letter || all_confusable).then_some(first)
+}
@@ -1,9 +1,11 @@
+#[macro_use]
+pub mod utils;
 pub mod lexer;
 //mod parser;
 mod codegen;
 pub mod parser;
 pub mod semantic;
-mod utils;
+pub mod cli;
 
 //mod ast;
 pub use crate::lexer::lex::SyntaxMode;
@@ -3,6 +3,60 @@
 
 use crate::parser::parser_error::ParserError;
 use num_bigint::BigInt;
+use num_complex::Complex64;
+use crate::tok::NumberSuffix;
+
+
+/// Identifiant du fichier source auquel appartient un noeud
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FileId(pub u32);
+
+/// Portion du code source couverte par un noeud de l'AST
+/// start/end sont des offsets en octets, line/column la position du debut
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub file_id: FileId,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[allow(dead_code)]
+impl Span {
+    pub fn new(file_id: FileId, start: usize, end: usize, line: usize, column: usize) -> Self {
+        Span { file_id, start, end, line, column }
+    }
+
+    /// Span fictif pour les noeuds crees hors du parser (tests, desugaring)
+    pub fn dummy() -> Self {
+        Span::default()
+    }
+
+    /// Fusionne deux spans: du debut de self jusqu'a la fin de other
+    pub fn to(&self, other: Span) -> Span {
+        if other.end < self.start {
+            return other.to(*self);
+        }
+        Span {
+            file_id: self.file_id,
+            start: self.start,
+            end: self.end.max(other.end),
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
 
 
 #[allow(dead_code)]
@@ -113,6 +167,17 @@ pub enum Operator {
     GreaterThanOrEqual, // >=
     Range, // ..
     RangeInclusive, // ..=
+    Power,      // **
+    BitwiseAnd, // &
+    BitwiseOr,  // |
+    BitwiseXor, // ^
+    ShiftLeft,  // <<
+    ShiftRight, // >>
+    MatrixMultiplication, // @
+    In,     // in
+    NotIn,  // not in
+    Is,     // is
+    IsNot,  // is not
 
 }
 
@@ -176,8 +241,9 @@ pub enum Type {
     String,
     Bool,
     Char,
-    Array(Box<Type>),
-    Tuple(Vec<Type>),
+    Array(Box<Type>),               // [T]
+    FixedArray(Box<Type>, usize),   // [T; N]
+    Tuple(Vec<Type>),               // (A, B), () pour le type unit
     // Struct(StructTypeId),
     Custom(String),
     Generic(GenericType),
@@ -187,11 +253,28 @@ pub enum Type {
     Named(String),
     SelfType,
     //BorrowedType(Box<Type>),
-    Reference(Box<Type>),
-    ReferenceMutable(Box<Type>),
+    Reference(Box<Type>, Option<String>),           // &T, &'a T
+    ReferenceMutable(Box<Type>, Option<String>),    // &mut T, &'a mut T
+    Function(Vec<Type>, Box<Type>),     // fn(A, B) -> R
+    Optional(Box<Type>),                // T?
 
 }
 
+impl Type {
+    /// Type numerique de taille fixe (`i8`, `u64`, `f32`, ...) ecrit comme un nom de type
+    pub fn fixed_width(&self) -> Option<NumberSuffix> {
+        match self {
+            Type::Named(name) | Type::Custom(name) => NumberSuffix::from_text(name),
+            _ => None,
+        }
+    }
+
+    /// Type `complex` integre, ecrit comme un nom de type
+    pub fn is_complex(&self) -> bool {
+        matches!(self, Type::Named(name) | Type::Custom(name) if name == "complex")
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum Declaration {
@@ -213,19 +296,23 @@ pub enum Declaration {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct VariableDeclaration {
-    pub name: String,
+    pub pattern: Pattern, // `x`, `(a, b)`, `Point { x, y }`
     pub variable_type: Option<Type>,
     pub value: Option<Expression>,
     pub mutability: Mutability,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct FunctionDeclaration {
     pub name: String,
+    pub generic_parameters: Option<Vec<GenericParameter>>, // fn max<T: Ord>
     pub parameters: Vec<Parameter>, // (nom, type)
     pub return_type: Option<Type>,
+    pub where_clause: Vec<WhereClause>,
     pub body: Vec<ASTNode>,
-    pub visibility: Visibility
+    pub visibility: Visibility,
+    pub span: Span,
     //pub annotations: Vec<Annotation>,
 }
 #[allow(dead_code)]
@@ -234,16 +321,18 @@ pub struct ConstDeclaration {
     pub name: String,
     pub constant_type: Option<Type>,
     pub value: Expression,
-    pub visibility: Visibility
+    pub visibility: Visibility,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct StructDeclaration {
     pub name: String,
-    // pub generic_type: Option<Vec<GenericType>>,
+    pub generic_parameters: Option<Vec<GenericParameter>>,
     pub fields: Vec<Field>,
     pub visibility: Visibility,
+    pub span: Span,
 
 }
 
@@ -251,12 +340,14 @@ pub struct StructDeclaration {
 #[derive(Debug, Clone)]
 pub struct ClassDeclaration {
     pub name: String,
+    pub generic_parameters: Option<Vec<GenericParameter>>,
     pub parent_classes: Vec<String>,
     pub attributes: Vec<Attribute>,
     pub constructor: Option<Constructor>,
     pub methods: Vec<MethodeDeclaration>,
     // pub body: Vec<ClassMember>,
     pub visibility: Visibility,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -282,8 +373,9 @@ pub struct MethodeDeclaration {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Parameter{
-    pub name: String,
+    pub pattern: Pattern, // `x` ou `(x, y)`
     pub parameter_type: Type,
+    pub span: Span,
 
 }
 
@@ -294,6 +386,7 @@ pub struct Attribute {
     pub attr_type: Type,
     pub visibility: Visibility,
     pub mutability: Mutability,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -302,14 +395,17 @@ pub struct Constructor { // Keyword  pour  le constructeur serai def  et le meth
     pub name: String,       //  def init (self, parameters) init est le nom du constructeur par defaut
     pub parameters: Vec<Parameter>,
     pub body: Vec<ASTNode>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct EnumDeclaration {
     pub name: String,
+    pub generic_parameters: Option<Vec<GenericParameter>>,
     pub variantes: Vec<EnumVariant>,
     pub visibility: Visibility,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -322,6 +418,7 @@ pub struct TraitDeclaration {
     pub visibility: Visibility,          // pub
     pub where_clause: Vec<WhereClause>,
     pub super_traits: Vec<TypeBound>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -333,6 +430,7 @@ pub struct ImplDeclaration {
     pub methods: Vec<ImplMethod>,
     pub where_clause: Vec<WhereClause>,
     pub visibility: Visibility,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -340,6 +438,7 @@ pub struct ImplDeclaration {
 pub struct ModuleDeclaration {
     pub name: String,
     pub statements: Vec<Statement>,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -347,6 +446,7 @@ pub struct MacroDeclaration {
     pub name: String,
     pub parameters: Vec<String>,
     pub body: Vec<ASTNode>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -382,8 +482,19 @@ pub struct Field{
 #[derive(Debug, Clone)]
 pub struct EnumVariant{
     pub name: String,
-    pub variante_type: Type, // None si pas de type associé
+    pub kind: EnumVariantKind,
+    pub discriminant: Option<Expression>, // Red = 1
     pub visibility: Visibility,
+    pub span: Span,
+}
+
+/// Forme d'une variante: `Red`, `Some(T)` ou `Move { x: int }`
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum EnumVariantKind{
+    Unit,
+    Tuple(Vec<Type>),
+    Struct(Vec<Field>),
 }
 
 #[allow(dead_code)]
@@ -435,9 +546,10 @@ pub struct ImplMethod{
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum Expression {
-    Literal(Literal),
-    Identifier(String),
+    Literal(Literal, Span),
+    Identifier(String, Span),
     BinaryOperation(BinaryOperation),
+    ComparisonChain(ComparisonChain),
     UnaryOperation(UnaryOperation),
     FunctionCall(FunctionCall),
 
@@ -472,14 +584,18 @@ pub enum Expression {
 
     CollectionAccess(CollectionAccess),
 
+    StructLiteral(StructLiteral),
+    Tuple(TupleExpression),
+    FormatString(FormatString),
+
 }
 //*********************************
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum CollectionAccess {
-    Dict(Box<Expression>, Box<Expression>),   // (dict, key)
-    Array(Box<Expression>, Box<Expression>),  // (array, index)
-    Unknown(Box<Expression>, Box<Expression>) // à résoudre pendant le type checking
+    Dict(Box<Expression>, Box<Expression>, Span),   // (dict, key)
+    Array(Box<Expression>, Box<Expression>, Span),  // (array, index)
+    Unknown(Box<Expression>, Box<Expression>, Span) // à résoudre pendant le type checking
 }
 
 //**********************************
@@ -487,7 +603,8 @@ pub enum CollectionAccess {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct DictLiteral{
-    pub entries: Vec<DictEntry>
+    pub entries: Vec<DictEntry>,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -501,6 +618,7 @@ pub struct DictEntry {
 pub struct DictAccess{
     pub dict: Box<Expression>,
     pub key: Box<Expression>,
+    pub span: Span,
 }
 
 
@@ -513,6 +631,7 @@ pub struct ListComprehension{
     pub elements: Box<Expression>,
     pub iterators: Vec<ComprehensionFor>,
     pub conditions: Vec<Expression>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -522,6 +641,7 @@ pub struct DictComprehension{
     pub value_expr: Box<Expression>,
     pub iterators: Vec<CompFor>,
     pub conditions: Vec<Expression>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -546,6 +666,52 @@ pub struct ComprehensionFor{
 #[derive(Debug, Clone)]
 pub struct ArrayExpression{
     pub elements: Vec<Expression>,
+    pub span: Span,
+}
+
+/// Point { x: 1, y } ou Point { x: 1, ..base }
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct StructLiteral{
+    pub name: String,
+    pub fields: Vec<StructLiteralField>,
+    pub base: Option<Box<Expression>>,
+    pub span: Span,
+}
+
+/// Champ d'un struct literal, `x` seul est un raccourci pour `x: x`
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct StructLiteralField{
+    pub name: String,
+    pub value: Expression,
+    pub span: Span,
+}
+
+/// (a, b), (a,) ou () pour le tuple vide
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TupleExpression{
+    pub elements: Vec<Expression>,
+    pub span: Span,
+}
+
+/// f"Hello {name}, {x + 1:>8.2}"
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct FormatString{
+    pub parts: Vec<FormatStringPart>,
+    pub span: Span,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum FormatStringPart{
+    Literal(String),
+    Expression {
+        expression: Box<Expression>,
+        format_spec: Option<String>,
+    },
 }
 
 #[allow(dead_code)]
@@ -553,6 +719,7 @@ pub struct ArrayExpression{
 pub struct ArrayRepeatExpression{
     pub value: Box<Expression>,
     pub size: Box<Expression>,
+    pub span: Span,
 }
 
 
@@ -563,6 +730,7 @@ pub struct MethodCall{
     pub object: Box<Expression>,
     pub method: String,
     pub arguments: Vec<Expression>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -570,6 +738,7 @@ pub struct MethodCall{
 pub struct IndexAccess{
     pub array: Box<Expression>,
     pub index: Box<Expression>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -578,6 +747,7 @@ pub struct CompoundAssignment{
     pub target: Box<Expression>,
     pub operator: CompoundOperator,
     pub value: Box<Expression>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -585,6 +755,7 @@ pub struct CompoundAssignment{
 pub struct DestructuringAssignment{
     pub targets: Vec<Expression>,
     pub value: Box<Expression>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -599,6 +770,7 @@ pub struct Borrow {
     pub borrowed_value: Box<Expression>,
     pub borrowed_type: BorrowType,
     pub access: Access,
+    pub span: Span,
 }
 
 
@@ -609,15 +781,18 @@ pub struct Assignment{
     // pub right: Box<Expression>,
     pub target: Box<Expression>,
     pub value: Box<Expression>,
+    pub span: Span,
 }
 
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum Literal {
-    Integer { value: BigInt },
+    Integer { value: BigInt, suffix: Option<NumberSuffix> },
     Float { value: f64 },
+    Complex { value: Complex64 },   // imaginaire pur `3j` -> 0 + 3i
     String(String),
+    Bytes(Vec<u8>),
     Boolean(bool),
     Array(Vec<Expression>),
     Char(char),
@@ -636,6 +811,7 @@ pub enum Literal {
 pub struct UnaryOperation {
     pub operator: UnaryOperator,
     pub operand: Box<Expression>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -644,6 +820,17 @@ pub struct BinaryOperation {
     pub left: Box<Expression>,
     pub operator: Operator,
     pub right: Box<Expression>,
+    pub span: Span,
+}
+
+/// `a < b <= c`: vaut (a < b) and (b <= c), chaque operande n'etant evaluee qu'une fois.
+/// `operators[i]` compare `operands[i]` et `operands[i + 1]`
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub struct ComparisonChain {
+    pub operands: Vec<Expression>,
+    pub operators: Vec<Operator>,
+    pub span: Span,
 }
 
 
@@ -652,12 +839,14 @@ pub struct BinaryOperation {
 pub struct FunctionCall {
     pub name: Box<Expression>,
     pub arguments: Vec<Expression>,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct ArrayAccess {
     pub array: Box<Expression>,
     pub index: Box<Expression>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -667,6 +856,7 @@ pub struct ArraySlice{
     pub start: Option<Box<Expression>>,
     pub end: Option<Box<Expression>>,
     pub step: Option<Box<Expression>>,
+    pub span: Span,
     // pub inclusive: bool,
 }
 
@@ -674,7 +864,8 @@ pub struct ArraySlice{
 pub struct Slice {
     pub start: Option<Box<Expression>>,
     pub end: Option<Box<Expression>>,
-    pub step: Option<Box<Expression>>
+    pub step: Option<Box<Expression>>,
+    pub span: Span,
 }
 
 
@@ -684,6 +875,7 @@ pub struct RangeSlice{
     pub array: Box<Expression>,
     pub range: Box<Expression>,
     pub step: Option<Box<Expression>>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -691,6 +883,7 @@ pub struct RangeSlice{
 pub struct MemberAccess {
     pub object: Box<Expression>,
     pub member: String,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -698,6 +891,7 @@ pub struct MemberAccess {
 pub struct TypeCast {
     pub expression: Box<Expression>,
     pub target_type: Type,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -706,6 +900,7 @@ pub struct Conditional {
     pub condition: Box<Expression>,
     pub then_block: Box<Expression>,
     pub else_block: Box<Expression>,
+    pub span: Span,
 }
 
 
@@ -732,8 +927,9 @@ pub enum Statement {
     BreakStatement(BreakStatement),
     ContinueStatement(ContinueStatement),
 
-    Break,
-    Continue,
+    Break(Span),
+    Continue(Span),
+    Pass(Span),
 
     TryStatement(TryStatement),
     WithStatement(WithStatement),
@@ -752,6 +948,7 @@ pub struct ModuleImportStatement{
     //pub module_path: ModulePath,
     pub module_path: Vec<String>,
     pub alias: Option<String>,
+    pub span: Span,
     // pub items: Option<Vec<ImportItem>>,
     // pub relative_level: usize,
 }
@@ -762,7 +959,8 @@ pub struct SpecificImportStatement{
     pub keyword: ImportKeyword,
     pub module_path: Vec<String>,
     pub alias: Option<String>,
-    pub imports : Vec<(String,Option<String>)>
+    pub imports : Vec<(String,Option<String>)>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -793,12 +991,14 @@ pub struct ImportItem{
 pub struct MatchStatement{
     pub expression: Expression,
     pub arms: Vec<MatchArm>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
 #[derive(Clone, Debug)]
 pub struct ReturnStatement {
     pub value: Option<Expression>,
+    pub span: Span,
     // pub value: Expression
 }
 
@@ -809,6 +1009,7 @@ pub struct IfStatement {
     pub then_block: Vec<ASTNode>,
     pub elif_block: Vec<ElifStatement>,
     pub else_block: Option<Vec<ASTNode>>,
+    pub span: Span,
 
 }
 
@@ -824,14 +1025,16 @@ pub struct ElifStatement {
 pub struct WhileStatement {
     pub condition: Expression,
     pub body: Vec<ASTNode>,
+    pub span: Span,
     //pub body: Body,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct ForStatement {
-    pub iterator: String,
+    pub pattern: Pattern, // `x`, `(k, v)`, `[first, ..rest]`
     pub iterable: Expression,
     pub body: Vec<ASTNode>,
+    pub span: Span,
     //pub body: Body,
 }
 
@@ -839,12 +1042,14 @@ pub struct ForStatement {
 #[derive(Debug, Clone)]
 pub struct BreakStatement {
     pub label: Option<String>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct ContinueStatement {
     pub label: Option<String>,
+    pub span: Span,
 }
 
 
@@ -853,6 +1058,7 @@ pub struct ContinueStatement {
 pub struct LoopStatement {
     pub label: Option<String>,
     pub body: Vec<ASTNode>,
+    pub span: Span,
     //pub body: Body,
 }
 
@@ -861,22 +1067,26 @@ pub struct LoopStatement {
 pub struct UseStatement {
     pub module:String,
     pub alias: Option<String>,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct ImportStatement {
     pub module_path: String,
     pub alias: Option<String>,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct RaiseStatement {
     pub exception: Expression,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct DelStatement {
     pub target: Expression,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -885,6 +1095,7 @@ pub struct TryStatement {
     pub body: Vec<ASTNode>,
     pub handlers: Vec<ExceptHandler>,
     pub finally_body: Option<Vec<ASTNode>>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -900,13 +1111,16 @@ pub struct ExceptHandler{
 #[derive(Debug, Clone)]
 pub struct WithStatement {
     pub target: Expression,
+    pub alias: Option<String>,     // pour with expr as name:
     pub body: Vec<ASTNode>,
+    pub span: Span,
     // pub body: Body,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct YieldStatement {
     pub value: Option<Expression>,
+    pub span: Span,
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -935,6 +1149,7 @@ pub struct LambdaExpression {
     pub return_type: Option<Type>,
     //pub body: Box<Expression>,
     pub body: Vec<ASTNode>,
+    pub span: Span,
     //pub body: Body,
 }
 
@@ -943,6 +1158,7 @@ pub struct LambdaExpression {
 pub struct MatchExpression {
     pub expression: Box<Expression>,
     pub arms: Vec<MatchArm>,
+    pub span: Span,
 }
 
 // #[allow(dead_code)]
@@ -960,6 +1176,7 @@ pub struct RangeExpression {
     pub left: Option<Box<Expression>>,
     pub operator: Operator,
     pub right: Option<Box<Expression>>,
+    pub span: Span,
 }
 
 
@@ -972,30 +1189,49 @@ pub struct MatchArm {
     pub guard: Option<Box<Expression>>,
     //pub expression: Box<Expression>
     pub body: Vec<ASTNode>,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum Pattern {
-    Literal(Literal),
-    Identifier(String),
-    Wildcard,
+    Literal(Literal, Span),
+    Identifier(String, Span),
+    Wildcard(Span),
     EnumVariant(EnumVariant),
-    Tuple(Vec<Pattern>),
-    Array(Vec<Pattern>),
-    Constructor(String, Vec<Pattern>),
-    Rest,
-    TupleRest(Vec<Pattern>),
+    Tuple(Vec<Pattern>, Span),
+    Array(Vec<Pattern>, Span),
+    Constructor(ConstructorPattern),
+    Rest(Option<String>, Span), // `..` ou `..rest` qui lie le reste
+    TupleRest(Vec<Pattern>, Span),
     ArrayRest(ArrayRest),
     RangePattern(RangePattern),
 }
 
+/// Shape::Circle(r), Some(x), Color::Red ou Shape::Move { x, .. }
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ConstructorPattern {
+    pub path: Vec<String>,
+    pub arguments: ConstructorArguments,
+    pub span: Span,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ConstructorArguments {
+    Unit,
+    Tuple(Vec<Pattern>),
+    Struct { fields: Vec<(String, Pattern)>, rest: bool },
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct RangePattern {
     pub start: Option<Box<Expression>>,
     pub end: Option<Box<Expression>>,
     pub inclusive: bool,
+    pub span: Span,
 
 }
 
@@ -1004,11 +1240,184 @@ pub struct RangePattern {
 pub struct  ArrayRest {
     pub before: Vec<Pattern>,
     pub after: Vec<Pattern>,
+    pub span: Span,
+}
+
+
+
+
+
+
+/// Acces uniforme au span de chaque noeud de l'AST
+#[allow(dead_code)]
+impl ASTNode {
+    pub fn span(&self) -> Span {
+        match self {
+            ASTNode::Program(nodes) => match (nodes.first(), nodes.last()) {
+                (Some(first), Some(last)) => first.span().to(last.span()),
+                _ => Span::dummy(),
+            },
+            ASTNode::Declaration(decl) => decl.span(),
+            ASTNode::Expression(expr) => expr.span(),
+            ASTNode::Statement(stmt) => stmt.span(),
+            ASTNode::Error(error) => error.span,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Declaration {
+    pub fn span(&self) -> Span {
+        match self {
+            Declaration::Variable(decl) => decl.span,
+            Declaration::Function(decl) => decl.span,
+            Declaration::Constante(decl) => decl.span,
+            Declaration::Structure(decl) => decl.span,
+            Declaration::Class(decl) => decl.span,
+            Declaration::Enum(decl) => decl.span,
+            Declaration::Trait(decl) => decl.span,
+            Declaration::Impl(decl) => decl.span,
+            Declaration::Module(decl) => decl.span,
+            Declaration::Macro(decl) => decl.span,
+            Declaration::Attributes(attr) => attr.span,
+            Declaration::Constructor(constructor) => constructor.span,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Expression {
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::Literal(_, span) => *span,
+            Expression::Identifier(_, span) => *span,
+            Expression::BinaryOperation(op) => op.span,
+            Expression::ComparisonChain(chain) => chain.span,
+            Expression::UnaryOperation(op) => op.span,
+            Expression::FunctionCall(call) => call.span,
+            Expression::ArrayAccess(access) => access.span,
+            Expression::ArraySlice(slice) => slice.span,
+            Expression::Slice(slice) => slice.span,
+            Expression::RangeSlice(slice) => slice.span,
+            Expression::MemberAccess(access) => access.span,
+            Expression::LambdaExpression(lambda) => lambda.span,
+            Expression::MatchExpression(match_expr) => match_expr.span,
+            Expression::MatchArm(arm) => arm.span,
+            Expression::TypeCast(cast) => cast.span,
+            Expression::Conditional(cond) => cond.span,
+            Expression::Assignment(assign) => assign.span,
+            Expression::Borrow(borrow) => borrow.span,
+            Expression::Statement(stmt) => stmt.span(),
+            Expression::MethodCall(call) => call.span,
+            Expression::IndexAccess(access) => access.span,
+            Expression::CompoundAssignment(assign) => assign.span,
+            Expression::DestructuringAssignment(assign) => assign.span,
+            Expression::RangeExpression(range) => range.span,
+            Expression::Array(array) => array.span,
+            Expression::ArrayRepeat(array) => array.span,
+            Expression::ListComprehension(comp) => comp.span,
+            Expression::DictLiteral(dict) => dict.span,
+            Expression::DictAccess(access) => access.span,
+            Expression::DictComprehension(comp) => comp.span,
+            Expression::CollectionAccess(access) => match access {
+                CollectionAccess::Dict(_, _, span)
+                | CollectionAccess::Array(_, _, span)
+                | CollectionAccess::Unknown(_, _, span) => *span,
+            },
+            Expression::StructLiteral(literal) => literal.span,
+            Expression::Tuple(tuple) => tuple.span,
+            Expression::FormatString(format) => format.span,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Statement {
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::Expression(expr) => expr.span(),
+            Statement::ReturnStatement(stmt) => stmt.span,
+            Statement::UseStatement(stmt) => stmt.span,
+            Statement::ImportStatement(stmt) => stmt.span,
+            Statement::ModuleImportStatement(stmt) => stmt.span,
+            Statement::SpecificImportStatement(stmt) => stmt.span,
+            Statement::RaiseStatement(stmt) => stmt.span,
+            Statement::DelStatement(stmt) => stmt.span,
+            Statement::IfStatement(stmt) => stmt.span,
+            Statement::WhileStatement(stmt) => stmt.span,
+            Statement::ForStatement(stmt) => stmt.span,
+            Statement::LoopStatement(stmt) => stmt.span,
+            Statement::BreakStatement(stmt) => stmt.span,
+            Statement::ContinueStatement(stmt) => stmt.span,
+            Statement::Break(span) => *span,
+            Statement::Continue(span) => *span,
+            Statement::Pass(span) => *span,
+            Statement::TryStatement(stmt) => stmt.span,
+            Statement::WithStatement(stmt) => stmt.span,
+            Statement::YieldStatement(stmt) => stmt.span,
+            Statement::DeclarationStatement(decl) => decl.span(),
+            Statement::Assignment(target, value) => target.span().to(value.span()),
+            Statement::MatchStatement(stmt) => stmt.span,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Pattern {
+    pub fn span(&self) -> Span {
+        match self {
+            Pattern::Literal(_, span) => *span,
+            Pattern::Identifier(_, span) => *span,
+            Pattern::Wildcard(span) => *span,
+            Pattern::EnumVariant(variant) => variant.span,
+            Pattern::Tuple(_, span) => *span,
+            Pattern::Array(_, span) => *span,
+            Pattern::Constructor(constructor) => constructor.span,
+            Pattern::Rest(_, span) => *span,
+            Pattern::TupleRest(_, span) => *span,
+            Pattern::ArrayRest(rest) => rest.span,
+            Pattern::RangePattern(range) => range.span,
+        }
+    }
+
+    /// Nom lie si le pattern est un simple identifiant
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Pattern::Identifier(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Noms introduits par le pattern, dans l'ordre du source
+    pub fn bindings(&self) -> Vec<(&str, Span)> {
+        let mut bindings = Vec::new();
+        self.collect_bindings(&mut bindings);
+        bindings
+    }
+
+    fn collect_bindings<'a>(&'a self, bindings: &mut Vec<(&'a str, Span)>) {
+        match self {
+            Pattern::Identifier(name, span) | Pattern::Rest(Some(name), span) => bindings.push((name, *span)),
+            Pattern::Tuple(patterns, _) | Pattern::Array(patterns, _) | Pattern::TupleRest(patterns, _) => {
+                patterns.iter().for_each(|pattern| pattern.collect_bindings(bindings));
+            }
+            Pattern::ArrayRest(rest) => {
+                rest.before.iter().chain(&rest.after).for_each(|pattern| pattern.collect_bindings(bindings));
+            }
+            Pattern::Constructor(constructor) => match &constructor.arguments {
+                ConstructorArguments::Tuple(patterns) => {
+                    patterns.iter().for_each(|pattern| pattern.collect_bindings(bindings));
+                }
+                ConstructorArguments::Struct { fields, .. } => {
+                    fields.iter().for_each(|(_, pattern)| pattern.collect_bindings(bindings));
+                }
+                ConstructorArguments::Unit => {}
+            },
+            Pattern::Literal(..) | Pattern::Wildcard(_) | Pattern::EnumVariant(_)
+            | Pattern::Rest(None, _) | Pattern::RangePattern(_) => {}
+        }
+    }
 }
 
 
-
-
-
-
 ///////////////////////////////// by YmC////////////////////////////////////////////////////////////
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::lexer::lex::Token;
-use crate::parser::ast::{ArrayAccess, ArrayExpression, ArrayRepeatExpression, ASTNode, Attribute, ClassDeclaration, CompFor, ComprehensionFor, ConstDeclaration, Constructor, Declaration, DictAccess, DictComprehension, DictEntry, DictLiteral, EnumDeclaration, EnumVariant, Expression, Field, FunctionDeclaration, GenericType, ImplDeclaration, ListComprehension, MethodeDeclaration, Mutability, StructDeclaration, TraitDeclaration, TraitMethod, Type, VariableDeclaration, Visibility, WhereClause};
+use crate::parser::ast::{Span, ArrayAccess, ArrayExpression, ArrayRepeatExpression, ASTNode, Attribute, ClassDeclaration, CompFor, ComprehensionFor, ConstDeclaration, Constructor, Declaration, DictAccess, DictComprehension, DictEntry, DictLiteral, EnumDeclaration, EnumVariant, EnumVariantKind, Expression, Field, FunctionDeclaration, GenericType, ImplDeclaration, ListComprehension, MethodeDeclaration, Mutability, StructDeclaration, TraitDeclaration, TraitMethod, Type, VariableDeclaration, Visibility, WhereClause};
 use crate::parser::ast::Declaration::Variable;
 use crate::parser::parser::Parser;
 use crate::parser::parser_error::ParserError;
@@ -24,14 +24,16 @@ impl Parser{
     //dans ce parse_variable_declaration, il y a implementation de l'inference de type,
     //mais j'ai decide de gere  l'inference de type dans la partie SÉMANTIQUE
     pub fn parse_variable_declaration(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de la déclaration de variable");
+        trace!("Début du parsing de la déclaration de variable");
+        let start = self.current_span();
 
         self.consume(TokenType::KEYWORD(Keywords::LET))?;
 
         let mutability = self.parse_mutability()?;
 
-        let  name = self.consume_identifier()?;
-        println!("Nom de la variable parsé : {}", name);
+        // `let x`, `let (a, b)`, `let [first, ..rest]`, `let Point { x, y }`
+        let pattern = self.parse_pattern_complex()?;
+        trace!("Pattern de la variable parsé : {:?}", pattern);
 
         let variable_type = if self.match_token(&[TokenType::DELIMITER(Delimiters::COLON)]) {
             self.parse_type()?
@@ -39,9 +41,9 @@ impl Parser{
             Type::Infer
         };
 
-        println!("Type de la variable parsé : {:?}", variable_type);
+        trace!("Type de la variable parsé : {:?}", variable_type);
 
-        println!("Debut de la valeur de la variable");
+        trace!("Debut de la valeur de la variable");
         self.consume(TokenType::OPERATOR(Operators::EQUAL))?;
 
         let value = self.parse_expression(0)?;
@@ -51,22 +53,24 @@ impl Parser{
         // ici, on vas implementer la fonction parse_inference_type pour determiner le type de la variable
         let final_type = self.parse_inference_type(&variable_type,&value)?;
 
-
+        let span = self.span_from(start);
         self.consume_seperator();
-        println!("Valeur de la variable parsée : {:?}", value);
+        trace!("Valeur de la variable parsée : {:?}", value);
 
         Ok(ASTNode::Declaration(Variable(VariableDeclaration {
-            name,
+            pattern,
             variable_type: Some(final_type),
             // variable_type: Some(variable_type),
             value: Some(value),
             mutability,
+            span,
         })))
 
     }
 
     pub fn parse_const_declaration(&mut self, visibility: Visibility) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de la déclaration de constante");
+        trace!("Début du parsing de la déclaration de constante");
+        let start = self.current_span();
 
         //let visibility = self.parse_visibility()?;
 
@@ -96,9 +100,10 @@ impl Parser{
         //infere  le type si neccessaire
         let final_type = self.parse_inference_type(&variable_type,&value)?;
 
+        let span = self.span_from(start);
         self.consume_seperator();
 
-        println!("la valeur de la constante parse : {:?}", value);
+        trace!("la valeur de la constante parse : {:?}", value);
 
         Ok(ASTNode::Declaration(Declaration::Constante(ConstDeclaration{
             name,
@@ -106,15 +111,19 @@ impl Parser{
             // constant_type: Some(variable_type),
             value,
             visibility,
+            span,
         })))
 
     }
 
     pub fn parse_function_declaration(&mut self, visibility: Visibility) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de la déclaration de fonction");
+        trace!("Début du parsing de la déclaration de fonction");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::FN))?;
         let name = self.consume_identifier()?;
-        println!("Nom de la fonction parsé : {}", name);
+        trace!("Nom de la fonction parsé : {}", name);
+
+        let generic_parameters = self.parse_optional_generic_parameters()?;
 
         self.consume(TokenType::DELIMITER(Delimiters::LPAR))?;
 
@@ -128,6 +137,8 @@ impl Parser{
             Type::Infer // Ou un type par défaut
         };
 
+        let where_clause = self.parse_where_clauses()?;
+
         if self.syntax_mode == SyntaxMode::Indentation{
             self.consume(TokenType::DELIMITER(Delimiters::COLON))?;
         }
@@ -142,28 +153,27 @@ impl Parser{
 
         Ok(ASTNode::Declaration(Declaration::Function(FunctionDeclaration {
             name,
+            generic_parameters,
             parameters,
             return_type: Some(return_type),
+            where_clause,
             body,
             visibility,
+            span: self.span_from(start),
         })))
     }
 
     pub fn parse_struct_declaration(&mut self, visibility: Visibility) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de la déclaration de structure");
+        trace!("Début du parsing de la déclaration de structure");
+        let start = self.current_span();
 
 
         self.consume(TokenType::KEYWORD(Keywords::STRUCT))?;
         let name = self.consume_identifier()?;
-        println!("Nom de la structure parsé : {}", name);
+        trace!("Nom de la structure parsé : {}", name);
+
+        let generic_parameters = self.parse_optional_generic_parameters()?;
 
-        // // on vas implementer le type generique si on as un <
-        // let generic_type = if self.match_token(&[TokenType::OPERATOR(Operators::LESS)]){
-        //     self.parse_gen_type_param()?;
-        // }else {
-        //     vec![]
-        //
-        // };
         self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
 
         let fields = self.parse_struct_fields()?;
@@ -173,9 +183,10 @@ impl Parser{
 
         Ok(ASTNode::Declaration(Declaration::Structure(StructDeclaration{
             name,
-            // generic_type,
+            generic_parameters,
             fields,
             visibility,
+            span: self.span_from(start),
         })))
 
     }
@@ -183,30 +194,38 @@ impl Parser{
 
 
     pub fn parse_enum_declaration(&mut self, visibility: Visibility) -> Result<ASTNode, ParserError> {
-        println!("Debut du parsing de la déclaration d'énumération");
+        trace!("Debut du parsing de la déclaration d'énumération");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::ENUM))?;
         let name = self.consume_identifier()?;
-        println!("Nom de l'énumération parsé : {}", name);
+        trace!("Nom de l'énumération parsé : {}", name);
+
+        // enum Option<T> { ... }
+        let generic_parameters = self.parse_optional_generic_parameters()?;
+
         self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
         let variantes = self.parse_enum_variantes()?;
         self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
 
         // self.consume_seperator();
 
-        println!("Variantes d'énumération parsées OK!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Variantes d'énumération parsées OK!!!!!!!!!!!!!!!!!!!!!!");
         Ok(ASTNode::Declaration(Declaration::Enum(EnumDeclaration{
             name,
+            generic_parameters,
             variantes,
             visibility,
+            span: self.span_from(start),
         })))
 
     }
 
     pub fn parse_trait_declaration(&mut self, visibility: Visibility) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de la déclaration de trait");
+        trace!("Début du parsing de la déclaration de trait");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::TRAIT))?;
         let name = self.consume_identifier()?;
-        println!("Nom du trait parsé : {}", name);
+        trace!("Nom du trait parsé : {}", name);
 
         let generic_params = if self.check(&[TokenType::OPERATOR(Operators::LESS)]) {
             Some(self.parse_generic_parameters()?)
@@ -265,7 +284,7 @@ impl Parser{
         }
 
 
-        println!("Parsing des Trait OK!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Parsing des Trait OK!!!!!!!!!!!!!!!!!!!!!!");
         Ok(ASTNode::Declaration(Declaration::Trait(TraitDeclaration{
             name,
             generic_parameters: generic_params,
@@ -273,14 +292,16 @@ impl Parser{
             associated_types,
             visibility,
             where_clause,
-            super_traits
+            super_traits,
+            span: self.span_from(start),
         })))
 
     }
 
 
     pub fn parse_impl_declaration(&mut self, visibility: Visibility) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de la déclaration d'implémentation");
+        trace!("Début du parsing de la déclaration d'implémentation");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::IMPL))?;
 
         // Parse les paramètres génériques optionnels
@@ -368,16 +389,20 @@ impl Parser{
             methods,
             where_clause,
             visibility,
+            span: self.span_from(start),
         })))
     }
 
     pub fn parse_class_declaration(&mut self, visibility: Visibility) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de la déclaration de classe");
+        trace!("Début du parsing de la déclaration de classe");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::CLASS))?;
 
         let name = self.consume_identifier()?;
 
-        println!("Nom de la classe parsé : {}", name);
+        trace!("Nom de la classe parsé : {}", name);
+
+        let generic_parameters = self.parse_optional_generic_parameters()?;
 
         let parent_classes = self.parse_class_inheritance()?;
 
@@ -389,17 +414,18 @@ impl Parser{
 
         let (attributes ,methods,constructor)= self.parse_class_body()?;
 
-        println!("Fin du parsing de la classe OK!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Fin du parsing de la classe OK!!!!!!!!!!!!!!!!!!!!!!");
 
 
         Ok(ASTNode::Declaration(Declaration::Class(ClassDeclaration{
             name,
+            generic_parameters,
             parent_classes,
             attributes,
             constructor,
             methods,
             visibility,
-
+            span: self.span_from(start),
         })))
 
     }
@@ -418,7 +444,7 @@ impl Parser{
             }
             self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
         }
-        println!("Classes parentes parsées : {:?}", parent_classes);
+        trace!("Classes parentes parsées : {:?}", parent_classes);
         Ok(parent_classes)
     }
 
@@ -480,7 +506,8 @@ impl Parser{
 
 
     fn parse_constructor_declaration(&mut self) -> Result<Constructor,ParserError>{
-        println!("Debut du parsing du constructeur");
+        trace!("Debut du parsing du constructeur");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::DEF))?;
         let constructor_name = self.consume_identifier()?;
         if constructor_name != "init"{
@@ -496,18 +523,20 @@ impl Parser{
 
         let body = self.parse_block()?;
 
-        println!("Fin du parsing du constructeur OK!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Fin du parsing du constructeur OK!!!!!!!!!!!!!!!!!!!!!!");
 
         Ok(Constructor{
             name: constructor_name,
             parameters,
             body,
+            span: self.span_from(start),
         })
 
     }
 
     fn parse_attribute_declaration(&mut self) -> Result<Attribute, ParserError> {
-        println!("Début du parsing de la déclaration de méthode");
+        trace!("Début du parsing de la déclaration de méthode");
+        let start = self.current_span();
         let visibility = self.parse_visibility()?;
         self.consume(TokenType::KEYWORD(Keywords::LET))?;
         let mutability = self.parse_mutability()?;
@@ -515,21 +544,23 @@ impl Parser{
         let name = self.consume_identifier()?;
         self.consume(TokenType::DELIMITER(Delimiters::COLON))?;
         let attribute_type = self.parse_type()?;
+        let span = self.span_from(start);
         self.consume_seperator();
-        println!("Parsing de la déclaration de méthode OK!!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Parsing de la déclaration de méthode OK!!!!!!!!!!!!!!!!!!!!!!!");
 
         Ok(Attribute{
             name,
             attr_type: attribute_type,
             // value: Some(value),
             visibility,
-            mutability
+            mutability,
+            span,
         })
 
     }
 
     fn parse_trait_methods(&mut self) -> Result<TraitMethod, ParserError> {
-        println!("Début du parsing de la signature de méthode de trait");
+        trace!("Début du parsing de la signature de méthode de trait");
         self.consume(TokenType::KEYWORD(Keywords::FN))?;
         let name = self.consume_identifier()?;
         self.consume(TokenType::DELIMITER(Delimiters::LPAR))?;
@@ -546,7 +577,7 @@ impl Parser{
 
         self.consume_seperator();
 
-        println!("Parsing de Trait Method OK!!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Parsing de Trait Method OK!!!!!!!!!!!!!!!!!!!!!!!");
 
         Ok(TraitMethod {
             name,
@@ -557,7 +588,7 @@ impl Parser{
 
 
     pub fn parse_where_clauses(&mut self) -> Result<Vec<WhereClause>,ParserError>{
-        println!("Début du parsing des clauses where");
+        trace!("Début du parsing des clauses where");
 
         // self.consume(TokenType::KEYWORD(Keywords::WHERE))?;
 
@@ -566,10 +597,15 @@ impl Parser{
         if self.check(&[TokenType::KEYWORD(Keywords::WHERE)]) /*&& !self.is_at_end()*/{
             self.consume(TokenType::KEYWORD(Keywords::WHERE))?;
             loop {
-                let type_name = self.consume_identifier()?;
+                // `where 'a: 'b` contraint un lifetime, nomme comme dans les parametres generiques
+                let type_name = if self.check_lifetime_token() {
+                    self.parse_lifetime()?
+                } else {
+                    self.consume_identifier()?
+                };
                 self.consume(TokenType::DELIMITER(Delimiters::COLON))?;
 
-                let bounds = self.parse_type_bounds()?;
+                let bounds = self.parse_trait_bounds()?;
                 clauses.push(WhereClause{
                     type_name,
                     bounds,
@@ -579,13 +615,13 @@ impl Parser{
                 }else { break; }
             }
         }
-        println!("Parsing des clauses where OK!!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Parsing des clauses where OK!!!!!!!!!!!!!!!!!!!!!!!");
         Ok(clauses)
 
     }
 
     pub fn parse_methode_declaration(&mut self) -> Result<MethodeDeclaration,ParserError>{
-        println!("Debut du parsing de la déclaration de méthode");
+        trace!("Debut du parsing de la déclaration de méthode");
         // Pour la visibilite de methode dans une classe, je pense que
         // ça serait  mieux de laisse ceci à  "pub class".
         // Une classe publique  rend toutes ses methodes publiques aussi
@@ -612,7 +648,7 @@ impl Parser{
 
         self.consume_seperator();
 
-        println!("Fin du parsing de la déclaration de méthode OK!!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Fin du parsing de la déclaration de méthode OK!!!!!!!!!!!!!!!!!!!!!!!");
 
         Ok(MethodeDeclaration{
             name,
@@ -644,7 +680,7 @@ impl Parser{
     ///fonction pour parser les champs de structure STRUCT
 
     pub fn parse_struct_fields(&mut self) -> Result<Vec<Field>, ParserError> {
-        println!("Début du parsing des champs de structure");
+        trace!("Début du parsing des champs de structure");
         let mut fields = Vec::new();
 
         if self.match_token(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]){
@@ -672,18 +708,18 @@ impl Parser{
                 return Err(ParserError::new(ExpectColon,self.current_position()))
             }
         }
-        println!("Champs de structure parsés : {:?}", fields);
+        trace!("Champs de structure parsés : {:?}", fields);
         Ok(fields)
 
     }
     pub fn parse_struct_field(&mut self) -> Result<Field, ParserError> {
         let visibility = self.parse_visibility()?;
-        println!("Visibilité du champ parsée : {:?}", visibility);
+        trace!("Visibilité du champ parsée : {:?}", visibility);
         let name = self.consume_identifier()?;
-        println!("Nom du champ parsé : {}", name);
+        trace!("Nom du champ parsé : {}", name);
         self.consume(TokenType::DELIMITER(Delimiters::COLON))?;
         let field_type = self.parse_type()?;
-        println!("Type du champ parsé : {:?}", field_type);
+        trace!("Type du champ parsé : {:?}", field_type);
         Ok(Field{
             name,
             field_type,
@@ -694,11 +730,9 @@ impl Parser{
     }
 
     pub fn parse_enum_variantes(&mut self) -> Result<Vec<EnumVariant>,ParserError>{
-        println!("Début du parsing des variantes d'énumération");
+        trace!("Début du parsing des variantes d'énumération");
         let mut variantes = Vec::new();
-        if self.match_token(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]){
-            return Ok(variantes)
-        }
+        // la '}' est consommee par parse_enum_declaration
         if self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]){
             return Ok(variantes)
         }
@@ -717,22 +751,53 @@ impl Parser{
                 return Err(ParserError::new(ExpectColon,self.current_position()))
             }
         }
-        println!("Variantes d'énumération parsées : {:?}", variantes);
+        trace!("Variantes d'énumération parsées : {:?}", variantes);
         Ok(variantes)
     }
 
+    /// Red, Red = 1, Some(T, U) ou Move { x: int, y: int }
     pub fn parse_enum_variant_fields(&mut self) ->  Result<EnumVariant,ParserError>{
+        let start = self.current_span();
         let visibility = self.parse_visibility()?;
-        println!("Visibilité de la variante parsée : {:?}", visibility);
+        trace!("Visibilité de la variante parsée : {:?}", visibility);
         let name = self.consume_identifier()?;
-        println!("Nom de la variante parsée : {}", name);
-        self.consume(TokenType::DELIMITER(Delimiters::COLON))?;
-        let variante_type = self.parse_type()?;
-        println!("Type de la variante parsée : {:?}", variante_type);
+        trace!("Nom de la variante parsée : {}", name);
+
+        let kind = if self.match_token(&[TokenType::DELIMITER(Delimiters::LPAR)]) {
+            let mut types = Vec::new();
+            while !self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+                types.push(self.parse_type()?);
+                if !self.match_token(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
+                    break;
+                }
+            }
+            self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
+            EnumVariantKind::Tuple(types)
+        } else if self.match_token(&[TokenType::DELIMITER(Delimiters::LCURBRACE)]) {
+            let fields = if self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) {
+                Vec::new()
+            } else {
+                self.parse_struct_fields()?
+            };
+            self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
+            EnumVariantKind::Struct(fields)
+        } else {
+            EnumVariantKind::Unit
+        };
+        trace!("Forme de la variante parsée : {:?}", kind);
+
+        let discriminant = if self.match_token(&[TokenType::OPERATOR(Operators::EQUAL)]) {
+            Some(self.parse_expression(0)?)
+        } else {
+            None
+        };
+
         Ok(EnumVariant{
             name,
-            variante_type,
-            visibility
+            kind,
+            discriminant,
+            visibility,
+            span: self.span_from(start),
         })
 
     }
@@ -745,7 +810,8 @@ impl Parser{
 
 
     pub fn parse_array_expression(&mut self) -> Result<Expression, ParserError> {
-        println!("Début du parsing d'un tableau");
+        trace!("Début du parsing d'un tableau");
+        let start = self.current_span();
 
         // Consommer '['
         self.consume(TokenType::DELIMITER(Delimiters::LSBRACKET))?;
@@ -755,7 +821,7 @@ impl Parser{
         // Vérifier si le tableau est vide
         if self.check(&[TokenType::DELIMITER(Delimiters::RSBRACKET)]) {
             self.advance();
-            return Ok(Expression::Array(ArrayExpression { elements }));
+            return Ok(Expression::Array(ArrayExpression { elements, span: self.span_from(start) }));
         }
 
         // Parser le premier élément
@@ -769,6 +835,7 @@ impl Parser{
             return Ok(Expression::ArrayRepeat(ArrayRepeatExpression {
                 value: Box::new(elements.remove(0)),
                 size: Box::new(size),
+                span: self.span_from(start),
             }));
         }
         // if self.check(&[TokenType::DELIMITER(Delimiters::COLON)]) {
@@ -790,8 +857,8 @@ impl Parser{
         // Consommer ']'
         self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
 
-        println!("Fin du parsing d'un tableau");
-        Ok(Expression::Array(ArrayExpression { elements }))
+        trace!("Fin du parsing d'un tableau");
+        Ok(Expression::Array(ArrayExpression { elements, span: self.span_from(start) }))
     }
 
 
@@ -880,7 +947,8 @@ impl Parser{
 
 
     pub fn parse_list_comprehension(&mut self) -> Result<Expression, ParserError> {
-        println!("Début du parsing de list comprehension");
+        trace!("Début du parsing de list comprehension");
+        let start = self.current_span();
 
         // Consommer '['
         self.consume(TokenType::DELIMITER(Delimiters::LSBRACKET))?;
@@ -918,21 +986,22 @@ impl Parser{
         // Consommer ']'
         self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
 
-        println!("Fin du parsing de list comprehension");
+        trace!("Fin du parsing de list comprehension");
 
         Ok(Expression::ListComprehension(ListComprehension{
             elements,
             iterators,
             conditions,
+            span: self.span_from(start),
         }))
     }
 
     pub fn parse_comprehension_for(&mut self) -> Result<ComprehensionFor, ParserError> {
-        println!("Début du parsing de la boucle for de list comprehension");
+        trace!("Début du parsing de la boucle for de list comprehension");
         let pattern = self.parse_pattern()?;
         self.consume(TokenType::KEYWORD(Keywords::IN))?;
         let iterator = self.parse_expression(0)?;
-        println!("Fin du parsing de la boucle for de list comprehension");
+        trace!("Fin du parsing de la boucle for de list comprehension");
         Ok(ComprehensionFor {
             pattern,
             iterator })
@@ -957,7 +1026,9 @@ impl Parser{
                     is_comprehension = true;
                     break;
                 }
-                if matches!(token.token_type, TokenType::DELIMITER(Delimiters::RSBRACKET)) {
+                // Un crochet non ferme s'arrete a la fin de l'instruction
+                if matches!(token.token_type,
+                    TokenType::DELIMITER(Delimiters::RSBRACKET | Delimiters::SEMICOLON) | TokenType::NEWLINE | TokenType::EOF) {
                     break;
                 }
                 self.advance();
@@ -974,32 +1045,37 @@ impl Parser{
         self.consume(TokenType::DELIMITER(Delimiters::LSBRACKET))?;
         let index = self.parse_expression(0)?;
         self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
+        let span = self.span_from(array.span());
         Ok(Expression::ArrayAccess(ArrayAccess {
             array: Box::new(array),
             index: Box::new(index),
+            span,
         }))
     }
 
     pub fn parse_dict_access(&mut self,dict:Expression) -> Result<Expression,ParserError>{
-        println!("Debut du parsing d'un accès à un dictionnaire");
+        trace!("Debut du parsing d'un accès à un dictionnaire");
         self.consume(TokenType::DELIMITER(Delimiters::LSBRACKET))?;
         let key = self.parse_expression(0)?;
         self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
+        let span = self.span_from(dict.span());
         Ok(Expression::DictAccess(DictAccess {
             dict: Box::new(dict),
             key: Box::new(key),
+            span,
         }))
     }
 
     pub fn parse_dict_literal(&mut self) -> Result<Expression, ParserError> {
-        println!("Debut du parsing d'un dictionnaire");
+        trace!("Debut du parsing d'un dictionnaire");
+        let start = self.current_span();
         self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
 
         // verifie si c'est un dictionnaire vide
         if self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]){
             self.advance();
-            println!("Fin du parsing du dictionnaire OK!!!!!!!!!!!!!!!!!!!!!!!");
-            return Ok(Expression::DictLiteral(DictLiteral { entries: vec![] }));
+            trace!("Fin du parsing du dictionnaire OK!!!!!!!!!!!!!!!!!!!!!!!");
+            return Ok(Expression::DictLiteral(DictLiteral { entries: vec![], span: self.span_from(start) }));
         }
 
 
@@ -1011,7 +1087,7 @@ impl Parser{
         // Vérifier si c'est une dict comprehension
         if self.check(&[TokenType::KEYWORD(Keywords::FOR)]) {
             self.advance(); // Consommer le 'for'
-            return self.parse_dict_comprehension(key, value);
+            return self.parse_dict_comprehension(key, value, start);
         }
 
         // Si ce n'est pas une dict comprehension, continuer avec un dictionnaire normal
@@ -1038,14 +1114,14 @@ impl Parser{
         }
 
         self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
-        println!("Fin du parsing d'un dictionnaire OK!!!!!!!!!!!!!!!!!!!!!!!");
+        trace!("Fin du parsing d'un dictionnaire OK!!!!!!!!!!!!!!!!!!!!!!!");
 
-        Ok(Expression::DictLiteral(DictLiteral { entries }))
+        Ok(Expression::DictLiteral(DictLiteral { entries, span: self.span_from(start) }))
     }
 
 
-    pub fn parse_dict_comprehension(&mut self, key_expr: Expression, value_expr: Expression) -> Result<Expression, ParserError> {
-        println!("Début du parsing d'une dict comprehension");
+    pub fn parse_dict_comprehension(&mut self, key_expr: Expression, value_expr: Expression, start: Span) -> Result<Expression, ParserError> {
+        trace!("Début du parsing d'une dict comprehension");
 
         let mut iterators = Vec::new();
         let mut conditions = Vec::new();
@@ -1056,7 +1132,7 @@ impl Parser{
 
             // Parser la première variable
             if let Some(Token { token_type: TokenType::IDENTIFIER { name }, .. }) = self.current_token() {
-                targets.push(Expression::Identifier(name.clone()));
+                targets.push(Expression::Identifier(name.to_string(), self.current_span()));
                 self.advance();
             } else {
                 return Err(ParserError::new(UnexpectedToken, self.current_position()));
@@ -1066,7 +1142,7 @@ impl Parser{
             while self.check(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
                 self.advance(); // Consomme la virgule
                 if let Some(Token { token_type: TokenType::IDENTIFIER { name }, .. }) = self.current_token() {
-                    targets.push(Expression::Identifier(name.clone()));
+                    targets.push(Expression::Identifier(name.to_string(), self.current_span()));
                     self.advance();
                 } else {
                     return Err(ParserError::new(UnexpectedToken, self.current_position()));
@@ -1099,13 +1175,14 @@ impl Parser{
 
         self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
 
-        println!("Fin du parsing de la dict comprehension");
+        trace!("Fin du parsing de la dict comprehension");
 
         Ok(Expression::DictComprehension(DictComprehension {
             key_expr: Box::new(key_expr),
             value_expr: Box::new(value_expr),
             iterators,
             conditions,
+            span: self.span_from(start),
         }))
     }
 
@@ -1,14 +1,16 @@
-use crate::parser::ast::{ ArrayExpression, ArraySlice, Assignment, ASTNode, BinaryOperation, CompoundAssignment, DestructuringAssignment, DictAccess, Expression, FunctionCall, IndexAccess, LambdaExpression, Literal, MemberAccess, MethodCall, Operator, Parameter, RangeExpression, Type, UnaryOperation, UnaryOperator};
-use crate::parser::parser::Parser;
+use crate::parser::ast::{ ArrayExpression, ArraySlice, Assignment, ASTNode, BinaryOperation, ComparisonChain, CompoundAssignment, DestructuringAssignment, DictAccess, Expression, FormatString, FormatStringPart, FunctionCall, IndexAccess, LambdaExpression, Literal, MemberAccess, MethodCall, Operator, Parameter, Pattern, RangeExpression, Span, StructLiteral, StructLiteralField, TupleExpression, Type, UnaryOperation, UnaryOperator};
+use crate::parser::parser::{Parser, NOT_PRECEDENCE, POWER_PRECEDENCE};
 use crate::parser::parser_error::ParserError;
-use crate::parser::parser_error::ParserErrorType::{ExpectedArrowOrBlock, ExpectedCloseParenthesis, ExpectedCommaOrClosingParenthesis, UnexpectedEndOfInput, UnexpectedToken};
-use crate::tok::{Delimiters, Keywords, Operators, TokenType};
+use crate::parser::parser_error::ParserErrorType::{ExpectedArrowOrBlock, ExpectedCloseParenthesis, ExpectedCommaOrCloseBrace, ExpectedCommaOrClosingParenthesis, InvalidFormatString, UnexpectedEndOfInput, UnexpectedToken};
+use crate::tok::{Delimiters, FStringPart, Keywords, Operators, StringKind, TokenType};
+use crate::{Lexer, SyntaxMode};
+use num_complex::Complex64;
 
 impl Parser {
     /// fonction pour parser les expressions
 
     pub fn parse_expression(&mut self,precedence:u8) -> Result<Expression, ParserError> {
-        println!("Début du parsing de l'expression");
+        trace!("Début du parsing de l'expression");
 
         if self.check(&[TokenType::DELIMITER(Delimiters::LCURBRACE)]){
             return self.parse_dict_literal();
@@ -16,7 +18,7 @@ impl Parser {
 
 
         if self.is_list_comprehension()?{
-            return self.parse_list_comprehension();
+            return self.with_struct_literals(|parser| parser.parse_list_comprehension());
         }
 
         if self.check(&[TokenType::DELIMITER(Delimiters::LSBRACKET)]) {
@@ -24,10 +26,10 @@ impl Parser {
             // Sinon, c'est une destructuration
             match self.previous_token() {
                 Some(token) if token.token_type == TokenType::OPERATOR(Operators::EQUAL) => {
-                    return self.parse_array_expression();
+                    return self.with_struct_literals(|parser| parser.parse_array_expression());
                 },
                 _ => {
-                    return self.parse_destructuring_assignment();
+                    return self.with_struct_literals(|parser| parser.parse_destructuring_assignment());
                 }
             }
         }
@@ -42,19 +44,23 @@ impl Parser {
                 TokenType::OPERATOR(Operators::EQUAL) => {
                     self.advance();
                     let value = self.parse_expression(precedence)?;
+                    let span = left.span().to(value.span());
                     return Ok(Expression::Assignment(Assignment{
                         target: Box::new(left),
                         value: Box::new(value),
+                        span,
                     }));
                 }
                 TokenType::OPERATOR(op) => {
                     if let Some(compound_op) = self.get_compound_operator(op){
                         self.advance();
                         let value = self.parse_expression(precedence)?;
+                        let span = left.span().to(value.span());
                         return Ok(Expression::CompoundAssignment(CompoundAssignment{
                             target: Box::new(left),
                             operator: compound_op,
                             value: Box::new(value),
+                            span,
                         }));
                     }
                 }
@@ -63,45 +69,80 @@ impl Parser {
         }
 
 
+        // Vrai si `left` est une comparaison produite par cette boucle (a prolonger en chaine)
+        let mut last_comparison = false;
+
         while let Some (operator) = self.peek_operator(){
             let operator_precedence = self.get_operator_precedence(&operator);
             if operator_precedence < precedence {
                 break;
             }
 
-            self.advance();
-            let right = self.parse_expression(precedence +1)?;
-
+            self.consume_operator(&operator);
+            let right = if self.is_right_associative(&operator) {
+                self.parse_expression(operator_precedence)?
+            } else {
+                self.parse_expression(operator_precedence + 1)?
+            };
+            let span = left.span().to(right.span());
+
+            if self.is_comparison_operator(&operator) {
+                // a < b < c garde ses operandes dans une chaine: b n'est evaluee qu'une fois
+                left = match left {
+                    Expression::BinaryOperation(comparison) if last_comparison => Expression::ComparisonChain(ComparisonChain{
+                        operands: vec![*comparison.left, *comparison.right, right],
+                        operators: vec![comparison.operator, operator],
+                        span,
+                    }),
+                    Expression::ComparisonChain(mut chain) if last_comparison => {
+                        chain.operands.push(right);
+                        chain.operators.push(operator);
+                        chain.span = span;
+                        Expression::ComparisonChain(chain)
+                    }
+                    left => Expression::BinaryOperation(BinaryOperation{
+                        left: Box::new(left),
+                        operator,
+                        right: Box::new(right),
+                        span,
+                    }),
+                };
+                last_comparison = true;
+                continue;
+            }
+            last_comparison = false;
 
             if let Operator::Range|Operator::RangeInclusive = operator{
                 left = Expression::RangeExpression(RangeExpression{
                     left: Some(Box::new(left)),
                     operator,
                     right: Some(Box::new(right)),
+                    span,
                 });
             }else {
                 left = Expression::BinaryOperation(BinaryOperation{
                     left: Box::new(left),
                     operator,
                     right: Box::new(right),
+                    span,
                 });
             }
 
         }
 
-        println!("Fin du parsing de l'expression ");
+        trace!("Fin du parsing de l'expression ");
 
         Ok(left)
 
     }
 
     pub fn parse_expression_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'expression statement");
+        trace!("Début du parsing de l'expression statement");
         let expr = self.parse_expression(0);
-        println!("Expression parsée : {:?}", expr);
+        trace!("Expression parsée : {:?}", expr);
         //self.consume(TokenType::DELIMITER(Delimiters::SEMICOLON))?;
         self.consume_seperator();
-        println!("Separateur consommé");
+        trace!("Separateur consommé");
         Ok(ASTNode::Expression(expr?))
 
     }
@@ -222,7 +263,7 @@ impl Parser {
 
                     // Parse start
                     let start = if !self.check(&[TokenType::DELIMITER(Delimiters::COLON)]) {
-                        Some(Box::new(self.parse_expression(0)?))
+                        Some(Box::new(self.with_struct_literals(|parser| parser.parse_expression(0))?))
                     } else {
                         None
                     };
@@ -235,7 +276,7 @@ impl Parser {
                         // Parse end
                         let end = if !self.check(&[TokenType::DELIMITER(Delimiters::COLON)]) &&
                             !self.check(&[TokenType::DELIMITER(Delimiters::RSBRACKET)]) {
-                            Some(Box::new(self.parse_expression(0)?))
+                            Some(Box::new(self.with_struct_literals(|parser| parser.parse_expression(0))?))
                         } else {
                             None
                         };
@@ -243,33 +284,38 @@ impl Parser {
                         // Parse step
                         let step = if self.check(&[TokenType::DELIMITER(Delimiters::COLON)]) {
                             self.advance(); // Consomme ':'
-                            Some(Box::new(self.parse_expression(0)?))
+                            Some(Box::new(self.with_struct_literals(|parser| parser.parse_expression(0))?))
                         } else {
                             None
                         };
 
                         self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
+                        let span = self.span_from(expr.span());
 
                         // Ne pas créer de RangeExpression, traiter start comme une valeur normale
                         Expression::ArraySlice(ArraySlice {
                             array: Box::new(expr),
                             start,
                             end,
-                            step
+                            step,
+                            span,
                         })
                     } else if let Some(start) = start {
                         // Simple index access
                         self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
+                        let span = self.span_from(expr.span());
                         match &*start {
-                            Expression::Literal(Literal::String(_)) => {
+                            Expression::Literal(Literal::String(_), _) => {
                                 Expression::DictAccess(DictAccess {
                                     dict: Box::new(expr),
-                                    key: start
+                                    key: start,
+                                    span,
                                 })
                             },
                             _ => Expression::IndexAccess(IndexAccess {
                                 array: Box::new(expr),
-                                index: start
+                                index: start,
+                                span,
                             })
                         }
                     } else {
@@ -279,33 +325,39 @@ impl Parser {
 
                 TokenType::DELIMITER(Delimiters::LPAR) => {
                     self.advance();
-                    let arguments = self.parse_arguments_list()?;
+                    let arguments = self.with_struct_literals(|parser| parser.parse_arguments_list())?;
                     // self.expect_token(&TokenType::DELIMITER(Delimiters::RPAR))?;
                     self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
+                    let span = self.span_from(expr.span());
                     Expression::FunctionCall(FunctionCall {
                         name: Box::new(expr),
-                        arguments
+                        arguments,
+                        span,
                     })
                 },
                 TokenType::DELIMITER(Delimiters::DOT) => {
                     self.advance();
                     if let Some(TokenType::IDENTIFIER { name }) = self.current_token().map(|t| &t.token_type) {
-                        let name = name.clone();
+                        let name = name.to_string();
                         self.advance();
                         if self.check(&[TokenType::DELIMITER(Delimiters::LPAR)]) {
                             self.advance();
-                            let arguments = self.parse_arguments_list()?;
+                            let arguments = self.with_struct_literals(|parser| parser.parse_arguments_list())?;
                             // self.expect_token(&TokenType::DELIMITER(Delimiters::RPAR))?;
                             self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
+                            let span = self.span_from(expr.span());
                             Expression::MethodCall(MethodCall {
                                 object: Box::new(expr),
                                 method: name,
-                                arguments
+                                arguments,
+                                span,
                             })
                         } else {
+                            let span = self.span_from(expr.span());
                             Expression::MemberAccess(MemberAccess {
                                 object: Box::new(expr),
-                                member: name
+                                member: name,
+                                span,
                             })
                         }
                     } else {
@@ -321,7 +373,8 @@ impl Parser {
 
 
     pub fn parse_destructuring_assignment(&mut self) -> Result<Expression,ParserError>{
-        println!("Début du parsing de l'assignation destructuree[");
+        trace!("Début du parsing de l'assignation destructuree[");
+        let start = self.current_span();
         self.consume(TokenType::DELIMITER(Delimiters::LSBRACKET))?;
         let mut targets = Vec::new();
         loop {
@@ -339,41 +392,70 @@ impl Parser {
             self.consume(TokenType::OPERATOR(Operators::EQUAL))?;
             let value = self.parse_expression(0)?;
 
-            println!("Fin du parsing de l'assignation destructuree OK!!!!");
+            trace!("Fin du parsing de l'assignation destructuree OK!!!!");
             Ok(Expression::DestructuringAssignment(DestructuringAssignment {
                 targets,
                 value: Box::new(value),
+                span: self.span_from(start),
             }))
         } else {
             // C'est un tableau littéral
-            println!("Fin du parsing d'un tableau");
+            trace!("Fin du parsing d'un tableau");
             Ok(Expression::Array(ArrayExpression {
-                elements: targets
+                elements: targets,
+                span: self.span_from(start),
             }))
         }
     }
 
     pub fn parse_unary_expression(&mut self) -> Result<Expression, ParserError> {
-        println!("Début du parsing de l'expression unaire");
-        println!("Début du parsing de l'expression unaire, current_token = {:?}", self.current_token());
+        trace!("Début du parsing de l'expression unaire");
+        trace!("Début du parsing de l'expression unaire, current_token = {:?}", self.current_token());
+        let start = self.current_span();
         if let Some(token) = self.current_token(){
             match &token.token_type{
                 //Gestion de la Negation (-)
                 TokenType::OPERATOR(Operators::MINUS) => {
                     self.advance();
                     let right = self.parse_unary_expression()?;
+                    // -x ** 2 == -(x ** 2)
+                    let right = self.parse_power_operand(right)?;
                     return Ok(Expression::UnaryOperation(UnaryOperation{
                         operator: UnaryOperator::Negative,
                         operand: Box::new(right),
+                        span: self.span_from(start),
                     }));
                 }
                 // Gestion de la Negation  Logique (!)
                 TokenType::OPERATOR(Operators::EXCLAMATION) => {
                     self.advance();
                     let right = self.parse_unary_expression()?;
+                    let right = self.parse_power_operand(right)?;
                     return Ok(Expression::UnaryOperation(UnaryOperation{
                         operator: UnaryOperator::Not,
                         operand: Box::new(right),
+                        span: self.span_from(start),
+                    }));
+                }
+                // Negation bit a bit (~)
+                TokenType::OPERATOR(Operators::TILDE) => {
+                    self.advance();
+                    let right = self.parse_unary_expression()?;
+                    let right = self.parse_power_operand(right)?;
+                    return Ok(Expression::UnaryOperation(UnaryOperation{
+                        operator: UnaryOperator::BitwiseNot,
+                        operand: Box::new(right),
+                        span: self.span_from(start),
+                    }));
+                }
+                // `not` a une precedence plus faible que les comparaisons: not a == b == not (a == b)
+                TokenType::KEYWORD(Keywords::NOT) => {
+                    self.advance();
+                    let right = self.parse_expression(NOT_PRECEDENCE)?;
+                    return Ok(Expression::UnaryOperation(UnaryOperation{
+                        operator: UnaryOperator::LogicalNot,
+                        operand: Box::new(right),
+                        span: self.span_from(start),
                     }));
                 }
                 // Gestion de la Reference(Borrowing) (&)
@@ -385,12 +467,14 @@ impl Parser {
                         return Ok(Expression::UnaryOperation(UnaryOperation{
                             operator: UnaryOperator::ReferenceMutable,
                             operand: Box::new(right),
+                            span: self.span_from(start),
                         }));
                     }else{
                         let right = self.parse_unary_expression()?;
                         return Ok(Expression::UnaryOperation(UnaryOperation{
                             operator: UnaryOperator::Reference,
                             operand: Box::new(right),
+                            span: self.span_from(start),
                         }));
                     }
                 }
@@ -401,61 +485,106 @@ impl Parser {
     }
 
 
+    /// `**` lie plus fort que l'operateur unaire qui le precede
+    fn parse_power_operand(&mut self, base: Expression) -> Result<Expression, ParserError> {
+        if !self.check(&[TokenType::OPERATOR(Operators::DOUBLESTAR)]) {
+            return Ok(base);
+        }
+        self.advance();
+        let exponent = self.parse_expression(POWER_PRECEDENCE)?;
+        let span = base.span().to(exponent.span());
+        Ok(Expression::BinaryOperation(BinaryOperation{
+            left: Box::new(base),
+            operator: Operator::Power,
+            right: Box::new(exponent),
+            span,
+        }))
+    }
+
+
     pub fn parse_primary_expression(&mut self) -> Result<Expression, ParserError> {
-        println!("Début du parsing de l'expression primaire, current_token = {:?}", self.current_token());
+        trace!("Début du parsing de l'expression primaire, current_token = {:?}", self.current_token());
+        let span = self.current_span();
         if let Some(token) = self.current_token() {
             let expr = match &token.token_type {
-                TokenType::INTEGER { value } => {
-                    let value = value.clone();
-                    println!("Valeur entière parsée : {}", value);
+                TokenType::INTEGER { value, suffix } => {
+                    let (value, suffix) = (value.clone(), *suffix);
+                    trace!("Valeur entière parsée : {}", value);
+                    self.advance();
+                    Expression::Literal(Literal::Integer { value, suffix }, span)
+                }
+                TokenType::FLOAT { value, .. } => {
+                    let value = *value;
+                    trace!("Valeur flottante parsée : {}", value);
                     self.advance();
-                    Expression::Literal(Literal::Integer { value })
+                    Expression::Literal(Literal::Float { value }, span)
                 }
-                TokenType::FLOAT { value } => {
+                TokenType::IMAGINARY { value } => {
                     let value = *value;
-                    println!("Valeur flottante parsée : {}", value);
+                    trace!("Valeur imaginaire parsée : {}j", value);
                     self.advance();
-                    Expression::Literal(Literal::Float { value })
+                    Expression::Literal(Literal::Complex { value: Complex64::new(0.0, value) }, span)
+                }
+
+                TokenType::STRING { kind: StringKind::FORMATTED(pieces), .. } => {
+                    let pieces = pieces.clone();
+                    self.advance();
+                    self.parse_format_string(pieces, span)?
                 }
 
                 TokenType::STRING { value,.. } => {
                     let value = value.clone();
                     if value.len() == 1 && self.if_single_quote(&value) {
                         self.advance();
-                        Expression::Literal(Literal::Char(value.chars().next().unwrap()))
+                        Expression::Literal(Literal::Char(value.chars().next().unwrap()), span)
                     }else {
                         self.advance();
-                        Expression::Literal(Literal::String(value))
+                        Expression::Literal(Literal::String(value), span)
                     }
                 }
 
+                TokenType::BYTES { value } => {
+                    let value = value.clone();
+                    self.advance();
+                    Expression::Literal(Literal::Bytes(value), span)
+                }
+
                 TokenType::CHAR { value } => {
                     let value = *value;
-                    println!("Valeur de caractère parsée : {}", value);
+                    trace!("Valeur de caractère parsée : {}", value);
                     self.advance();
-                    Expression::Literal(Literal::Char(value))
+                    Expression::Literal(Literal::Char(value), span)
                 }
 
                 TokenType::KEYWORD(Keywords::TRUE) => {
                     self.advance(); // Consomme le token
-                    Expression::Literal(Literal::Boolean(true))
+                    Expression::Literal(Literal::Boolean(true), span)
                 }
                 TokenType::KEYWORD(Keywords::FALSE) => {
                     self.advance(); // Consomme le token
-                    Expression::Literal(Literal::Boolean(false))
+                    Expression::Literal(Literal::Boolean(false), span)
                 }
                 // SELF   pour les methode d'instantiation dans class declaration
                 TokenType::KEYWORD(Keywords::SELF) =>{
                     self.advance();
                     let name = "self".to_string();
-                    Expression::Identifier(name)
+                    Expression::Identifier(name, span)
 
                 }
 
                 TokenType::IDENTIFIER { name } => {
-                    let name = name.clone();
+                    let mut name = name.to_string();
                     self.advance();
-                    Expression::Identifier(name)
+                    // Chemin qualifie: Color::Red, Shape::Circle(1.0), Shape::Move { x: 1 }
+                    while self.match_token(&[TokenType::DELIMITER(Delimiters::DOUBLECOLON)]) {
+                        name.push_str("::");
+                        name.push_str(&self.consume_identifier()?);
+                    }
+                    if self.is_struct_literal_start() {
+                        self.parse_struct_literal(name, span)?
+                    } else {
+                        Expression::Identifier(name, span)
+                    }
                 }
                 TokenType::KEYWORD(Keywords::LAMBDA) => {
                     // self.advance();
@@ -463,24 +592,7 @@ impl Parser {
                 }
 
                 TokenType::DELIMITER(Delimiters::LPAR) => {
-                    self.advance();
-                    let expr = self.parse_expression(0)?;
-                    if let Some(token) = self.current_token() {
-                        if matches!(token.token_type, TokenType::DELIMITER(Delimiters::RPAR)) {
-                            self.advance();
-                            expr
-                        } else {
-                            return Err(ParserError::new(
-                                ExpectedCloseParenthesis,
-                                self.current_position(),
-                            ));
-                        }
-                    } else {
-                        return Err(ParserError::new(
-                            UnexpectedEndOfInput,
-                            self.current_position(),
-                        ));
-                    }
+                    self.with_struct_literals(|parser| parser.parse_parenthesized_expression())?
                 }
                 _ => return Err(ParserError::new(UnexpectedToken, self.current_position())),
             };
@@ -495,8 +607,156 @@ impl Parser {
     }
 
 
+    /// Apres un identifiant, `{` ouvre un struct literal si le contenu ressemble a des champs:
+    /// `{}`, `{ ..base }` ou `{ nom` suivi de `:`, `,` ou `}`.
+    /// Un dict literal commence toujours par `{` et n'est donc jamais precede d'un identifiant.
+    fn is_struct_literal_start(&self) -> bool {
+        if self.no_struct_literal || !self.check(&[TokenType::DELIMITER(Delimiters::LCURBRACE)]) {
+            return false;
+        }
+        let token_at = |offset: usize| self.tokens.get(self.current + offset).map(|t| &t.token_type);
+        match token_at(1) {
+            Some(TokenType::DELIMITER(Delimiters::RCURBRACE)) => true,
+            Some(TokenType::OPERATOR(Operators::DOTDOT)) => true,
+            Some(TokenType::IDENTIFIER { .. }) => matches!(
+                token_at(2),
+                Some(TokenType::DELIMITER(Delimiters::COLON))
+                    | Some(TokenType::DELIMITER(Delimiters::COMMA))
+                    | Some(TokenType::DELIMITER(Delimiters::RCURBRACE))
+            ),
+            _ => false,
+        }
+    }
+
+    /// Point { x: 1, y, ..base }
+    pub fn parse_struct_literal(&mut self, name: String, start: Span) -> Result<Expression, ParserError> {
+        trace!("Début du parsing du struct literal {}", name);
+        self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
+
+        let mut fields = Vec::new();
+        let mut base = None;
+        while !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) {
+            if self.match_token(&[TokenType::OPERATOR(Operators::DOTDOT)]) {
+                // ..base doit etre le dernier element
+                base = Some(Box::new(self.with_struct_literals(|parser| parser.parse_expression(0))?));
+                if !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) {
+                    return Err(ParserError::new(ExpectedCommaOrCloseBrace, self.current_position()));
+                }
+                break;
+            }
+
+            let field_start = self.current_span();
+            let field_name = self.consume_identifier()?;
+            let value = if self.match_token(&[TokenType::DELIMITER(Delimiters::COLON)]) {
+                self.with_struct_literals(|parser| parser.parse_expression(0))?
+            } else {
+                // Raccourci: `x` vaut `x: x`
+                Expression::Identifier(field_name.clone(), field_start)
+            };
+            fields.push(StructLiteralField {
+                name: field_name,
+                value,
+                span: self.span_from(field_start),
+            });
+
+            if !self.match_token(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
+                if !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) {
+                    return Err(ParserError::new(ExpectedCommaOrCloseBrace, self.current_position()));
+                }
+                break;
+            }
+        }
+        self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
+
+        trace!("Fin du parsing du struct literal OK!!!!!!!!!!!!!!!");
+        Ok(Expression::StructLiteral(StructLiteral {
+            name,
+            fields,
+            base,
+            span: self.span_from(start),
+        }))
+    }
+
+    /// Parse chaque expression `{...}` d'une f-string, deja decoupee par le lexer,
+    /// avec un sous-lexer en mode Braces. Les spans sont replaces dans le fichier.
+    fn parse_format_string(&mut self, pieces: Vec<FStringPart>, span: Span) -> Result<Expression, ParserError> {
+        let mut parts = Vec::new();
+        for piece in pieces {
+            match piece {
+                FStringPart::Literal(text) => parts.push(FormatStringPart::Literal(text)),
+                FStringPart::Expression { source, format_spec, span: hole } => {
+                    trace!("Parsing de l'expression de f-string : {}", source);
+                    let tokens = Lexer::new(&source, self.syntax_mode).with_keyword_set(self.keyword_set).tokenize().into_iter()
+                        .map(|token| {
+                            let span = token.span;
+                            token.with_span(Span::new(
+                                hole.file_id,
+                                hole.start + span.start,
+                                hole.start + span.end,
+                                hole.line + span.line - 1,
+                                if span.line == 1 { hole.column + span.column - 1 } else { span.column },
+                            ))
+                        })
+                        .collect();
+                    let mut parser = Parser::new(tokens, self.syntax_mode).with_keyword_set(self.keyword_set);
+                    let expression = parser.parse_expression(0)
+                        .ok()
+                        .filter(|_| parser.is_at_end())
+                        .ok_or_else(|| ParserError::new(InvalidFormatString, self.current_position()).with_span(hole))?;
+                    parts.push(FormatStringPart::Expression {
+                        expression: Box::new(expression),
+                        format_spec,
+                    });
+                }
+            }
+        }
+
+        Ok(Expression::FormatString(FormatString { parts, span }))
+    }
+
+    /// `(expr)` est un groupement, `()` le tuple vide, `(a,)` et `(a, b)` des tuples
+    fn parse_parenthesized_expression(&mut self) -> Result<Expression, ParserError> {
+        let start = self.current_span();
+        self.consume(TokenType::DELIMITER(Delimiters::LPAR))?;
+
+        if self.match_token(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+            return Ok(Expression::Tuple(TupleExpression {
+                elements: vec![],
+                span: self.span_from(start),
+            }));
+        }
+
+        let first = self.parse_expression(0)?;
+        if !self.check(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
+            if self.current_token().is_none() {
+                return Err(ParserError::new(UnexpectedEndOfInput, self.current_position()));
+            }
+            if !self.match_token(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+                return Err(ParserError::new(ExpectedCloseParenthesis, self.current_position()));
+            }
+            return Ok(first);
+        }
+
+        let mut elements = vec![first];
+        while self.match_token(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
+            if self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+                break;
+            }
+            elements.push(self.parse_expression(0)?);
+        }
+        if !self.match_token(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+            return Err(ParserError::new(ExpectedCommaOrClosingParenthesis, self.current_position()));
+        }
+
+        Ok(Expression::Tuple(TupleExpression {
+            elements,
+            span: self.span_from(start),
+        }))
+    }
+
     pub fn parse_lambda_expression(&mut self) -> Result<Expression, ParserError> {
-        println!("Début du parsing de l'expression lambda");
+        trace!("Début du parsing de l'expression lambda");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::LAMBDA))?;
 
         self.consume(TokenType::DELIMITER(Delimiters::LPAR))?;
@@ -519,6 +779,15 @@ impl Parser {
             self.parse_block_expression()?
             //self.parse_body_block()?
             // self.parse_block()?
+        } else if self.syntax_mode == SyntaxMode::Indentation && self.match_token(&[TokenType::DELIMITER(Delimiters::COLON)]) {
+            // `lambda (x) -> int: x + 1`, `:{x + y}` ou bloc indente
+            if self.check(&[TokenType::DELIMITER(Delimiters::LCURBRACE)]) {
+                self.parse_block_expression()?
+            } else if self.check(&[TokenType::NEWLINE]) {
+                self.parse_function_body()?
+            } else {
+                vec![ASTNode::Expression(self.parse_expression(0)?)]
+            }
         } else {
             return Err(ParserError::new(ExpectedArrowOrBlock, self.current_position()));
         };
@@ -527,6 +796,7 @@ impl Parser {
             parameters,
             return_type: Some(return_type),
             body,
+            span: self.span_from(start),
         }))
 
     }
@@ -534,7 +804,7 @@ impl Parser {
     /// fonction pour parser les parametres
 
     pub fn parse_arguments_list(&mut self) -> Result<Vec<Expression>, ParserError> {
-        println!("Début du parsing de la liste d'arguments");
+        trace!("Début du parsing de la liste d'arguments");
         let mut arguments = Vec::new();
         if self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]){
             return Ok(arguments);
@@ -547,13 +817,13 @@ impl Parser {
                 break;
             }
         }
-        println!("Arguments liste parsés : {:?}", arguments);
+        trace!("Arguments liste parsés : {:?}", arguments);
         Ok(arguments)
 
     }
 
     pub fn parse_parameter_list(&mut self) -> Result<Vec<Parameter>, ParserError> {
-        println!("Début du parsing de la liste des paramètres");
+        trace!("Début du parsing de la liste des paramètres");
         let mut parameters = Vec::new();
 
         if self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
@@ -562,6 +832,7 @@ impl Parser {
         }
 
         loop {
+            let param_start = self.current_span();
             let param_name = self.consume_identifier()?;
 
             // Vérifier s'il y a un type spécifié
@@ -572,8 +843,9 @@ impl Parser {
             };
 
             parameters.push(Parameter {
-                name: param_name,
+                pattern: Pattern::Identifier(param_name, self.span_from(param_start)),
                 parameter_type: param_type.unwrap_or(Type::Infer),
+                span: self.span_from(param_start),
             });
 
             // Si le prochain token est une virgule, continuer
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use crate::parser::ast::{Assignment, BinaryOperation, Expression, Literal, Operator, Type, UnaryOperation, UnaryOperator, VariableDeclaration};
+use crate::parser::ast::{Assignment, BinaryOperation, ComparisonChain, Expression, Literal, Operator, Type, UnaryOperation, UnaryOperator, VariableDeclaration};
 
 
 
@@ -29,9 +29,10 @@ impl TypeContext {
 
     pub fn infer_expression(&mut self, expr: &Expression) -> Result<Type, String> {
         match expr {
-            Expression::Literal(lit) => self.infer_literal(lit),
-            Expression::Identifier(name) => self.lookup_type(name),
+            Expression::Literal(lit, _) => self.infer_literal(lit),
+            Expression::Identifier(name, _) => self.lookup_type(name),
             Expression::BinaryOperation(binop) => self.infer_binary_op(binop),
+            Expression::ComparisonChain(chain) => self.infer_comparison_chain(chain),
             Expression::Assignment(assign) => self.infer_assignment(assign),
             Expression::UnaryOperation(unop) => self.infer_unary_op(unop),
 
@@ -47,6 +48,7 @@ impl TypeContext {
         match lit {
             Literal::Integer { .. } => Ok(Type::Int),
             Literal::Float { .. } => Ok(Type::Float),
+            Literal::Complex { .. } => Ok(Type::Named("complex".to_string())),
             // Literal::String(_) => Ok(Type::String),
             Literal::String(s) => {
                 // Si c'est un seul caractère entre guillemets simples
@@ -71,6 +73,8 @@ impl TypeContext {
             Operator::Multiplication | Operator::Division => {
                 if left_type == Type::Int && right_type == Type::Int {
                     Ok(Type::Int)
+                } else if left_type.is_complex() || right_type.is_complex() {
+                    Ok(Type::Named("complex".to_string()))
                 } else if left_type == Type::Float || right_type == Type::Float {
                     Ok(Type::Float)
                 } else if left_type == Type::Infer || right_type == Type::Infer {
@@ -79,7 +83,7 @@ impl TypeContext {
                     Err("Type mismatch in binary operation".to_string())
                 }
             },
-            Operator::Equal | Operator::NotEqual |
+            Operator::EqualEqual | Operator::NotEqual |
             Operator::LessThan | Operator::GreaterThan |
             Operator::LesshanOrEqual | Operator::GreaterThanOrEqual => {
                 self.add_constraint(TypeConstraint::Equal(left_type, right_type));
@@ -89,11 +93,21 @@ impl TypeContext {
         }
     }
 
+    fn infer_comparison_chain(&mut self, chain: &ComparisonChain) -> Result<Type, String> {
+        let types = chain.operands.iter()
+            .map(|operand| self.infer_expression(operand))
+            .collect::<Result<Vec<_>, _>>()?;
+        for pair in types.windows(2) {
+            self.add_constraint(TypeConstraint::Equal(pair[0].clone(), pair[1].clone()));
+        }
+        Ok(Type::Bool)
+    }
+
     fn infer_assignment(&mut self, assign: &Assignment) -> Result<Type, String> {
         let value_type = self.infer_expression(&assign.value)?;
 
         match &*assign.target {
-            Expression::Identifier(name) => {
+            Expression::Identifier(name, _) => {
                 self.type_vars.insert(name.clone(), value_type.clone());
                 Ok(value_type)
             },
@@ -169,6 +183,7 @@ impl TypeContext {
                 match operand_type {
                     Type::Int => Ok(Type::Int),
                     Type::Float => Ok(Type::Float),
+                    complex if complex.is_complex() => Ok(complex),
                     Type::Infer => Ok(Type::Infer),
                     _ => Err("Operator '-' cannot be applied to this type".to_string())
                 }
@@ -180,6 +195,20 @@ impl TypeContext {
                     _ => Err("Operator '!' can only be applied to boolean types".to_string())
                 }
             },
+            UnaryOperator::LogicalNot => {
+                match operand_type {
+                    Type::Bool => Ok(Type::Bool),
+                    Type::Infer => Ok(Type::Infer),
+                    _ => Err("Operator 'not' can only be applied to boolean types".to_string())
+                }
+            },
+            UnaryOperator::BitwiseNot => {
+                match operand_type {
+                    Type::Int => Ok(Type::Int),
+                    Type::Infer => Ok(Type::Infer),
+                    _ => Err("Operator '~' can only be applied to integer types".to_string())
+                }
+            },
             UnaryOperator::Reference => Ok(Type::Array(Box::new(operand_type))),
             UnaryOperator::ReferenceMutable => Ok(Type::Array(Box::new(operand_type))),
             _ => Err("Unknown unary operator".to_string()),
@@ -191,11 +220,10 @@ impl TypeContext {
         self.constraints.push(constraint);
     }
 
+    /// Type d'une variable affectee plus haut; les autres noms (parametres, `let`
+    /// precedents) sont resolus par l'analyse semantique
     fn lookup_type(&self, name: &str) -> Result<Type, String> {
-        self.type_vars
-            .get(name)
-            .cloned()
-            .ok_or_else(|| format!("Undefined variable: {}", name))
+        Ok(self.type_vars.get(name).cloned().unwrap_or(Type::Infer))
     }
 
 }
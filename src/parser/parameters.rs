@@ -10,10 +10,15 @@ impl Parser{
         let mut params = Vec::new();
 
         while !self.check(&[TokenType::OPERATOR(Operators::GREATER)]) {
-            let name = self.consume_identifier()?;
+            // Un parametre de lifetime (`'a`) garde son apostrophe dans le nom
+            let name = if self.check_lifetime_token() {
+                self.parse_lifetime()?
+            } else {
+                self.consume_identifier()?
+            };
             let mut bounds = Vec::new();
 
-            // Parse les bounds du paramètre générique (T: Display + Clone)
+            // Parse les bounds du paramètre générique (T: Display + Clone, 'a: 'b)
             if self.check(&[TokenType::DELIMITER(Delimiters::COLON)]) {
                 self.advance(); // Consomme ':'
                 bounds = self.parse_trait_bounds()?;
@@ -33,6 +38,15 @@ impl Parser{
         Ok(params)
     }
 
+    /// `<T, U: Display>` apres le nom d'une fonction, structure, enumeration ou classe
+    pub fn parse_optional_generic_parameters(&mut self) -> Result<Option<Vec<GenericParameter>>, ParserError> {
+        if self.check(&[TokenType::OPERATOR(Operators::LESS)]) {
+            Ok(Some(self.parse_generic_parameters()?))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn parse_trait_bounds(&mut self) -> Result<Vec<TypeBound>, ParserError> {
         let mut bounds = Vec::new();
 
@@ -58,11 +72,11 @@ impl Parser{
 
     /// Parse un lifetime ('a, 'static, etc)
 
-    fn parse_lifetime(&mut self) -> Result<String, ParserError> {
+    pub fn parse_lifetime(&mut self) -> Result<String, ParserError> {
         if let Some(token) = self.current_token() {
             match &token.token_type {
-                TokenType::IDENTIFIER { name } if name.starts_with('\'') => {
-                    let lifetime_name = name.clone();
+                TokenType::LIFETIME { name } => {
+                    let lifetime_name = format!("'{}", name);
                     self.advance(); // Consomme le token
                     Ok(lifetime_name)
                 },
@@ -244,10 +258,11 @@ impl Parser{
     }
 
     fn parse_parameter(&mut self) -> Result<Parameter, ParserError> {
-        println!("Début du parsing d'un paramètre");
+        trace!("Début du parsing d'un paramètre");
 
-        // 1. Parser le nom du paramètre
-        let param_name = self.consume_identifier()?;
+        // 1. Parser le nom du paramètre, ou un pattern qui le destructure
+        let start = self.current_span();
+        let pattern = self.parse_pattern_complex()?;
 
         // 2. Si on trouve un deux-points, on doit avoir un type qui suit
         if self.check(&[TokenType::DELIMITER(Delimiters::COLON)]) {
@@ -262,14 +277,16 @@ impl Parser{
 
             let param_type = self.parse_type()?;
             Ok(Parameter {
-                name: param_name,
+                pattern,
                 parameter_type: param_type,
+                span: self.span_from(start),
             })
         } else {
             // Si pas de ':', utiliser le type Infer
             Ok(Parameter {
-                name: param_name,
+                pattern,
                 parameter_type: Type::Infer,
+                span: self.span_from(start),
             })
         }
     }
@@ -1,24 +1,38 @@
 
 #[allow(dead_code)]
+use crate::lexer::keywords::KeywordSet;
 use crate::lexer::lex::{SyntaxMode, Token};
 
-use crate::parser::ast::{ ArrayRest, AssociatedType, ASTNode,  CompoundOperator, Expression,  GenericType,   ImportKeyword, Literal,  MatchArm, MatchStatement, ModuleImportStatement, Operator, Parameter, Pattern,  RangePattern, ReturnStatement,  SpecificImportStatement, Statement, Type, TypeBound,UnaryOperation, UnaryOperator,};
+use crate::parser::ast::{ Span, ArrayRest, AssociatedType, ASTNode,  CompoundOperator, ConstructorArguments, ConstructorPattern, Expression,  GenericType,   ImportKeyword, Literal,  MatchArm, MatchStatement, ModuleImportStatement, Operator, Parameter, Pattern,  RangePattern, ReturnStatement,  SpecificImportStatement, Statement, Type, TypeBound,UnaryOperation, UnaryOperator,};
 
 use crate::parser::parser_error::ParserErrorType::{ ExpectIdentifier, ExpectedTypeAnnotation,  InvalidTypeAnnotation,  UnexpectedEOF, UnexpectedEndOfInput,  UnexpectedToken, ExpectedParameterName,MultipleRestPatterns, ExpectedUseOrImport,  ExpectedCommaOrCloseBrace, };
 use crate::parser::parser_error::{ParserError, ParserErrorType, Position};
 use crate::tok::{Delimiters, Keywords, Operators, TokenType};
 use crate::parser::inference::{TypeContext};
+use num_complex::Complex64;
 
 
 
 //use crate::tok::TokenType::EOF;
+
+/// Precedences utilisees hors de get_operator_precedence
+pub const COMPARISON_PRECEDENCE: u8 = 5;
+pub const NOT_PRECEDENCE: u8 = 4;
+pub const POWER_PRECEDENCE: u8 = 13;
+
 //////////////////////Debut///////////////////////////
 
 pub struct Parser {
     pub(crate) tokens: Vec<Token>, // liste des tokens genere par le lexer
     pub(crate) current: usize,     // index du token actuel
     pub(crate) syntax_mode: SyntaxMode,
+    /// Dictionnaire de mots cles du fichier, reutilise pour relire les trous des f-strings
+    pub(crate) keyword_set: KeywordSet,
     indent_level: Vec<usize>,
+    /// Vrai pendant le parsing d'une condition: `x { ... }` y est un bloc, pas un struct literal
+    pub(crate) no_struct_literal: bool,
+    /// Erreurs recuperees pendant le parsing, dans l'ordre du source
+    pub(crate) errors: Vec<ParserError>,
 }
 
 
@@ -28,10 +42,19 @@ impl Parser {
             tokens,
             current: 0,
             syntax_mode,
+            keyword_set: KeywordSet::default(),
             indent_level: vec![0],
+            no_struct_literal: false,
+            errors: Vec::new(),
         }
     }
 
+    /// Dictionnaire de mots cles utilise par le lexer (anglais par defaut)
+    pub fn with_keyword_set(mut self, keyword_set: KeywordSet) -> Self {
+        self.keyword_set = keyword_set;
+        self
+    }
+
     // pub fn parse_program(&mut self) -> Result<ASTNode, ParserError> {
     //     let mut statements = Vec::new();
     //     while !self.is_at_end() {
@@ -40,24 +63,56 @@ impl Parser {
     //     Ok(ASTNode::Program(statements))
     // }
 
-    pub fn parse_program(&mut self) -> Result<ASTNode, ParserError> {
+    /// Parse tout le fichier sans s'arreter a la premiere erreur.
+    /// Chaque erreur est remplacee par un noeud `ASTNode::Error` dans l'arbre et renvoyee avec les autres.
+    pub fn parse_program(&mut self) -> (ASTNode, Vec<ParserError>) {
         let mut statements = Vec::new();
         while !self.is_at_end() {
-            let statement = match self.parse_statement() {
-                Ok(stmt) => stmt,
+            let before = self.current;
+            match self.parse_statement() {
+                Ok(stmt) => statements.push(stmt),
                 Err(e) => {
-                    eprintln!("Erreur de parsing : {:?}", e);
-                    // On applique la synchronisation
-                    self.synchronize()?;
-                    // On peut continuer à la prochaine itération
-                    continue;
+                    let e = self.attach_span(e);
+                    trace!("Erreur de parsing : {:?}", e);
+                    self.errors.push(e.clone());
+                    statements.push(ASTNode::Error(e));
+                    // On se place sur la prochaine declaration
+                    if self.synchronize().is_err() {
+                        break;
+                    }
                 }
-            };
-            statements.push(statement);
+            }
+            // Garantit la progression si aucun token n'a ete consomme
+            if self.current == before {
+                self.advance();
+            }
         }
-        Ok(ASTNode::Program(statements))
+        (ASTNode::Program(statements), std::mem::take(&mut self.errors))
     }
 
+    /// Erreurs deja recuperees (blocs compris)
+    pub fn errors(&self) -> &[ParserError] {
+        &self.errors
+    }
+
+    /// Parse une instruction d'un bloc; en cas d'erreur, l'erreur est gardee,
+    /// un noeud `ASTNode::Error` la remplace et le parsing reprend a l'instruction suivante du bloc
+    fn parse_block_statement(&mut self) -> ASTNode {
+        let before = self.current;
+        match self.parse_statement() {
+            Ok(stmt) => stmt,
+            Err(e) => {
+                let e = self.attach_span(e);
+                trace!("Erreur de parsing dans un bloc : {:?}", e);
+                self.errors.push(e.clone());
+                self.synchronize_block();
+                if self.current == before && !self.is_block_end() {
+                    self.advance();
+                }
+                ASTNode::Error(e)
+            }
+        }
+    }
 
     pub fn current_position(&self) -> Position {
         Position {
@@ -65,6 +120,49 @@ impl Parser {
         }
     }
 
+    /// Span du token a l'index donne (ou du dernier token si l'index depasse)
+    pub fn token_span(&self, index: usize) -> Span {
+        self.tokens
+            .get(index)
+            .or_else(|| self.tokens.last())
+            .map(|token| token.span())
+            .unwrap_or_default()
+    }
+
+    /// Span du token courant
+    pub fn current_span(&self) -> Span {
+        self.token_span(self.current)
+    }
+
+    /// Span du dernier token consomme
+    pub fn previous_span(&self) -> Span {
+        if self.current > 0 {
+            self.token_span(self.current - 1)
+        } else {
+            self.current_span()
+        }
+    }
+
+    /// Span allant du debut donne jusqu'au dernier token consomme
+    pub fn span_from(&self, start: Span) -> Span {
+        let end = self.previous_span();
+        if end.end < start.start {
+            start
+        } else {
+            start.to(end)
+        }
+    }
+
+    /// Complete le span d'une erreur a partir de l'index du token fautif
+    pub fn attach_span(&self, error: ParserError) -> ParserError {
+        if error.span == Span::dummy() {
+            let span = self.token_span(error.position.index);
+            error.with_span(span)
+        } else {
+            error
+        }
+    }
+
     /// fonction pour aider le parsing des blocs
     #[allow(dead_code)]
     fn get_syntax_mode(&self) ->SyntaxMode{
@@ -92,14 +190,14 @@ impl Parser {
     }
 
     fn parse_indented_block(&mut self) -> Result<Vec<ASTNode>, ParserError> {
-        println!("Parsing indented block");
+        trace!("Parsing indented block");
         self.consume(TokenType::DELIMITER(Delimiters::COLON))?;
         self.consume(TokenType::NEWLINE)?;
         self.consume(TokenType::INDENT)?;
 
         let mut statements = Vec::new();
         while !self.check(&[TokenType::DEDENT, TokenType::EOF]) {
-            let stmt = self.parse_statement()?;
+            let stmt = self.parse_block_statement();
             //self.consume(TokenType::NEWLINE)?;
             statements.push(stmt);
         }
@@ -109,12 +207,17 @@ impl Parser {
     }
 
     fn parse_braced_block(&mut self) -> Result<Vec<ASTNode>, ParserError> {
-        println!("Parsing braced block");
+        trace!("Parsing braced block");
         self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
         let mut statements = Vec::new();
 
         while !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE), TokenType::EOF]) {
-            let stmt = self.parse_statement()?;
+            let stmt = self.parse_block_statement();
+            if matches!(stmt, ASTNode::Error(_)) {
+                // synchronize_block s'est deja place sur l'instruction suivante
+                statements.push(stmt);
+                continue;
+            }
 
             // if !self.is_block_expression(&stmt) && !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) {
             //     self.consume(TokenType::DELIMITER(Delimiters::SEMICOLON))?;
@@ -187,7 +290,7 @@ impl Parser {
 
 
     pub(crate) fn parse_function_parameters(&mut self) -> Result<Vec<Parameter>, ParserError> {
-        println!("Début du parsing des paramètres de fonction");
+        trace!("Début du parsing des paramètres de fonction");
         let mut parameters = Vec::new();
 
         if self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]){
@@ -198,25 +301,26 @@ impl Parser {
         if !self.match_token(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
             loop {
                 //let name = self.consume_parameter_name()?;
-                let name = self.consume_identifier()?;
-                println!("Nom du paramètre parsé : {}", name);
+                let start = self.current_span();
+                let pattern = self.parse_pattern_complex()?;
+                trace!("Pattern du paramètre parsé : {:?}", pattern);
                 self.consume(TokenType::DELIMITER(Delimiters::COLON))?;
                 let param_type = self.parse_type()?;
-                println!("Type du paramètre parsé : {:?}", param_type);
+                trace!("Type du paramètre parsé : {:?}", param_type);
 
-                parameters.push(Parameter { name, parameter_type: param_type });
+                parameters.push(Parameter { pattern, parameter_type: param_type, span: self.span_from(start) });
 
                 if self.match_token(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
                     continue;
                 } else if self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
                     break;
                 }else {
-                    println!("Erreur lors du parsing des paramètres, token actuel : {:?}", self.current_token());
+                    trace!("Erreur lors du parsing des paramètres, token actuel : {:?}", self.current_token());
                     return Err(ParserError::new(ExpectedParameterName, self.current_position()));
                 }
             }
         }
-        println!("Paramètres parsés : {:?}", parameters);
+        trace!("Paramètres parsés : {:?}", parameters);
         Ok(parameters)
     }
 
@@ -226,8 +330,8 @@ impl Parser {
         match self.syntax_mode {
             SyntaxMode::Braces => {
                 self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
-                while !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) {
-                    let statement = self.parse_statement()?;
+                while !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) && !self.is_at_end() {
+                    let statement = self.parse_block_statement();
                     body.push(statement);
                 }
                 self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
@@ -238,13 +342,11 @@ impl Parser {
                 self.consume(TokenType::INDENT)?;
 
                 while !self.check(&[TokenType::EOF, TokenType::DEDENT]) {
-                    let statement = self.parse_statement()?;
+                    let statement = self.parse_block_statement();
                     body.push(statement);
                 }
-                //consommer le DEDENT final s'il existe
-                if !self.check(&[TokenType::DEDENT]) {
-                    self.consume(TokenType::DEDENT)?;
-                }
+                // Le DEDENT final appartient a ce bloc (les separateurs ne le consomment pas)
+                self.match_token(&[TokenType::DEDENT]);
             }
         }
 
@@ -252,29 +354,29 @@ impl Parser {
     }
 
     pub fn parse_body_block(&mut self) -> Result<Vec<ASTNode>,ParserError>{
-        println!("Début du parsing du corps");
+        trace!("Début du parsing du corps");
         self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
         let mut statements = Vec::new();
         while !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) && !self.is_at_end() {
-            let stmt = self.parse_statement()?;
+            let stmt = self.parse_block_statement();
             statements.push(stmt);
         }
         self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
-        println!("Fin du parsing du corps OK!!!!!!!!!!!!");
+        trace!("Fin du parsing du corps OK!!!!!!!!!!!!");
         Ok(statements)
     }
 
     pub fn parse_block_expression(&mut self) -> Result<Vec<ASTNode>,ParserError>{
-        println!("Debut du parsing de du bloc de L'expression LAMBDA");
+        trace!("Debut du parsing de du bloc de L'expression LAMBDA");
         self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
 
         let mut body = Vec::new();
-        while !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]){
-            let statement = self.parse_statement()?;
+        while !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) && !self.is_at_end() {
+            let statement = self.parse_block_statement();
             body.push(statement);
         }
         self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
-        println!("Fin du parsing du bloc de l'expression LAMBDA OK!!!!!!!!!!!");
+        trace!("Fin du parsing du bloc de l'expression LAMBDA OK!!!!!!!!!!!");
         Ok(body)
 
     }
@@ -326,12 +428,21 @@ impl Parser {
 
 
     /// fonction pour parser les types
+    /// Parse un type, suivi eventuellement de `?` (type optionnel)
     pub fn parse_type(&mut self) -> Result<Type, ParserError> {
+        let mut parsed = self.parse_base_type()?;
+        while self.match_token(&[TokenType::OPERATOR(Operators::INTERROGATION)]) {
+            parsed = Type::Optional(Box::new(parsed));
+        }
+        Ok(parsed)
+    }
+
+    fn parse_base_type(&mut self) -> Result<Type, ParserError> {
         let token = self
             .current_token()
             .ok_or_else(|| ParserError::new(ExpectedTypeAnnotation, self.current_position()))?;
 
-        println!("Parsing type: {:?}", token);
+        trace!("Parsing type: {:?}", token);
 
         match &token.token_type {
             TokenType::KEYWORD(Keywords::INT) => {
@@ -354,8 +465,25 @@ impl Parser {
                 self.advance(); // Consomme le token `char`
                 Ok(Type::Char)
             }
+            TokenType::KEYWORD(Keywords::FN) => self.parse_function_type(),
+            TokenType::DELIMITER(Delimiters::LSBRACKET) => self.parse_array_type(),
+            TokenType::DELIMITER(Delimiters::LPAR) => self.parse_tuple_type(),
+            TokenType::OPERATOR(Operators::AMPER) => {
+                self.advance();
+                self.parse_reference_type()
+            }
+            TokenType::OPERATOR(Operators::AND) => {
+                // `&&T` est lu comme un seul token par le lexer
+                self.advance();
+                let inner = self.parse_reference_type()?;
+                Ok(Type::Reference(Box::new(inner), None))
+            }
+            TokenType::IDENTIFIER { name } if name == "Self" => {
+                self.advance();
+                Ok(Type::SelfType)
+            }
             TokenType::IDENTIFIER { name } => {
-                let base_name = name.clone();
+                let base_name = name.to_string();
                 self.advance();
                 // let base_name = name.clone();
 
@@ -371,6 +499,10 @@ impl Parser {
                         if self.check(&[TokenType::OPERATOR(Operators::GREATER)]) {
                             self.advance();
                             break;
+                        } else if self.check(&[TokenType::OPERATOR(Operators::RIGHTSHIFT)]) {
+                            // `Vec<Vec<int>>`: le `>>` ferme deux listes, on en laisse un pour la liste englobante
+                            self.tokens[self.current].token_type = TokenType::OPERATOR(Operators::GREATER);
+                            break;
                         } else if self.check(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
                             self.advance();
                         } else {
@@ -387,7 +519,7 @@ impl Parser {
                 }
             }
             _ => {
-                println!("Unexpected token: {:?}", token);
+                trace!("Unexpected token: {:?}", token);
                 // Si le token actuel n'est pas un type valide, renvoyer une erreur
                 Err(ParserError::new(
                     InvalidTypeAnnotation,
@@ -397,6 +529,90 @@ impl Parser {
         }
     }
 
+    /// [T] ou [T; N]
+    fn parse_array_type(&mut self) -> Result<Type, ParserError> {
+        self.consume(TokenType::DELIMITER(Delimiters::LSBRACKET))?;
+        let element_type = self.parse_type()?;
+
+        if self.match_token(&[TokenType::DELIMITER(Delimiters::SEMICOLON)]) {
+            let size = match self.current_token().map(|t| &t.token_type) {
+                Some(TokenType::INTEGER { value, .. }) => usize::try_from(value)
+                    .map_err(|_| ParserError::new(InvalidTypeAnnotation, self.current_position()))?,
+                _ => return Err(ParserError::new(InvalidTypeAnnotation, self.current_position())),
+            };
+            self.advance();
+            self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
+            return Ok(Type::FixedArray(Box::new(element_type), size));
+        }
+
+        self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
+        Ok(Type::Array(Box::new(element_type)))
+    }
+
+    /// () , (T) , (T,) , (A, B, ...)
+    fn parse_tuple_type(&mut self) -> Result<Type, ParserError> {
+        self.consume(TokenType::DELIMITER(Delimiters::LPAR))?;
+        if self.match_token(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+            return Ok(Type::Tuple(Vec::new()));
+        }
+
+        let first = self.parse_type()?;
+        if self.match_token(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+            // Simple parenthesage
+            return Ok(first);
+        }
+
+        let mut elements = vec![first];
+        while self.match_token(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
+            if self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+                break;
+            }
+            elements.push(self.parse_type()?);
+        }
+        self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
+        Ok(Type::Tuple(elements))
+    }
+
+    /// Apres le `&`: ['a] [mut] T
+    fn parse_reference_type(&mut self) -> Result<Type, ParserError> {
+        let lifetime = if self.check_lifetime_token() {
+            Some(self.parse_lifetime()?)
+        } else {
+            None
+        };
+
+        if self.match_token(&[TokenType::KEYWORD(Keywords::MUT)]) {
+            let inner = self.parse_type()?;
+            Ok(Type::ReferenceMutable(Box::new(inner), lifetime))
+        } else {
+            let inner = self.parse_type()?;
+            Ok(Type::Reference(Box::new(inner), lifetime))
+        }
+    }
+
+    /// fn(A, B) -> R   (sans `->`, le retour est le type unit `()`)
+    fn parse_function_type(&mut self) -> Result<Type, ParserError> {
+        self.consume(TokenType::KEYWORD(Keywords::FN))?;
+        self.consume(TokenType::DELIMITER(Delimiters::LPAR))?;
+
+        let mut parameters = Vec::new();
+        while !self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+            parameters.push(self.parse_type()?);
+            if !self.match_token(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
+                break;
+            }
+        }
+        self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
+
+        let return_type = if self.match_token(&[TokenType::OPERATOR(Operators::RARROW)]) {
+            self.parse_type()?
+        } else {
+            Type::Tuple(Vec::new())
+        };
+
+        Ok(Type::Function(parameters, Box::new(return_type)))
+    }
+
 
     // fn parse_type_cast(&mut self,expr: Expression) -> Result<Expression, ParserError> {
     //
@@ -404,9 +620,10 @@ impl Parser {
 
 
     pub fn parse_match_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction match");
+        trace!("Début du parsing de l'instruction match");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::MATCH))?;
-        let match_expr = self.parse_expression(0)?;
+        let match_expr = self.parse_condition_expression()?;
 
         let mut arms = Vec::new();
         if self.syntax_mode == SyntaxMode::Indentation {
@@ -432,10 +649,11 @@ impl Parser {
             self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
         }
 
-        println!("Fin du parsing de l'instruction match OK!!!!!!!!!!!!!!");
+        trace!("Fin du parsing de l'instruction match OK!!!!!!!!!!!!!!");
         Ok(ASTNode::Statement(Statement::MatchStatement(MatchStatement{
             expression: match_expr,
             arms,
+            span: self.span_from(start),
         })))
 
     }
@@ -516,7 +734,8 @@ impl Parser {
         }
     }
     pub fn parse_match_arm(&mut self) -> Result<MatchArm, ParserError> {
-        println!("Début du parsing du bras de match");
+        trace!("Début du parsing du bras de match");
+        let start = self.current_span();
         let pattern = self.parse_pattern_complex()?;
 
         let guard = self.parse_guard()?;
@@ -526,19 +745,25 @@ impl Parser {
         }else {
             self.parse_braced_arm_body()?
         };
-        println!("Fin du parsing du bras de match OK!!!!!!!!!!!!!!!");
+        trace!("Fin du parsing du bras de match OK!!!!!!!!!!!!!!!");
         Ok(MatchArm{
             pattern,
             guard,
             body,
+            span: self.span_from(start),
         })
     }
 
     pub fn parse_pattern_complex(&mut self) -> Result<Pattern, ParserError>{
-        if self.check(&[TokenType::DELIMITER(Delimiters::DOT)]){
-            self.consume(TokenType::DELIMITER(Delimiters::DOT))?;
-            self.consume(TokenType::DELIMITER(Delimiters::DOT))?;
-            Ok(Pattern::Rest)
+        if self.check(&[TokenType::OPERATOR(Operators::DOTDOT)]){
+            // `..` ignore le reste, `..rest` le lie a un nom
+            let start = self.current_span();
+            self.advance();
+            let name = match self.current_token().map(|token| &token.token_type) {
+                Some(TokenType::IDENTIFIER { .. }) => Some(self.consume_identifier()?),
+                _ => None,
+            };
+            Ok(Pattern::Rest(name, self.span_from(start)))
         }else if self.check(&[TokenType::DELIMITER(Delimiters::LPAR)]){
             self.parse_tuple_pattern()
         }else if self.check(&[TokenType::DELIMITER(Delimiters::LSBRACKET)]){
@@ -548,6 +773,7 @@ impl Parser {
     }
 
     pub fn parse_tuple_pattern(&mut self) -> Result<Pattern, ParserError> {
+        let start = self.current_span();
         self.consume(TokenType::DELIMITER(Delimiters::LPAR))?;
         let mut patterns = Vec::new();
         if !self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]){
@@ -560,12 +786,13 @@ impl Parser {
             }
         }
         self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
-        println!("Fin du parsing du tuple pattern OK!!!!!!!!!!!!!!!");
-        Ok(Pattern::Tuple(patterns))
+        trace!("Fin du parsing du tuple pattern OK!!!!!!!!!!!!!!!");
+        Ok(Pattern::Tuple(patterns, self.span_from(start)))
     }
 
     //feature pour plus tard
     pub fn parse_tuple_rest_pattern(&mut self) -> Result<Pattern, ParserError> {
+        let start = self.current_span();
         self.consume(TokenType::DELIMITER(Delimiters::LPAR))?;
         let mut patterns = Vec::new();
         let mut has_rest = false;
@@ -590,11 +817,12 @@ impl Parser {
         }
 
         self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
-        Ok(Pattern::TupleRest(patterns))
+        Ok(Pattern::TupleRest(patterns, self.span_from(start)))
     }
 
     pub fn parse_array_pattern(&mut self) -> Result<Pattern, ParserError> {
-        println!("Début du parsing du pattern de tableau Array");
+        trace!("Début du parsing du pattern de tableau Array");
+        let start = self.current_span();
         self.consume(TokenType::DELIMITER(Delimiters::LSBRACKET))?;
         let mut patterns = Vec::new();
         if !self.check(&[TokenType::DELIMITER(Delimiters::RSBRACKET)]){
@@ -607,13 +835,14 @@ impl Parser {
             }
         }
         self.consume(TokenType::DELIMITER(Delimiters::RSBRACKET))?;
-        println!("Fin du parsing du pattern de tableau Array OK!!!!!!!!!!!!!!!");
-        Ok(Pattern::Array(patterns))
+        trace!("Fin du parsing du pattern de tableau Array OK!!!!!!!!!!!!!!!");
+        Ok(Pattern::Array(patterns, self.span_from(start)))
 
     }
 
     //feature pour plus tard
     pub fn parse_array_rest_pattern(&mut self) -> Result<Pattern, ParserError> {
+        let start = self.current_span();
         self.consume(TokenType::DELIMITER(Delimiters::LSBRACKET))?;
         let mut before = Vec::new();
         let mut after = Vec::new();
@@ -647,10 +876,12 @@ impl Parser {
         Ok(Pattern::ArrayRest(ArrayRest{
             before,
             after,
+            span: self.span_from(start),
         }))
     }
 
     pub fn parse_range_pattern(&mut self) -> Result<Pattern, ParserError> {
+        let start_span = self.current_span();
         let start = if !self.check(&[TokenType::DELIMITER(Delimiters::DOT)]) {
             Some(Box::new(self.parse_expression(0)?))
         } else {
@@ -672,52 +903,68 @@ impl Parser {
         Ok(Pattern::RangePattern(RangePattern{
             start,
             end,
-            inclusive: false  // Par défaut, on utilise la range exclusive
+            inclusive: false,  // Par défaut, on utilise la range exclusive
+            span: self.span_from(start_span),
         }))
     }
 
 
     pub fn parse_pattern(&mut self) -> Result<Pattern, ParserError> {
-        println!("Début du parsing du pattern");
+        trace!("Début du parsing du pattern");
+        let span = self.current_span();
 
 
         if self.match_token(&[TokenType::OPERATOR(Operators::UNDERSCORE)]) {
             // Pattern par défaut '_'
-            Ok(Pattern::Wildcard)
+            Ok(Pattern::Wildcard(span))
         } else if let Some(token) = self.current_token() {
             match &token.token_type {
                 TokenType::IDENTIFIER { name } => {
                     if name == "_" {
                         self.advance();
-                        Ok(Pattern::Wildcard)
+                        Ok(Pattern::Wildcard(span))
                     } else {
-                        let identifier = name.clone();
+                        let identifier = name.to_string();
                         self.advance();
-                        Ok(Pattern::Identifier(identifier))
+                        if self.check(&[
+                            TokenType::DELIMITER(Delimiters::DOUBLECOLON),
+                            TokenType::DELIMITER(Delimiters::LPAR),
+                            TokenType::DELIMITER(Delimiters::LCURBRACE),
+                        ]) {
+                            self.parse_constructor_pattern(identifier, span)
+                        } else {
+                            Ok(Pattern::Identifier(identifier, span))
+                        }
                     }
                 },
-                TokenType::INTEGER { value } => {
+                TokenType::INTEGER { value, suffix } => {
                     let int_value = value.clone(); // Clonez la valeur ici
+                    let suffix = *suffix;
                     self.advance(); // Consomme l'entier
-                    Ok(Pattern::Literal(Literal::Integer { value: int_value }))
+                    Ok(Pattern::Literal(Literal::Integer { value: int_value, suffix }, span))
                 },
-                TokenType::FLOAT { value } => {
+                TokenType::FLOAT { value, .. } => {
                     let float_value = *value;
                     self.advance(); // Consomme le flottant
-                    Ok(Pattern::Literal(Literal::Float { value: float_value }))
+                    Ok(Pattern::Literal(Literal::Float { value: float_value }, span))
+                },
+                TokenType::IMAGINARY { value } => {
+                    let imaginary = *value;
+                    self.advance(); // Consomme l'imaginaire
+                    Ok(Pattern::Literal(Literal::Complex { value: Complex64::new(0.0, imaginary) }, span))
                 },
                 TokenType::STRING { value, kind: _ } => {
                     let string_value = value.clone();
                     self.advance(); // Consomme la chaîne
-                    Ok(Pattern::Literal(Literal::String(string_value)))
+                    Ok(Pattern::Literal(Literal::String(string_value), span))
                 }
                 TokenType::KEYWORD(Keywords::TRUE) => {
                     self.advance(); // Consomme le mot-clé 'true'
-                    Ok(Pattern::Literal(Literal::Boolean(true)))
+                    Ok(Pattern::Literal(Literal::Boolean(true), span))
                 },
                 TokenType::KEYWORD(Keywords::FALSE) => {
                     self.advance(); // Consomme le mot-clé 'false'
-                    Ok(Pattern::Literal(Literal::Boolean(false)))
+                    Ok(Pattern::Literal(Literal::Boolean(false), span))
                 },
 
 
@@ -730,18 +977,81 @@ impl Parser {
 
 
 
+    /// Shape::Circle(r), Some(x), Color::Red, Shape::Move { x, y: 0, .. }
+    /// Le premier segment du chemin a deja ete consomme
+    pub fn parse_constructor_pattern(&mut self, first: String, start: Span) -> Result<Pattern, ParserError> {
+        trace!("Début du parsing du pattern constructeur");
+        let mut path = vec![first];
+        while self.match_token(&[TokenType::DELIMITER(Delimiters::DOUBLECOLON)]) {
+            path.push(self.consume_identifier()?);
+        }
+
+        let arguments = if self.match_token(&[TokenType::DELIMITER(Delimiters::LPAR)]) {
+            let mut patterns = Vec::new();
+            while !self.check(&[TokenType::DELIMITER(Delimiters::RPAR)]) {
+                patterns.push(self.parse_pattern_complex()?);
+                if !self.match_token(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
+                    break;
+                }
+            }
+            self.consume(TokenType::DELIMITER(Delimiters::RPAR))?;
+            ConstructorArguments::Tuple(patterns)
+        } else if self.match_token(&[TokenType::DELIMITER(Delimiters::LCURBRACE)]) {
+            let mut fields = Vec::new();
+            let mut rest = false;
+            while !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) {
+                if self.match_token(&[TokenType::OPERATOR(Operators::DOTDOT)]) {
+                    // `..` ignore les champs restants et doit etre en derniere position
+                    rest = true;
+                    break;
+                }
+                let field_span = self.current_span();
+                let field = self.consume_identifier()?;
+                // `x` seul lie le champ a une variable du meme nom
+                let pattern = if self.match_token(&[TokenType::DELIMITER(Delimiters::COLON)]) {
+                    self.parse_pattern_complex()?
+                } else {
+                    Pattern::Identifier(field.clone(), field_span)
+                };
+                fields.push((field, pattern));
+                if !self.match_token(&[TokenType::DELIMITER(Delimiters::COMMA)]) {
+                    break;
+                }
+            }
+            if !self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE)]) {
+                return Err(ParserError::new(ExpectedCommaOrCloseBrace, self.current_position()));
+            }
+            self.advance();
+            ConstructorArguments::Struct { fields, rest }
+        } else {
+            ConstructorArguments::Unit
+        };
+
+        trace!("Fin du parsing du pattern constructeur OK!!!!!!!!!!!!!!!");
+        Ok(Pattern::Constructor(ConstructorPattern {
+            path,
+            arguments,
+            span: self.span_from(start),
+        }))
+    }
+
     pub fn parse_return_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction de retour");
+        trace!("Début du parsing de l'instruction de retour");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::RETURN))?;
-        let value = if !self.match_token(&[TokenType::NEWLINE, TokenType::DEDENT, TokenType::EOF]) {
+        let value = if !self.is_statement_end() {
             Some(self.parse_expression(0)?)
         } else {
             None
         };
-        println!("Valeur de retour parsée : {:?}", value);
-        println!("Fin du parsing de l'instruction de retour OK!!!!!!!!!!!!!!");
+        trace!("Valeur de retour parsée : {:?}", value);
+        let span = self.span_from(start);
+        // Le separateur laisse le DEDENT au bloc qui se termine
+        self.consume_seperator();
+        trace!("Fin du parsing de l'instruction de retour OK!!!!!!!!!!!!!!");
         Ok(ASTNode::Statement(Statement::ReturnStatement(ReturnStatement{
             value,
+            span,
         })))
 
     }
@@ -751,12 +1061,14 @@ impl Parser {
     /// J'ai deja  implementé la gestion des emprunts dans parse_unary_expression()
 
     pub fn parse_borrow(&mut self) -> Result<Expression, ParserError> {
+        let start = self.current_span();
         if self.match_token(&[TokenType::OPERATOR(Operators::AMPER)]){
             let mutable = self.match_token(&[TokenType::KEYWORD(Keywords::MUT)]);
             let expression = self.parse_expression(0)?;
             Ok(Expression::UnaryOperation(UnaryOperation{
                 operator: if mutable { UnaryOperator::ReferenceMutable} else {UnaryOperator::Reference},
                 operand: Box::new(expression),
+                span: self.span_from(start),
             }))
         } else {
             self.parse_primary_expression()
@@ -765,8 +1077,9 @@ impl Parser {
     }
 
     pub fn parse_module_import_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction d'import de module Import/Use");
+        trace!("Début du parsing de l'instruction d'import de module Import/Use");
 
+        let start = self.previous_span();
         let keyword_token = self.previous_token();
         let keyword = match keyword_token.unwrap().token_type {
             TokenType::KEYWORD(Keywords::USE) => ImportKeyword::Use,
@@ -778,7 +1091,7 @@ impl Parser {
         let module_path = self.parse_module_path()?;
 
         if self.match_token(&[TokenType::DELIMITER(Delimiters::DOUBLECOLON)]){
-            self.parse_specific_import(keyword, module_path)
+            self.parse_specific_import(keyword, module_path, start)
         }else {
             let alias = if self.match_token(&[TokenType::KEYWORD(Keywords::AS)]) {
                 let name = self.consume_identifier()?;
@@ -787,12 +1100,14 @@ impl Parser {
                 None
             };
 
+            let span = self.span_from(start);
             self.consume_seperator();
-            println!("Fin du parsing de l'instruction d'import de module Import/Use OK!!!!!!!!!!!!!!");
+            trace!("Fin du parsing de l'instruction d'import de module Import/Use OK!!!!!!!!!!!!!!");
             Ok(ASTNode::Statement(Statement::ModuleImportStatement(ModuleImportStatement{
                 keyword,
                 module_path,
                 alias,
+                span,
             })))
         }
 
@@ -815,7 +1130,7 @@ impl Parser {
         Ok(path)
     }
 
-    pub fn parse_specific_import(&mut self, keyword: ImportKeyword, module_path: Vec<String>) -> Result<ASTNode, ParserError>{
+    pub fn parse_specific_import(&mut self, keyword: ImportKeyword, module_path: Vec<String>, start: Span) -> Result<ASTNode, ParserError>{
         self.consume(TokenType::DELIMITER(Delimiters::LCURBRACE))?;
 
         // parser la liste des element importés
@@ -840,14 +1155,16 @@ impl Parser {
         }
 
         self.consume(TokenType::DELIMITER(Delimiters::RCURBRACE))?;
+        let span = self.span_from(start);
         self.consume_seperator();
 
-        println!("Fin du parsing de L'importation Specifique OK!!!!!!!!!!!!!!");
+        trace!("Fin du parsing de L'importation Specifique OK!!!!!!!!!!!!!!");
         Ok(ASTNode::Statement(Statement::SpecificImportStatement(SpecificImportStatement{
             keyword,
             module_path,
             alias: None,
             imports: import_list,
+            span,
         })))
 
     }
@@ -888,7 +1205,13 @@ impl Parser {
 
         match explicit_type {
             Type::Infer => Ok(inferred_type),
-            explicit if explicit == &inferred_type => Ok(explicit.clone()),
+            explicit if explicit == &inferred_type || inferred_type == Type::Infer => Ok(explicit.clone()),
+            // `let x: i8 = 5`: un litteral int ou float prend le type de taille fixe declare
+            explicit if explicit.fixed_width().is_some_and(|width| {
+                inferred_type == if width.is_float() { Type::Float } else { Type::Int }
+            }) => Ok(explicit.clone()),
+            // `let z: complex = 1.5`: int et float sont promus en complex
+            explicit if explicit.is_complex() && matches!(inferred_type, Type::Int | Type::Float) => Ok(explicit.clone()),
             _explicit => Err(ParserError::new(
                 ParserErrorType::TypeInferenceError,
                 self.current_position(),
@@ -898,19 +1221,43 @@ impl Parser {
 
 
 
+    /// Table de precedence des operateurs binaires (du plus faible au plus fort)
     pub fn get_operator_precedence(&self, operator: &Operator) -> u8 {
         match operator {
-            Operator::Multiplication | Operator::Division | Operator::Modulo => 5,
-            Operator::Addition | Operator::Substraction => 4,
-            Operator::LessThan | Operator::GreaterThan | Operator::LesshanOrEqual | Operator::GreaterThanOrEqual => 3,
-            Operator::Range | Operator::RangeInclusive => 3,
-            Operator::Equal | Operator::NotEqual => 2,
-            Operator::And => 1,
-            //Operator::Or => 0,
-            _ => 0,
+            // assignation: traitee avant la boucle de parse_expression, garde le niveau le plus bas
+            Operator::Equal => 0,
+            Operator::Range | Operator::RangeInclusive => 1,
+            Operator::Or => 2,
+            Operator::And => 3,
+            // 4 : `not` prefixe (NOT_PRECEDENCE)
+            Operator::EqualEqual | Operator::NotEqual
+            | Operator::LessThan | Operator::GreaterThan
+            | Operator::LesshanOrEqual | Operator::GreaterThanOrEqual
+            | Operator::In | Operator::NotIn
+            | Operator::Is | Operator::IsNot => COMPARISON_PRECEDENCE,
+            Operator::BitwiseOr => 6,
+            Operator::BitwiseXor => 7,
+            Operator::BitwiseAnd => 8,
+            Operator::ShiftLeft | Operator::ShiftRight => 9,
+            Operator::Addition | Operator::Substraction => 10,
+            Operator::Multiplication | Operator::Division | Operator::Modulo
+            | Operator::MatrixMultiplication => 11,
+            // 12 : operateurs unaires (-x, !x, ~x)
+            Operator::Power => POWER_PRECEDENCE,
         }
     }
 
+    /// `**` est associatif a droite: 2 ** 3 ** 2 == 2 ** (3 ** 2)
+    pub fn is_right_associative(&self, operator: &Operator) -> bool {
+        matches!(operator, Operator::Power)
+    }
+
+    /// Operateurs de comparaison, enchainables: a < b < c == (a < b) and (b < c),
+    /// represente par un `ComparisonChain` ou b n'apparait qu'une fois.
+    pub fn is_comparison_operator(&self, operator: &Operator) -> bool {
+        self.get_operator_precedence(operator) == COMPARISON_PRECEDENCE
+    }
+
 
 
     pub fn get_compound_operator(&self,op:&Operators) -> Option<CompoundOperator>{
@@ -927,7 +1274,7 @@ impl Parser {
 
     pub fn peek_operator(&self) -> Option<Operator> {
         let token = self.current_token()?;
-        println!("Token: {:?}", token);
+        trace!("Token: {:?}", token);
         match &token.token_type {
             TokenType::OPERATOR(op) => {
                 match op {
@@ -947,6 +1294,25 @@ impl Parser {
                     Operators::OR => Some(Operator::Or),
                     Operators::DOTDOT => Some(Operator::Range),
                     Operators::DOTDOTEQUAL => Some(Operator::RangeInclusive),
+                    Operators::DOUBLESTAR => Some(Operator::Power),
+                    Operators::AMPER => Some(Operator::BitwiseAnd),
+                    Operators::VBAR => Some(Operator::BitwiseOr),
+                    Operators::CIRCUMFLEX => Some(Operator::BitwiseXor),
+                    Operators::LEFTSHIFT => Some(Operator::ShiftLeft),
+                    Operators::RIGHTSHIFT => Some(Operator::ShiftRight),
+                    Operators::AT => Some(Operator::MatrixMultiplication),
+                    _ => None,
+                }
+            }
+            TokenType::KEYWORD(keyword) => {
+                let next = self.peek_next_token().map(|t| &t.token_type);
+                match keyword {
+                    Keywords::AND => Some(Operator::And),
+                    Keywords::OR => Some(Operator::Or),
+                    Keywords::IN => Some(Operator::In),
+                    Keywords::NOT if next == Some(&TokenType::KEYWORD(Keywords::IN)) => Some(Operator::NotIn),
+                    Keywords::IS if next == Some(&TokenType::KEYWORD(Keywords::NOT)) => Some(Operator::IsNot),
+                    Keywords::IS => Some(Operator::Is),
                     _ => None,
                 }
             }
@@ -955,6 +1321,14 @@ impl Parser {
 
     }
 
+    /// Consomme les tokens de l'operateur renvoye par peek_operator (`not in` et `is not` en ont deux)
+    pub fn consume_operator(&mut self, operator: &Operator) {
+        self.advance();
+        if matches!(operator, Operator::NotIn | Operator::IsNot) {
+            self.advance();
+        }
+    }
+
     /// fonction pour la gestion des
 
 
@@ -968,6 +1342,23 @@ impl Parser {
         self.previous_token()
     }
 
+    /// Parse l'expression qui precede un bloc (if, elif, while, for, match, with).
+    /// Les struct literals y sont interdits, sauf entre parentheses ou crochets.
+    pub fn parse_condition_expression(&mut self) -> Result<Expression, ParserError> {
+        let previous = std::mem::replace(&mut self.no_struct_literal, true);
+        let result = self.parse_expression(0);
+        self.no_struct_literal = previous;
+        result
+    }
+
+    /// Reautorise les struct literals a l'interieur d'un delimiteur
+    pub fn with_struct_literals<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T, ParserError>) -> Result<T, ParserError> {
+        let previous = std::mem::replace(&mut self.no_struct_literal, false);
+        let result = parse(self);
+        self.no_struct_literal = previous;
+        result
+    }
+
     pub fn peek_token(&self) -> Option<&Token>{
         self.tokens.get(self.current)
     }
@@ -1010,17 +1401,17 @@ impl Parser {
     pub fn consume(&mut self, expected: TokenType) -> Result<(), ParserError> {
         if let Some(token) = self.current_token() {
             if token.token_type == expected {
-                println!("Consommation du token {:?}", token);
+                trace!("Consommation du token {:?}", token);
                 //self.print_surrounding_tokens();
                 self.advance();
                 Ok(())
             } else {
-                println!("PyRust:!!!!!!!!!!!!!!!!!!!! Erreur: token attendu {:?}, token actuel {:?}", expected, token);
+                trace!("PyRust:!!!!!!!!!!!!!!!!!!!! Erreur: token attendu {:?}, token actuel {:?}", expected, token);
                 Err(ParserError::new(UnexpectedToken, self.current_position()))
             }
         } else {
             //self.print_surrounding_tokens();
-            println!("PyRust:!!!!!!!!!!!!!!!!: Erreur: fin de l'entrée inattendue");
+            trace!("PyRust:!!!!!!!!!!!!!!!!: Erreur: fin de l'entrée inattendue");
             Err(ParserError::new(UnexpectedEndOfInput, self.current_position()))
         }
     }
@@ -1045,8 +1436,8 @@ impl Parser {
 
     pub fn consume_identifier(&mut self) -> Result<String, ParserError> {
         let current_token = self.current_token().ok_or_else(|| ParserError::new(UnexpectedEOF,self.current_position()))?;
-        if let TokenType::IDENTIFIER {name:_} = &current_token.token_type{
-            let name = current_token.text.clone();
+        if let TokenType::IDENTIFIER {name} = &current_token.token_type{
+            let name = name.to_string();
             self.advance();
             Ok(name)
         } else { Err(ParserError::new(ExpectIdentifier,self.current_position())) }
@@ -1061,7 +1452,7 @@ impl Parser {
             Position {
                 index: self.current,
             },
-        )
+        ).with_span(self.current_span())
     }
 
     fn print_surrounding_tokens(&self) {
@@ -1076,37 +1467,36 @@ impl Parser {
         } else {
             None
         };
-        println!("");
-        println!("---------------- Token Error Context--by-YmC ----------");
+        trace!("");
+        trace!("---------------- Token Error Context--by-YmC ----------");
         if let Some(prev) = prev_token {
-            println!("Previous Token: {:?}", prev);
+            trace!("Previous Token: {:?}", prev);
         }
         if let Some(current) = current_token {
-            println!("Current Token: {:?}", current);
+            trace!("Current Token: {:?}", current);
         }
         if let Some(next) = next_token {
-            println!("Next Token: {:?}", next);
+            trace!("Next Token: {:?}", next);
         }
-        println!("----------------------------------------------------------");
-        println!("");
+        trace!("----------------------------------------------------------");
+        trace!("");
     }
 
     pub fn consume_seperator(&mut self)  {
-        println!("Mode de syntaxe : {:?}", self.syntax_mode);
+        trace!("Mode de syntaxe : {:?}", self.syntax_mode);
         match self.syntax_mode{
             SyntaxMode::Indentation =>{
                 // ordre logique de verification EOF → DEDENT → NEWLINE
-                println!("Indentation Mode");
+                // Un DEDENT termine l'instruction mais reste au bloc qui le ferme
+                trace!("Indentation Mode");
                 if self.check(&[TokenType::EOF]){
                     let _ = self.consume(TokenType::EOF);
-                }else if self.check(&[TokenType::DEDENT]){
-                    let _ = self.consume(TokenType::DEDENT);
-                }else {
+                }else if !self.check(&[TokenType::DEDENT]){
                     let _ = self.consume(TokenType::NEWLINE) ;
                 }
             }
             SyntaxMode::Braces =>{
-                println!("Braces Mode");
+                trace!("Braces Mode");
                 if self.check(&[TokenType::DELIMITER(Delimiters::SEMICOLON)]) || self.check(&[TokenType::EOF]){
                     let _  = self.consume(TokenType::DELIMITER(Delimiters::SEMICOLON));
                 }
@@ -1114,6 +1504,18 @@ impl Parser {
         }
     }
 
+    /// Vrai si le token courant termine l'instruction (valeur optionnelle absente)
+    pub fn is_statement_end(&self) -> bool {
+        match self.syntax_mode {
+            SyntaxMode::Indentation => self.check(&[TokenType::NEWLINE, TokenType::DEDENT, TokenType::EOF]),
+            SyntaxMode::Braces => self.check(&[
+                TokenType::DELIMITER(Delimiters::SEMICOLON),
+                TokenType::DELIMITER(Delimiters::RCURBRACE),
+                TokenType::EOF,
+            ]),
+        }
+    }
+
     /// fonction pour verifier la sequence de tokens a utiliser plus tard
     pub fn check_sequence(&self, tokens: &[TokenType]) -> bool {
         for (i, token_type) in tokens.iter().enumerate() {
@@ -1135,7 +1537,7 @@ impl Parser {
                         TokenType::DELIMITER(Delimiters::COLON)
                     ) => {
                         // Clone le nom avant d'avancer
-                        let label_name = name.clone();
+                        let label_name = name.to_string();
 
                         // Consomme l'identifiant et le ':'
                         self.advance(); // Consomme l'identifiant
@@ -1153,12 +1555,7 @@ impl Parser {
 
     // fonction pour checke  le lifetime
     pub fn check_lifetime_token(&mut self) ->bool{
-        if let Some(token) = &self.current_token(){
-            match &token.token_type {
-                TokenType::IDENTIFIER {name} => name.starts_with('\''),
-                _ => false,
-            }
-        }else { false }
+        matches!(self.current_token(), Some(token) if matches!(token.token_type, TokenType::LIFETIME { .. }))
     }
 
 
@@ -2,6 +2,7 @@
 use std::fmt;
 #[allow(dead_code)]
 use std::fmt::{Display, Formatter};
+use crate::parser::ast::Span;
 
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Clone)]
@@ -31,6 +32,7 @@ pub struct ParserError {
     pub error: ParserErrorType,
     pub message: String,
     pub position: Position,
+    pub span: Span,
 }
 
 #[allow(dead_code)]
@@ -95,7 +97,9 @@ pub enum ParserErrorType {
     //TypeInferenceNotSupported,/
     TypeInferenceError,
 
-    MissingExceptHandler
+    MissingExceptHandler,
+    InvalidDelTarget,
+    InvalidFormatString,
 
 
 
@@ -213,6 +217,8 @@ impl Display for ParserErrorType {
 
             ParserErrorType::TypeInferenceError => write!(f, "TypeInferenceError"),
             ParserErrorType::MissingExceptHandler => write!(f, "MissingExceptHandler"),
+            ParserErrorType::InvalidDelTarget => write!(f, "InvalidDelTarget"),
+            ParserErrorType::InvalidFormatString => write!(f, "InvalidFormatString"),
 
 
 
@@ -221,6 +227,62 @@ impl Display for ParserErrorType {
     }
 }
 
+//implementation du code d'erreur du parseur (utilise par les diagnostics)
+impl ParserErrorType {
+    /// Code stable de l'erreur, les erreurs du parseur sont dans la plage E02xx
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParserErrorType::UnexpectedToken => "E0200",
+            ParserErrorType::UnexpectedEOF => "E0201",
+            ParserErrorType::IndentationError => "E0202",
+            ParserErrorType::BraceError => "E0203",
+            ParserErrorType::InvalidAssignmentTarget => "E0204",
+            ParserErrorType::ExpectedExpression => "E0205",
+            ParserErrorType::InvalidVariableDeclaration => "E0206",
+            ParserErrorType::InvalidFunctionDeclaration => "E0207",
+            ParserErrorType::InvalidTypeAnnotation => "E0208",
+            ParserErrorType::ExpectVariableName => "E0209",
+            ParserErrorType::ExpectOperatorEqual => "E0210",
+            ParserErrorType::ExpectValue => "E0211",
+            ParserErrorType::ExpectColon => "E0212",
+            ParserErrorType::ExpectedTypeAnnotation => "E0213",
+            ParserErrorType::ExpectParameterName => "E0214",
+            ParserErrorType::ExpectFunctionName => "E0215",
+            ParserErrorType::ExpectIdentifier => "E0216",
+            ParserErrorType::ExpectedType => "E0217",
+            ParserErrorType::ExpectedDeclaration => "E0218",
+            ParserErrorType::ExpectedOpenParenthesis => "E0219",
+            ParserErrorType::ExpectedCloseParenthesis => "E0220",
+            ParserErrorType::ExpectedCommaOrCloseBrace => "E0221",
+            ParserErrorType::ExpectedStructField => "E0222",
+            ParserErrorType::ExpectedArrowOrBlock => "E0223",
+            ParserErrorType::ExpectedCommaOrClosingParenthesis => "E0224",
+            ParserErrorType::UnexpectedIndentation => "E0225",
+            ParserErrorType::UnexpectedEndOfInput => "E0226",
+            ParserErrorType::ExpectedUseOrImport => "E0227",
+            ParserErrorType::ExpectedAlias => "E0228",
+            ParserErrorType::ExpectedRangeOperator => "E0229",
+            ParserErrorType::ExpectedLifetime => "E0230",
+            ParserErrorType::ExpectedParameterName => "E0231",
+            ParserErrorType::ExpectedSelfParameter => "E0232",
+            ParserErrorType::MultipleConstructors => "E0233",
+            ParserErrorType::UnexpectedParameterName => "E0234",
+            ParserErrorType::MismatchedParametersAndAttributes => "E0235",
+            ParserErrorType::MultipleRestPatterns => "E0236",
+            ParserErrorType::InvalidConstructorReturn => "E0237",
+            ParserErrorType::InvalidConstructorParameter => "E0238",
+            ParserErrorType::InvalidConstructorName => "E0239",
+            ParserErrorType::InvalidSelfParameter => "E0240",
+            ParserErrorType::MissingType => "E0241",
+            ParserErrorType::MissingParameter => "E0242",
+            ParserErrorType::TypeInferenceError => "E0243",
+            ParserErrorType::MissingExceptHandler => "E0244",
+            ParserErrorType::InvalidDelTarget => "E0245",
+            ParserErrorType::InvalidFormatString => "E0246",
+        }
+    }
+}
+
 //implementation du message d'erreur du parseur
 impl ParserError {
     pub fn new(error: ParserErrorType, position: Position) -> Self {
@@ -295,6 +357,8 @@ impl ParserError {
             ParserErrorType::TypeInferenceError => "Type inference error".to_string(),
 
             ParserErrorType::MissingExceptHandler => "Missing except handler".to_string(),
+            ParserErrorType::InvalidDelTarget => "Invalid target for del".to_string(),
+            ParserErrorType::InvalidFormatString => "Invalid expression in f-string".to_string(),
 
 
 
@@ -304,6 +368,13 @@ impl ParserError {
             error,
             message,
             position,
+            span: Span::dummy(),
         }
     }
+
+    /// Attache la portion du code source concernee par l'erreur
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
 }
\ No newline at end of file
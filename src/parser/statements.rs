@@ -1,4 +1,4 @@
-use crate::parser::ast::{ASTNode, BreakStatement, ContinueStatement, ElifStatement, ExceptHandler, ForStatement, IfStatement, LoopStatement, Statement, TryStatement, Visibility, WhileStatement};
+use crate::parser::ast::{ASTNode, BreakStatement, ContinueStatement, DelStatement, ElifStatement, ExceptHandler, Expression, ForStatement, IfStatement, LoopStatement, RaiseStatement, Statement, TryStatement, Visibility, WhileStatement, WithStatement, YieldStatement};
 use crate::parser::parser_error::{ParserError, ParserErrorType};
 use crate::parser::parser::Parser;
 use crate::tok::{Delimiters, Keywords, TokenType};
@@ -8,15 +8,16 @@ impl Parser{
 
     /// fonction pour le gestion de structure de controle
     pub fn parse_if_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction if");
+        trace!("Début du parsing de l'instruction if");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::IF))?;
-        let condition = self.parse_expression(0)?;
+        let condition = self.parse_condition_expression()?;
         let then_block = self.parse_block()?;
 
         let mut elif_branches = Vec::new();
         while self.check(&[TokenType::KEYWORD(Keywords::ELIF)]) {
             self.consume(TokenType::KEYWORD(Keywords::ELIF))?;
-            let elif_condition = self.parse_expression(0)?;
+            let elif_condition = self.parse_condition_expression()?;
             let elif_then_block = self.parse_block()?;
             elif_branches.push(ElifStatement {
                 condition: elif_condition,
@@ -36,76 +37,89 @@ impl Parser{
             then_block,
             elif_block: elif_branches,
             else_block,
+            span: self.span_from(start),
         })))
     }
 
     pub fn parse_while_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction while");
+        trace!("Début du parsing de l'instruction while");
+        let start = self.current_span();
 
         self.consume(TokenType::KEYWORD(Keywords::WHILE))?;
 
-        let condition = self.parse_expression(0)?;
+        let condition = self.parse_condition_expression()?;
         let body = self.parse_body_block()?;
-        println!("Fin du parsing de l'instruction while OK!!!!!!!!!!!!!!");
+        trace!("Fin du parsing de l'instruction while OK!!!!!!!!!!!!!!");
         Ok(ASTNode::Statement(Statement::WhileStatement(WhileStatement{
             condition,
             body,
+            span: self.span_from(start),
         })))
 
     }
 
     pub fn parse_loop_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction loop");
+        trace!("Début du parsing de l'instruction loop");
+        let start = self.current_span();
 
         // ajoute de label optional pour la boucle pour
         let label = self.check_for_label()?;
 
         self.consume(TokenType::KEYWORD(Keywords::LOOP))?;
         let body = self.parse_block()?;
-        println!("Fin du parsing de l'instruction loop OK!!!!!!!!!!!!!!");
+        trace!("Fin du parsing de l'instruction loop OK!!!!!!!!!!!!!!");
         Ok(ASTNode::Statement(Statement::LoopStatement(LoopStatement{
             label,
             body,
+            span: self.span_from(start),
         })))
     }
 
     pub fn parse_for_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction for");
+        trace!("Début du parsing de l'instruction for");
+        let start = self.current_span();
 
         self.consume(TokenType::KEYWORD(Keywords::FOR))?;
 
-        let iterator = self.consume_identifier()?;
+        let pattern = self.parse_pattern_complex()?;
         self.consume(TokenType::KEYWORD(Keywords::IN))?;
-        let iterable = self.parse_expression(0)?;
-        let body = self.parse_body_block()?;
-        println!("Fin du parsing de l'instruction for OK!!!!!!!!!!!!!!!");
+        let iterable = self.parse_condition_expression()?;
+        let body = self.parse_block()?;
+        trace!("Fin du parsing de l'instruction for OK!!!!!!!!!!!!!!!");
         Ok(ASTNode::Statement(Statement::ForStatement(ForStatement{
-            iterator,
+            pattern,
             iterable,
-            body
+            body,
+            span: self.span_from(start),
         })))
 
     }
 
     pub fn parse_break_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction break");
+        trace!("Début du parsing de l'instruction break");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::BREAK))?;
         let label = self.check_for_label()?;
+        let span = self.span_from(start);
         self.consume_seperator();
-        println!("Fin du parsing de l'instruction break OK!!!!!!!!!!!!!!!");
+        trace!("Fin du parsing de l'instruction break OK!!!!!!!!!!!!!!!");
         Ok(ASTNode::Statement(Statement::BreakStatement(BreakStatement{
-            label
+            label,
+            span,
         })))
     }
 
     pub fn parse_continue_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction continue");
+        trace!("Début du parsing de l'instruction continue");
+        let start = self.current_span();
         self.consume(TokenType::KEYWORD(Keywords::CONTINUE))?;
         let label = self.check_for_label()?;
+        let span = self.span_from(start);
         self.consume_seperator();
-        println!("Fin du parsing de l'instruction continue OK!!!!!!!!!!!!!!!");
+        trace!("Fin du parsing de l'instruction continue OK!!!!!!!!!!!!!!!");
         Ok(ASTNode::Statement(Statement::ContinueStatement(ContinueStatement{
-            label
+            label,
+            span,
         })))
     }
 
@@ -113,7 +127,8 @@ impl Parser{
 
     ///fonction pour le parsing des blocs de code Try/Except/Finally
     pub fn parse_try_statement(&mut self) -> Result<ASTNode, ParserError> {
-        println!("Début du parsing de l'instruction try");
+        trace!("Début du parsing de l'instruction try");
+        let start = self.current_span();
 
         // Consommer le 'try'
         self.consume(TokenType::KEYWORD(Keywords::TRY))?;
@@ -146,12 +161,13 @@ impl Parser{
             body: try_body,
             handlers,
             finally_body,
+            span: self.span_from(start),
         })))
     }
 
 
     pub fn parse_except_handler(&mut self) -> Result<ExceptHandler,ParserError>{
-        println!("Début du parsing de l'except handler");
+        trace!("Début du parsing de l'except handler");
 
         self.consume(TokenType::KEYWORD(Keywords::EXCEPT))?;
 
@@ -169,7 +185,7 @@ impl Parser{
 
         let body = self.parse_block()?;
 
-        println!("Fin du parsing de l'exception handler");
+        trace!("Fin du parsing de l'exception handler");
 
         Ok(ExceptHandler {
             exception_type,
@@ -180,6 +196,109 @@ impl Parser{
     }
 
 
+    /// raise expr
+    pub fn parse_raise_statement(&mut self) -> Result<ASTNode, ParserError> {
+        trace!("Début du parsing de l'instruction raise");
+        let start = self.current_span();
+        self.consume(TokenType::KEYWORD(Keywords::RAISE))?;
+
+        if self.is_statement_end() {
+            return Err(ParserError::new(ParserErrorType::ExpectedExpression, self.current_position()));
+        }
+        let exception = self.parse_expression(0)?;
+        let span = self.span_from(start);
+        self.consume_seperator();
+        trace!("Fin du parsing de l'instruction raise OK!!!!!!!!!!!!!!!");
+        Ok(ASTNode::Statement(Statement::RaiseStatement(RaiseStatement {
+            exception,
+            span,
+        })))
+    }
+
+    /// del cible   (variable, membre ou element indexe)
+    pub fn parse_del_statement(&mut self) -> Result<ASTNode, ParserError> {
+        trace!("Début du parsing de l'instruction del");
+        let start = self.current_span();
+        self.consume(TokenType::KEYWORD(Keywords::DEL))?;
+
+        if self.is_statement_end() {
+            return Err(ParserError::new(ParserErrorType::ExpectedExpression, self.current_position()));
+        }
+        let target_position = self.current_position();
+        let target = self.parse_expression(0)?;
+        if !matches!(
+            target,
+            Expression::Identifier(..)
+                | Expression::MemberAccess(_)
+                | Expression::IndexAccess(_)
+                | Expression::ArrayAccess(_)
+                | Expression::DictAccess(_)
+                | Expression::CollectionAccess(_)
+        ) {
+            return Err(ParserError::new(ParserErrorType::InvalidDelTarget, target_position)
+                .with_span(target.span()));
+        }
+        let span = self.span_from(start);
+        self.consume_seperator();
+        trace!("Fin du parsing de l'instruction del OK!!!!!!!!!!!!!!!");
+        Ok(ASTNode::Statement(Statement::DelStatement(DelStatement {
+            target,
+            span,
+        })))
+    }
+
+    /// with expr [as name] bloc
+    pub fn parse_with_statement(&mut self) -> Result<ASTNode, ParserError> {
+        trace!("Début du parsing de l'instruction with");
+        let start = self.current_span();
+        self.consume(TokenType::KEYWORD(Keywords::WITH))?;
+
+        let target = self.parse_condition_expression()?;
+        let alias = if self.match_token(&[TokenType::KEYWORD(Keywords::AS)]) {
+            Some(self.consume_identifier()?)
+        } else {
+            None
+        };
+
+        let body = self.parse_block()?;
+        trace!("Fin du parsing de l'instruction with OK!!!!!!!!!!!!!!!");
+        Ok(ASTNode::Statement(Statement::WithStatement(WithStatement {
+            target,
+            alias,
+            body,
+            span: self.span_from(start),
+        })))
+    }
+
+    /// yield [expr]
+    pub fn parse_yield_statement(&mut self) -> Result<ASTNode, ParserError> {
+        trace!("Début du parsing de l'instruction yield");
+        let start = self.current_span();
+        self.consume(TokenType::KEYWORD(Keywords::YIELD))?;
+
+        let value = if self.is_statement_end() {
+            None
+        } else {
+            Some(self.parse_expression(0)?)
+        };
+        let span = self.span_from(start);
+        self.consume_seperator();
+        trace!("Fin du parsing de l'instruction yield OK!!!!!!!!!!!!!!!");
+        Ok(ASTNode::Statement(Statement::YieldStatement(YieldStatement {
+            value,
+            span,
+        })))
+    }
+
+    pub fn parse_pass_statement(&mut self) -> Result<ASTNode, ParserError> {
+        let start = self.current_span();
+        self.consume(TokenType::KEYWORD(Keywords::PASS))?;
+        let span = self.span_from(start);
+        self.consume_seperator();
+        Ok(ASTNode::Statement(Statement::Pass(span)))
+    }
+
+
     ///fonction principal pour  le parsing des statements
 
     pub fn parse_statement(&mut self) -> Result<ASTNode, ParserError> {
@@ -211,7 +330,7 @@ impl Parser{
         // }
 
 
-        if self.check(&[TokenType::KEYWORD(Keywords::LET)]){
+        let result = if self.check(&[TokenType::KEYWORD(Keywords::LET)]){
             self.parse_variable_declaration()
         }else if self.check(&[TokenType::KEYWORD(Keywords::FN)]) {
             let visibility = visibility.unwrap_or(Visibility::Private);
@@ -265,23 +384,37 @@ impl Parser{
             self.parse_match_statement()
         }else if self.check(&[TokenType::KEYWORD(Keywords::TRY)]) {
             self.parse_try_statement()
+        }else if self.check(&[TokenType::KEYWORD(Keywords::WITH)]) {
+            self.parse_with_statement()
+        }else if self.check(&[TokenType::KEYWORD(Keywords::RAISE)]) {
+            self.parse_raise_statement()
+        }else if self.check(&[TokenType::KEYWORD(Keywords::DEL)]) {
+            self.parse_del_statement()
+        }else if self.check(&[TokenType::KEYWORD(Keywords::YIELD)]) {
+            self.parse_yield_statement()
+        }else if self.check(&[TokenType::KEYWORD(Keywords::PASS)]) {
+            self.parse_pass_statement()
 
             // }else if self.check(&[TokenType::KEYWORD(Keywords::WHERE)]){
             //     self.parse_where_clauses()
 
         }else if self.match_token(&[TokenType::KEYWORD(Keywords::BREAK)]){
+            let span = self.previous_span();
             self.consume_seperator();
-            Ok(ASTNode::Statement(Statement::Break))
+            Ok(ASTNode::Statement(Statement::Break(span)))
             //self.parse_break_statement()
 
         }else if self.match_token(&[TokenType::KEYWORD(Keywords::CONTINUE)]){
+            let span = self.previous_span();
             self.consume_seperator();
-            Ok(ASTNode::Statement(Statement::Continue))
+            Ok(ASTNode::Statement(Statement::Continue(span)))
             //self.parse_continue_statement()
         }else {
             self.parse_expression_statement()
-        }
+        };
 
+        // Les erreurs construites avec un simple index de token recoivent leur span ici
+        result.map_err(|error| self.attach_span(error))
 
     }
 
@@ -9,7 +9,7 @@ use crate::tok::{Delimiters, Keywords, TokenType};
 impl Parser{
 
     pub fn synchronize(&mut self) -> Result<(), ParserError> {
-        println!("Début de la synchronisation après erreur");
+        trace!("Début de la synchronisation après erreur");
 
         let mut nesting_level: i32 = 0;
 
@@ -68,56 +68,70 @@ impl Parser{
                 return Ok(());
             }
 
+            // Au niveau 0, la fin de l'instruction fautive est aussi un point de reprise
+            if nesting_level == 0 && self.at_separator() {
+                self.advance();
+                if !self.check(&[TokenType::INDENT]) {
+                    return Ok(());
+                }
+                continue;
+            }
+
             self.advance();
         }
 
         Ok(())
     }
 
-    // Helper pour la récupération d'erreur dans les blocs spécifiques
-    #[allow(dead_code)]
-    fn synchronize_block(&mut self) -> Result<(), ParserError> {
-        let mut nesting = 1;
+    /// Récupération dans un bloc: avance jusqu'à la fin de l'instruction fautive
+    /// (`;` en mode Braces, fin de ligne en mode Indentation) sans sortir du bloc courant.
+    /// La fermeture du bloc (`}` ou DEDENT) n'est pas consommée.
+    pub(crate) fn synchronize_block(&mut self) {
+        let mut nesting: i32 = 0;
 
         while !self.is_at_end() {
-            // Convertir l'Option en Result avec gestion d'erreur explicite
-            let current_token = self.current_token()
-                .ok_or_else(|| ParserError::new(
-                    ParserErrorType::UnexpectedEOF,
-                    self.current_position()
-                ))?;
+            let Some(current_token) = self.current_token() else { return };
 
-            match self.syntax_mode {
-                SyntaxMode::Braces => {
-                    match &current_token.token_type {
-                        TokenType::DELIMITER(Delimiters::LCURBRACE) => nesting += 1,
-                        TokenType::DELIMITER(Delimiters::RCURBRACE) => {
-                            nesting -= 1;
-                            if nesting == 0 {
-                                self.advance();
-                                return Ok(());
-                            }
-                        }
-                        _ => {}
+            match &current_token.token_type {
+                TokenType::DELIMITER(Delimiters::LCURBRACE) => nesting += 1,
+                TokenType::INDENT if self.syntax_mode == SyntaxMode::Indentation => nesting += 1,
+                TokenType::DELIMITER(Delimiters::RCURBRACE) => {
+                    if nesting == 0 {
+                        return;
                     }
+                    nesting -= 1;
                 }
-                SyntaxMode::Indentation => {
-                    match &current_token.token_type {
-                        TokenType::INDENT => nesting += 1,
-                        TokenType::DEDENT => {
-                            nesting -= 1;
-                            if nesting == 0 {
-                                self.advance();
-                                return Ok(());
-                            }
-                        }
-                        _ => {}
+                TokenType::DEDENT if self.syntax_mode == SyntaxMode::Indentation => {
+                    if nesting == 0 {
+                        return;
+                    }
+                    nesting -= 1;
+                }
+                _ if nesting == 0 && self.at_separator() => {
+                    self.advance();
+                    // Une ligne qui ouvre un bloc indente fait partie de l'instruction fautive
+                    if !self.check(&[TokenType::INDENT]) {
+                        return;
                     }
+                    continue;
                 }
+                _ => {}
             }
             self.advance();
         }
-        Ok(())
+    }
+
+    /// Vrai sur un separateur d'instructions: `;`, ou fin de ligne en mode Indentation
+    fn at_separator(&self) -> bool {
+        match self.syntax_mode {
+            SyntaxMode::Braces => self.check(&[TokenType::DELIMITER(Delimiters::SEMICOLON)]),
+            SyntaxMode::Indentation => self.check(&[TokenType::NEWLINE, TokenType::DELIMITER(Delimiters::SEMICOLON)]),
+        }
+    }
+
+    /// Vrai sur la fermeture du bloc courant ou en fin de fichier
+    pub(crate) fn is_block_end(&self) -> bool {
+        self.is_at_end() || self.check(&[TokenType::DELIMITER(Delimiters::RCURBRACE), TokenType::DEDENT])
     }
 
 
@@ -129,7 +143,7 @@ impl Parser{
         match self.parse_impl_method() {
             Ok(method) => Ok(method),
             Err(e) => {
-                println!("Erreur lors du parsing de la méthode : {:?}", e);
+                trace!("Erreur lors du parsing de la méthode : {:?}", e);
                 self.synchronize()?;
 
                 // Retourne une méthode "placeholder" pour continuer le parsing
@@ -1,13 +1,17 @@
 //src/semantic/analyzer.rs
 
-use crate::parser::ast::{ASTNode, Statement, Declaration, Expression, VariableDeclaration,
-                         FunctionDeclaration, Mutability as ASTMutability};
+use crate::parser::ast::{ASTNode, Statement, Declaration, Expression, VariableDeclaration, ForStatement, WithStatement,
+                         Pattern, ConstructorArguments,
+                         FunctionDeclaration, StructDeclaration, EnumDeclaration, EnumVariantKind, Literal,
+                         UnaryOperator, Type as ASTType, GenericParameter, WhereClause,
+                         Mutability as ASTMutability, Span};
 use crate::semantic::borrow_checker::MutabilityManager;
-use crate::semantic::symbols::{SymbolKind, SourceLocation};
+use crate::semantic::symbols::{SymbolId, SymbolKind, SourceLocation};
 use crate::semantic::symbol_table::SymbolTable;
 use crate::semantic::type_checker::TypeChecker;
 use crate::semantic::types::type_system::Mutability;
-use crate::semantic::semantic_error::{SemanticError, SemanticErrorType, Position};
+use crate::semantic::types::type_system::{EnumVariantInfo, Type, TypeId, TypeKind, VariantPayload};
+use crate::semantic::semantic_error::{SemanticError, SemanticErrorType, TypeError, Position};
 
 /// Analyseur sémantique principal qui coordonne tous les composants
 pub struct SemanticAnalyzer {
@@ -15,6 +19,7 @@ pub struct SemanticAnalyzer {
     pub type_checker: TypeChecker,
     pub errors: Vec<SemanticError>,
     pub warnings: Vec<SemanticError>, // Pour les avertissements non-fatals
+    pub file_name: String, // Nom du fichier source utilise dans les SourceLocation
 }
 
 impl SemanticAnalyzer {
@@ -28,26 +33,38 @@ impl SemanticAnalyzer {
             type_checker,
             errors: Vec::new(),
             warnings: Vec::new(),
+            file_name: "<input>".to_string(),
         }
     }
 
+    /// Definit le nom du fichier source analyse
+    pub fn with_file_name(mut self, file_name: &str) -> Self {
+        self.file_name = file_name.to_string();
+        self
+    }
+
+    /// Construit une SourceLocation a partir du span d'un noeud
+    fn location(&self, span: Span) -> SourceLocation {
+        SourceLocation::from_span(&self.file_name, span)
+    }
+
     /// Analyse un AST complet
     pub fn analyze(&mut self, ast: &[ASTNode]) -> Result<(), Vec<SemanticError>> {
         // Réinitialiser les erreurs
-        println!("Reunitialisation des erreur ");
+        trace!("Reunitialisation des erreur ");
         self.errors.clear();
         self.warnings.clear();
 
         // 1. Première passe: déclarer tous les symboles de haut niveau
-        println!("Première passe: déclaration des symboles de haut niveau");
+        trace!("Première passe: déclaration des symboles de haut niveau");
         self.declare_top_level_symbols(ast);
 
         // 2. Deuxième passe: vérifier les types et la sémantique
-        println!("Deuxième passe: vérification des types et de la sémantique");
+        trace!("Deuxième passe: vérification des types et de la sémantique");
         self.check_semantics(ast);
 
         // 3. Troisième passe: validations finales
-        println!("Troisième passe: validations finales");
+        trace!("Troisième passe: validations finales");
         self.final_validations();
 
         // Retourner les erreurs s'il y en a
@@ -62,9 +79,158 @@ impl SemanticAnalyzer {
     fn declare_top_level_symbols(&mut self, ast: &[ASTNode]) {
         for node in ast {
             if let Err(error) = self.declare_node_symbols(node) {
-                self.errors.push(error);
+                self.errors.push(error.or_span(node.span()));
+            }
+        }
+
+        // Les champs sont resolus une fois toutes les structures declarees,
+        // un champ peut donc referencer une structure declaree plus loin
+        for node in ast {
+            match node {
+                ASTNode::Declaration(Declaration::Structure(struct_decl)) => {
+                    self.declare_struct_fields(struct_decl);
+                },
+                ASTNode::Declaration(Declaration::Enum(enum_decl)) => {
+                    if let Err(error) = self.declare_enum_variants(enum_decl) {
+                        self.errors.push(error.or_span(enum_decl.span));
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /// Enregistre les variantes d'une enumeration et calcule leurs discriminants
+    /// Sans valeur explicite, une variante vaut la precedente + 1 (0 pour la premiere)
+    fn declare_enum_variants(&mut self, enum_decl: &EnumDeclaration) -> Result<(), SemanticError> {
+        let generic_names = Self::generic_names(&enum_decl.generic_parameters);
+        let registry = &mut self.symbol_table.type_system_mut().type_registry;
+        let Some(&enum_type_id) = registry.enum_types.get(&enum_decl.name) else {
+            return Ok(());
+        };
+
+        let mut variants: Vec<EnumVariantInfo> = Vec::new();
+        let mut next_discriminant = 0i64;
+        for variant in &enum_decl.variantes {
+            let invalid_variant = |message: String, span: Span| SemanticError::new(
+                SemanticErrorType::TypeError(TypeError::InvalidType(message.clone())),
+                message,
+                Position { index: span.start }
+            ).with_span(span);
+
+            if variants.iter().any(|existing| existing.name == variant.name) {
+                return Err(invalid_variant(
+                    format!("Variant '{}' is declared more than once in enum '{}'", variant.name, enum_decl.name),
+                    variant.span
+                ));
             }
+
+            let discriminant = match &variant.discriminant {
+                Some(expression) => Self::discriminant_value(expression).ok_or_else(|| invalid_variant(
+                    format!("Discriminant of '{}::{}' must be an integer literal", enum_decl.name, variant.name),
+                    expression.span()
+                ))?,
+                None => next_discriminant,
+            };
+            if let Some(existing) = variants.iter().find(|existing| existing.discriminant == discriminant) {
+                return Err(invalid_variant(
+                    format!("Discriminant {} of '{}' is already used by '{}'", discriminant, variant.name, existing.name),
+                    variant.span
+                ));
+            }
+            next_discriminant = discriminant.wrapping_add(1);
+
+            let mut convert = |field_type: &ASTType| {
+                let type_id = registry.convert_ast_type_with_generics(field_type, &generic_names);
+                registry.get_type(type_id).cloned()
+            };
+            let payload = match &variant.kind {
+                EnumVariantKind::Unit => VariantPayload::Unit,
+                EnumVariantKind::Tuple(types) => VariantPayload::Tuple(
+                    types.iter().filter_map(&mut convert).collect()
+                ),
+                EnumVariantKind::Struct(fields) => VariantPayload::Struct(
+                    fields.iter()
+                        .filter_map(|field| convert(&field.field_type).map(|t| (field.name.clone(), t)))
+                        .collect()
+                ),
+            };
+
+            variants.push(EnumVariantInfo {
+                name: variant.name.clone(),
+                payload,
+                discriminant,
+            });
         }
+
+        registry.set_enum_variants(enum_type_id, variants);
+        Ok(())
+    }
+
+    /// Noms des parametres generiques d'une declaration
+    fn generic_names(generic_parameters: &Option<Vec<GenericParameter>>) -> Vec<String> {
+        generic_parameters.iter()
+            .flatten()
+            .map(|param| param.name.clone())
+            .collect()
+    }
+
+    /// Déclare les parametres generiques dans le scope courant avec SymbolKind::Generic
+    /// Une clause where ne peut contraindre qu'un parametre declare
+    fn declare_generic_parameters(
+        &mut self,
+        generic_parameters: &Option<Vec<GenericParameter>>,
+        where_clause: &[WhereClause],
+        span: Span
+    ) -> Result<(), SemanticError> {
+        for param in generic_parameters.iter().flatten() {
+            let generic_type_id = self.symbol_table.type_system_mut()
+                .type_registry.register_type(TypeKind::Generic(param.name.clone(), Vec::new()));
+            let location = self.location(span);
+            self.symbol_table.declare_symbol_with_type(
+                param.name.clone(),
+                SymbolKind::Generic,
+                generic_type_id,
+                location,
+                false
+            )?;
+        }
+
+        let generic_names = Self::generic_names(generic_parameters);
+        for clause in where_clause {
+            if !generic_names.contains(&clause.type_name) {
+                return Err(SemanticError::new(
+                    SemanticErrorType::TypeError(TypeError::InvalidTypeParameter(clause.type_name.clone())),
+                    format!("Where clause refers to undeclared type parameter '{}'", clause.type_name),
+                    Position { index: span.start }
+                ).with_span(span));
+            }
+        }
+        Ok(())
+    }
+
+    /// Valeur d'un discriminant explicite: un entier litteral, eventuellement negatif
+    fn discriminant_value(expression: &Expression) -> Option<i64> {
+        match expression {
+            Expression::Literal(Literal::Integer { value, .. }, _) => i64::try_from(value).ok(),
+            Expression::UnaryOperation(unary) if matches!(unary.operator, UnaryOperator::Negative | UnaryOperator::Negate) => {
+                Self::discriminant_value(&unary.operand).map(|value| -value)
+            },
+            _ => None,
+        }
+    }
+
+    /// Enregistre les types des champs d'une structure
+    fn declare_struct_fields(&mut self, struct_decl: &StructDeclaration) {
+        let generic_names = Self::generic_names(&struct_decl.generic_parameters);
+        let registry = &mut self.symbol_table.type_system_mut().type_registry;
+        let Some(&struct_type_id) = registry.struct_types.get(&struct_decl.name) else {
+            return;
+        };
+        let fields = struct_decl.fields.iter()
+            .map(|field| (field.name.clone(), registry.convert_ast_type_with_generics(&field.field_type, &generic_names)))
+            .collect();
+        registry.set_struct_fields(struct_type_id, fields);
     }
 
     /// Déclare les symboles pour un nœud AST
@@ -81,19 +247,10 @@ impl SemanticAnalyzer {
             ASTNode::Declaration(Declaration::Structure(struct_decl)) => {
                 // Créer un nouveau type pour la structure
                 let struct_type_id = self.symbol_table.type_system_mut()
-                    .type_registry.register_type(
-                    crate::semantic::types::type_system::TypeKind::Named(
-                        struct_decl.name.clone(),
-                        Vec::new()
-                    )
-                );
+                    .type_registry.create_struct_type(&struct_decl.name);
 
                 // Déclarer le symbole de la structure
-                let location = SourceLocation {
-                    file: "current_file.pk".to_string(), // À remplacer par le vrai nom de fichier
-                    line: 1, // À remplacer par la vraie position
-                    column: 1,
-                };
+                let location = self.location(struct_decl.span);
 
                 self.symbol_table.declare_symbol_with_type(
                     struct_decl.name.clone(),
@@ -109,18 +266,9 @@ impl SemanticAnalyzer {
             ASTNode::Declaration(Declaration::Enum(enum_decl)) => {
                 // Créer un nouveau type pour l'énumération
                 let enum_type_id = self.symbol_table.type_system_mut()
-                    .type_registry.register_type(
-                    crate::semantic::types::type_system::TypeKind::Named(
-                        enum_decl.name.clone(),
-                        Vec::new()
-                    )
-                );
-
-                let location = SourceLocation {
-                    file: "current_file.rs".to_string(),
-                    line: 1,
-                    column: 1,
-                };
+                    .type_registry.create_enum_type(&enum_decl.name);
+
+                let location = self.location(enum_decl.span);
 
                 self.symbol_table.declare_symbol_with_type(
                     enum_decl.name.clone(),
@@ -134,11 +282,7 @@ impl SemanticAnalyzer {
             },
 
             ASTNode::Declaration(Declaration::Trait(trait_decl)) => {
-                let location = SourceLocation {
-                    file: "current_file.rs".to_string(),
-                    line: 1,
-                    column: 1,
-                };
+                let location = self.location(trait_decl.span);
 
                 self.symbol_table.declare_symbol(
                     trait_decl.name.clone(),
@@ -150,11 +294,7 @@ impl SemanticAnalyzer {
             },
 
             ASTNode::Declaration(Declaration::Module(module_decl)) => {
-                let location = SourceLocation {
-                    file: "current_file.rs".to_string(),
-                    line: 1,
-                    column: 1,
-                };
+                let location = self.location(module_decl.span);
 
                 self.symbol_table.declare_symbol(
                     module_decl.name.clone(),
@@ -190,70 +330,175 @@ impl SemanticAnalyzer {
         }
     }
 
-    /// Déclare un symbole de variable
+    /// Déclare un symbole pour chaque nom lie par le pattern de la variable
     fn declare_variable_symbol(&mut self, var_decl: &VariableDeclaration) -> Result<(), SemanticError> {
-        let location = SourceLocation {
-            file: "current_file.rs".to_string(),
-            line: 1,
-            column: 1,
-        };
-
         let is_mutable = matches!(var_decl.mutability, ASTMutability::Mutable);
 
-        // Si un type est spécifié, le convertir
-        let type_id = if let Some(ast_type) = &var_decl.variable_type {
-            Some(self.symbol_table.type_system_mut().type_registry.convert_ast_type(ast_type))
-        } else {
-            None
+        // Si un type est spécifié, le convertir (`_` sera inféré depuis la valeur)
+        let type_id = match &var_decl.variable_type {
+            Some(ast_type) if *ast_type != ASTType::Infer => {
+                Some(self.symbol_table.type_system_mut().type_registry.convert_ast_type(ast_type))
+            }
+            _ => None,
         };
 
-        // Déclarer le symbole
-        let symbol_id = if let Some(type_id) = type_id {
-            self.symbol_table.declare_symbol_with_type(
-                var_decl.name.clone(),
-                SymbolKind::Variable,
-                type_id,
-                location,
-                is_mutable
-            )?
-        } else {
-            // Variable sans type explicite - sera inféré plus tard
-            let symbol_id = self.symbol_table.declare_symbol(
-                var_decl.name.clone(),
-                SymbolKind::Variable,
-                location
-            )?;
+        self.declare_pattern(&var_decl.pattern, type_id, is_mutable, var_decl.span, "let binding")?;
+        Ok(())
+    }
 
-            // Marquer comme mutable si nécessaire
-            if let Some(symbol) = self.symbol_table.get_symbol_mut(symbol_id) {
-                symbol.attributes.is_mutable = is_mutable;
+    /// Déclare les noms lies par un pattern de `let`, `for` ou de parametre.
+    /// Le pattern doit etre irrefutable; les noms sans type connu seront inférés plus tard
+    fn declare_pattern(
+        &mut self,
+        pattern: &Pattern,
+        type_id: Option<TypeId>,
+        is_mutable: bool,
+        span: Span,
+        context: &str
+    ) -> Result<Vec<SymbolId>, SemanticError> {
+        if let Some(refutable) = self.refutable_part(pattern) {
+            return Err(SemanticError::new(
+                SemanticErrorType::TypeError(TypeError::RefutablePattern(context.to_string())),
+                format!("Refutable pattern in {}: it may not match every value", context),
+                Position { index: refutable.span().start }
+            ).with_span(refutable.span()));
+        }
+
+        let mut bindings = Vec::new();
+        self.pattern_binding_types(pattern, type_id, &mut bindings);
+
+        let mut symbol_ids = Vec::new();
+        for (name, type_id) in bindings {
+            let location = self.location(span);
+            let symbol_id = match type_id {
+                Some(type_id) => self.symbol_table.declare_symbol_with_type(
+                    name,
+                    SymbolKind::Variable,
+                    type_id,
+                    location,
+                    is_mutable
+                )?,
+                None => {
+                    let symbol_id = self.symbol_table.declare_symbol(name, SymbolKind::Variable, location)?;
+                    if let Some(symbol) = self.symbol_table.get_symbol_mut(symbol_id) {
+                        symbol.attributes.is_mutable = is_mutable;
+                    }
+                    symbol_id
+                }
+            };
+            symbol_ids.push(symbol_id);
+        }
+        Ok(symbol_ids)
+    }
+
+    /// Premier sous-pattern qui peut ne pas correspondre: litteral, intervalle ou variante
+    /// d'enumeration. Seul le constructeur d'une structure (`Point { x, y }`) est irrefutable
+    fn refutable_part<'p>(&self, pattern: &'p Pattern) -> Option<&'p Pattern> {
+        match pattern {
+            Pattern::Identifier(..) | Pattern::Wildcard(_) | Pattern::Rest(..) => None,
+            Pattern::Tuple(patterns, _) | Pattern::Array(patterns, _) | Pattern::TupleRest(patterns, _) => {
+                patterns.iter().find_map(|pattern| self.refutable_part(pattern))
+            }
+            Pattern::ArrayRest(rest) => {
+                rest.before.iter().chain(&rest.after).find_map(|pattern| self.refutable_part(pattern))
+            }
+            Pattern::Constructor(constructor) => {
+                let is_struct = constructor.path.len() == 1 && self.symbol_table.lookup_symbol(&constructor.path[0])
+                    .and_then(|symbol_id| self.symbol_table.get_symbol(symbol_id))
+                    .is_ok_and(|symbol| symbol.kind == SymbolKind::Struct);
+                if !is_struct {
+                    return Some(pattern);
+                }
+                match &constructor.arguments {
+                    ConstructorArguments::Tuple(patterns) => patterns.iter().find_map(|pattern| self.refutable_part(pattern)),
+                    ConstructorArguments::Struct { fields, .. } => fields.iter().find_map(|(_, pattern)| self.refutable_part(pattern)),
+                    ConstructorArguments::Unit => None,
+                }
             }
+            Pattern::Literal(..) | Pattern::EnumVariant(_) | Pattern::RangePattern(_) => Some(pattern),
+        }
+    }
 
-            symbol_id
-        };
+    /// Type de chaque nom lie par `pattern` quand la valeur est de type `type_id`.
+    /// Un element de tuple ou de tableau prend le type de l'element, `..rest` celui du tableau
+    fn pattern_binding_types(&self, pattern: &Pattern, type_id: Option<TypeId>, bindings: &mut Vec<(String, Option<TypeId>)>) {
+        let kind = type_id
+            .and_then(|type_id| self.symbol_table.type_system().type_registry.get_type(type_id))
+            .map(|type_obj| &type_obj.kind);
+        match pattern {
+            Pattern::Identifier(name, _) | Pattern::Rest(Some(name), _) => bindings.push((name.clone(), type_id)),
+            Pattern::Tuple(patterns, _) | Pattern::TupleRest(patterns, _) => {
+                let elements = match kind {
+                    Some(TypeKind::Tuple(elements)) if elements.len() == patterns.len() => Some(elements),
+                    _ => None,
+                };
+                for (index, pattern) in patterns.iter().enumerate() {
+                    self.pattern_binding_types(pattern, elements.map(|elements| elements[index].id), bindings);
+                }
+            }
+            Pattern::Array(patterns, _) => self.array_binding_types(patterns.iter(), type_id, bindings),
+            Pattern::ArrayRest(rest) => {
+                self.array_binding_types(rest.before.iter().chain(&rest.after), type_id, bindings)
+            }
+            Pattern::Constructor(constructor) => match &constructor.arguments {
+                ConstructorArguments::Struct { fields, .. } => {
+                    // Chaque champ prend le type declare dans la structure
+                    let struct_fields = match kind {
+                        Some(TypeKind::Struct(struct_type)) => {
+                            self.symbol_table.type_system().type_registry.get_struct_fields(struct_type.id)
+                        }
+                        _ => None,
+                    };
+                    for (field, pattern) in fields {
+                        let field_type = struct_fields
+                            .and_then(|struct_fields| struct_fields.iter().find(|(name, _)| name == field))
+                            .map(|(_, field_type)| field_type.id);
+                        self.pattern_binding_types(pattern, field_type, bindings);
+                    }
+                }
+                ConstructorArguments::Tuple(patterns) => {
+                    patterns.iter().for_each(|pattern| self.pattern_binding_types(pattern, None, bindings));
+                }
+                ConstructorArguments::Unit => {}
+            },
+            Pattern::Literal(..) | Pattern::Wildcard(_) | Pattern::EnumVariant(_)
+            | Pattern::Rest(None, _) | Pattern::RangePattern(_) => {}
+        }
+    }
 
-        Ok(())
+    /// Elements d'un pattern de tableau: `..rest` garde le type du tableau
+    fn array_binding_types<'p>(
+        &self,
+        patterns: impl Iterator<Item = &'p Pattern>,
+        type_id: Option<TypeId>,
+        bindings: &mut Vec<(String, Option<TypeId>)>
+    ) {
+        let element = match type_id.and_then(|type_id| self.symbol_table.type_system().type_registry.get_type(type_id)) {
+            Some(Type { kind: TypeKind::Array(element, _), .. }) => Some(element.id),
+            _ => None,
+        };
+        for pattern in patterns {
+            let pattern_type = if matches!(pattern, Pattern::Rest(..)) { type_id } else { element };
+            self.pattern_binding_types(pattern, pattern_type, bindings);
+        }
     }
 
     /// Déclare un symbole de fonction
     fn declare_function_symbol(&mut self, func_decl: &FunctionDeclaration) -> Result<(), SemanticError> {
-        let location = SourceLocation {
-            file: "current_file.rs".to_string(),
-            line: 1,
-            column: 1,
-        };
+        let location = self.location(func_decl.span);
 
-        // Créer le type de la fonction
+        // Créer le type de la fonction, `T` designe un parametre generique de la fonction
+        let generic_names = Self::generic_names(&func_decl.generic_parameters);
         let mut param_type_ids = Vec::new();
         for param in &func_decl.parameters {
             let param_type_id = self.symbol_table.type_system_mut()
-                .type_registry.convert_ast_type(&param.parameter_type);
+                .type_registry.convert_ast_type_with_generics(&param.parameter_type, &generic_names);
             param_type_ids.push(param_type_id);
         }
 
         let return_type_id = match &func_decl.return_type {
             Some(ast_type) => self.symbol_table.type_system_mut()
-                .type_registry.convert_ast_type(ast_type),
+                .type_registry.convert_ast_type_with_generics(ast_type, &generic_names),
             None => self.symbol_table.type_system().type_registry.type_unit,
         };
 
@@ -276,7 +521,7 @@ impl SemanticAnalyzer {
     fn check_semantics(&mut self, ast: &[ASTNode]) {
         for node in ast {
             if let Err(error) = self.check_node_semantics(node) {
-                self.errors.push(error);
+                self.errors.push(error.or_span(node.span()));
             }
         }
     }
@@ -288,6 +533,14 @@ impl SemanticAnalyzer {
                 self.check_declaration_semantics(declaration)
             },
 
+            ASTNode::Statement(Statement::ForStatement(for_stmt)) => {
+                self.check_for_statement_semantics(for_stmt)
+            },
+
+            ASTNode::Statement(Statement::WithStatement(with_stmt)) => {
+                self.check_with_statement_semantics(with_stmt)
+            },
+
             ASTNode::Statement(statement) => {
                 // Synchroniser le type checker avec la table des symboles
                 self.sync_type_checker();
@@ -328,47 +581,44 @@ impl SemanticAnalyzer {
             },
 
             Declaration::Structure(struct_decl) => {
-                // Vérifier les champs de la structure
-                for field in &struct_decl.fields {
-                    // Vérifier que le type du champ existe
-                    let field_type_id = self.symbol_table.type_system_mut()
-                        .type_registry.convert_ast_type(&field.field_type);
-
-                    if self.symbol_table.type_system().type_registry.get_type(field_type_id).is_none() {
-                        return Err(SemanticError::new(
-                            SemanticErrorType::TypeError(
-                                crate::semantic::semantic_error::TypeError::TypeNotFound(
-                                    format!("Type for field {}", field.name)
-                                )
-                            ),
-                            "Invalid field type".to_string(),
-                            Position { index: 0 }
-                        ));
+                // Les parametres generiques sont visibles dans les champs
+                self.in_scope(crate::semantic::symbols::ScopeKind::Struct, |this| {
+                    this.declare_generic_parameters(&struct_decl.generic_parameters, &[], struct_decl.span)?;
+                    let generic_names = Self::generic_names(&struct_decl.generic_parameters);
+
+                    // Vérifier les champs de la structure
+                    for field in &struct_decl.fields {
+                        // Vérifier que le type du champ existe
+                        let field_type_id = this.symbol_table.type_system_mut()
+                            .type_registry.convert_ast_type_with_generics(&field.field_type, &generic_names);
+
+                        if this.symbol_table.type_system().type_registry.get_type(field_type_id).is_none() {
+                            return Err(SemanticError::new(
+                                SemanticErrorType::TypeError(
+                                    crate::semantic::semantic_error::TypeError::TypeNotFound(
+                                        format!("Type for field {}", field.name)
+                                    )
+                                ),
+                                "Invalid field type".to_string(),
+                                Position { index: struct_decl.span.start }
+                            ).with_span(struct_decl.span));
+                        }
                     }
-                }
-                Ok(())
+                    Ok(())
+                })
             },
 
             Declaration::Enum(enum_decl) => {
-                // Vérifier les variantes de l'énumération
-                for variant in &enum_decl.variantes {
-                    // Vérifier que le type de la variante existe
-                    let variant_type_id = self.symbol_table.type_system_mut()
-                        .type_registry.convert_ast_type(&variant.variante_type);
-
-                    if self.symbol_table.type_system().type_registry.get_type(variant_type_id).is_none() {
-                        return Err(SemanticError::new(
-                            SemanticErrorType::TypeError(
-                                crate::semantic::semantic_error::TypeError::TypeNotFound(
-                                    format!("Type for variant {}", variant.name)
-                                )
-                            ),
-                            "Invalid variant type".to_string(),
-                            Position { index: 0 }
-                        ));
-                    }
-                }
-                Ok(())
+                // Les variantes sont enregistrees et verifiees lors de la premiere passe
+                self.in_scope(crate::semantic::symbols::ScopeKind::Struct, |this| {
+                    this.declare_generic_parameters(&enum_decl.generic_parameters, &[], enum_decl.span)
+                })
+            },
+
+            Declaration::Class(class_decl) => {
+                self.in_scope(crate::semantic::symbols::ScopeKind::Struct, |this| {
+                    this.declare_generic_parameters(&class_decl.generic_parameters, &[], class_decl.span)
+                })
             },
 
             Declaration::Trait(_) => {
@@ -391,10 +641,19 @@ impl SemanticAnalyzer {
         // Vérifier la déclaration
         let inferred_type_id = self.type_checker.check_variable_declaration(var_decl)?;
 
-        // Mettre à jour le type du symbole si nécessaire
-        if let Ok(symbol_id) = self.symbol_table.lookup_symbol(&var_decl.name) {
-            if self.symbol_table.get_symbol_type_id(symbol_id)?.is_none() {
-                self.symbol_table.set_symbol_type(symbol_id, inferred_type_id)?;
+        // Les types crees par le type checker (tuple, tableau inferes) n'existent que dans
+        // sa copie du systeme de types: les reprendre pour pouvoir typer les noms lies
+        *self.symbol_table.type_system_mut() = self.type_checker.type_system.clone();
+
+        // Mettre à jour le type de chaque nom lie si nécessaire
+        let mut bindings = Vec::new();
+        self.pattern_binding_types(&var_decl.pattern, Some(inferred_type_id), &mut bindings);
+        for (name, type_id) in bindings {
+            let Ok(symbol_id) = self.symbol_table.lookup_symbol(&name) else {
+                continue;
+            };
+            if let (None, Some(type_id)) = (self.symbol_table.get_symbol_type_id(symbol_id)?, type_id) {
+                self.symbol_table.set_symbol_type(symbol_id, type_id)?;
             }
 
             // Si la variable a un initializer, la marquer comme initialisée
@@ -408,44 +667,67 @@ impl SemanticAnalyzer {
 
     /// Vérifie la sémantique d'une déclaration de fonction
     fn check_function_declaration_semantics(&mut self, func_decl: &FunctionDeclaration) -> Result<(), SemanticError> {
-        // Entrer dans un nouveau scope pour la fonction
-        let function_scope_id = self.symbol_table.enter_scope(crate::semantic::symbols::ScopeKind::Function);
-
-        // Déclarer les paramètres dans le scope de la fonction
-        for param in &func_decl.parameters {
-            let param_type_id = self.symbol_table.type_system_mut()
-                .type_registry.convert_ast_type(&param.parameter_type);
-
-            let location = SourceLocation {
-                file: "current_file.rs".to_string(),
-                line: 1,
-                column: 1,
-            };
+        // Nouveau scope pour la fonction, refermé même en cas d'erreur
+        self.in_scope(crate::semantic::symbols::ScopeKind::Function, |this| {
+            // Déclarer les paramètres generiques puis les paramètres dans le scope de la fonction
+            this.declare_generic_parameters(&func_decl.generic_parameters, &func_decl.where_clause, func_decl.span)?;
+            let generic_names = Self::generic_names(&func_decl.generic_parameters);
+            for param in &func_decl.parameters {
+                let param_type_id = this.symbol_table.type_system_mut()
+                    .type_registry.convert_ast_type_with_generics(&param.parameter_type, &generic_names);
+
+                // Les paramètres sont immutables par défaut
+                let param_symbol_ids = this.declare_pattern(&param.pattern, Some(param_type_id), false, param.span, "function parameter")?;
+
+                // Marquer le paramètre comme initialisé
+                for symbol_id in param_symbol_ids {
+                    this.symbol_table.mark_initialized(symbol_id)?;
+                }
+            }
 
-            let param_symbol_id = self.symbol_table.declare_symbol_with_type(
-                param.name.clone(),
-                SymbolKind::Variable,
-                param_type_id,
-                location,
-                false // Les paramètres sont immutables par défaut
-            )?;
+            // Analyser le corps de la fonction
+            this.check_body_semantics(&func_decl.body)
+        })
+    }
 
-            // Marquer le paramètre comme initialisé
-            self.symbol_table.mark_initialized(param_symbol_id)?;
-        }
+    /// Exécute `check` dans un nouveau scope et le referme sur tous les chemins,
+    /// pour qu'une erreur ne laisse pas les déclarations suivantes dans ce scope
+    fn in_scope<T>(
+        &mut self,
+        kind: crate::semantic::symbols::ScopeKind,
+        check: impl FnOnce(&mut Self) -> Result<T, SemanticError>,
+    ) -> Result<T, SemanticError> {
+        self.symbol_table.enter_scope(kind);
+        let result = check(self);
+        let exited = self.symbol_table.exit_scope();
+        let value = result?;
+        exited?;
+        Ok(value)
+    }
 
-        // Synchroniser le type checker
+    /// Vérifie le corps d'une fonction ou d'une boucle dans le scope courant.
+    /// Les `let` y declarent leurs noms avant d'etre verifies
+    fn check_body_semantics(&mut self, body: &[ASTNode]) -> Result<(), SemanticError> {
         self.sync_type_checker();
-
-        // Analyser le corps de la fonction
-        for stmt_node in &func_decl.body {
+        for stmt_node in body {
             match stmt_node {
+                ASTNode::Statement(Statement::ForStatement(for_stmt)) => {
+                    self.check_for_statement_semantics(for_stmt)?;
+                },
+                ASTNode::Statement(Statement::WithStatement(with_stmt)) => {
+                    self.check_with_statement_semantics(with_stmt)?;
+                },
                 ASTNode::Statement(statement) => {
                     self.type_checker.check_statement(statement)?;
                 },
                 ASTNode::Expression(expression) => {
                     self.type_checker.check_expression(expression)?;
                 },
+                ASTNode::Declaration(Declaration::Variable(var_decl)) => {
+                    self.declare_variable_symbol(var_decl)?;
+                    self.check_variable_declaration_semantics(var_decl)?;
+                    self.sync_type_checker();
+                },
                 ASTNode::Declaration(declaration) => {
                     self.check_declaration_semantics(declaration)?;
                 },
@@ -454,10 +736,52 @@ impl SemanticAnalyzer {
                 }
             }
         }
+        Ok(())
+    }
 
-        // Sortir du scope de la fonction
-        self.symbol_table.exit_scope()?;
+    /// Vérifie une boucle `for`: le pattern lie ses noms dans le scope de la boucle,
+    /// avec le type des elements quand l'iterable est un tableau
+    fn check_for_statement_semantics(&mut self, for_stmt: &ForStatement) -> Result<(), SemanticError> {
+        self.sync_type_checker();
+        let iterable_type_id = self.type_checker.check_expression(&for_stmt.iterable)?;
+        let element_type_id = match self.symbol_table.type_system().type_registry.get_type(iterable_type_id) {
+            Some(Type { kind: TypeKind::Array(element, _), .. }) => Some(element.id),
+            _ => None,
+        };
 
+        self.in_scope(crate::semantic::symbols::ScopeKind::Loop, |this| {
+            let symbol_ids = this.declare_pattern(&for_stmt.pattern, element_type_id, false, for_stmt.span, "for loop")?;
+            for symbol_id in symbol_ids {
+                this.symbol_table.mark_initialized(symbol_id)?;
+            }
+            this.check_body_semantics(&for_stmt.body)
+        })?;
+        self.sync_type_checker();
+        Ok(())
+    }
+
+    /// Vérifie un `with expr as alias`: l'alias prend le type de l'expression
+    /// et n'est visible que dans le corps
+    fn check_with_statement_semantics(&mut self, with_stmt: &WithStatement) -> Result<(), SemanticError> {
+        self.sync_type_checker();
+        let target_type_id = self.type_checker.check_expression(&with_stmt.target)?;
+        *self.symbol_table.type_system_mut() = self.type_checker.type_system.clone();
+
+        self.in_scope(crate::semantic::symbols::ScopeKind::Block, |this| {
+            if let Some(alias) = &with_stmt.alias {
+                let location = this.location(with_stmt.span);
+                let symbol_id = this.symbol_table.declare_symbol_with_type(
+                    alias.clone(),
+                    SymbolKind::Variable,
+                    target_type_id,
+                    location,
+                    false
+                )?;
+                this.symbol_table.mark_initialized(symbol_id)?;
+            }
+            this.check_body_semantics(&with_stmt.body)
+        })?;
+        self.sync_type_checker();
         Ok(())
     }
 
@@ -488,8 +812,8 @@ impl SemanticAnalyzer {
                         )
                     ),
                     format!("Symbol '{}' is declared but never used", symbol.name),
-                    Position { index: 0 }
-                );
+                    symbol.location.position()
+                ).with_span(symbol.location.span);
                 self.warnings.push(warning);
             }
         }
@@ -577,8 +901,8 @@ impl crate::semantic::borrow_checker::BorrowChecker {
                         )
                     ),
                     "Cannot have both mutable and immutable borrows".to_string(),
-                    Position { index: 0 }
-                ));
+                    mutable_borrows[0].location.position()
+                ).with_span(mutable_borrows[0].location.span));
             }
 
             // Vérifier qu'il n'y a pas plus d'un emprunt mutable
@@ -590,8 +914,8 @@ impl crate::semantic::borrow_checker::BorrowChecker {
                         )
                     ),
                     "Cannot have multiple mutable borrows".to_string(),
-                    Position { index: 0 }
-                ));
+                    mutable_borrows[1].location.position()
+                ).with_span(mutable_borrows[1].location.span));
             }
         }
 
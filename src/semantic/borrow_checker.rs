@@ -393,6 +393,7 @@ mod tests {
             file: "test.punk".to_string(),
             line,
             column: 1,
+            span: crate::parser::ast::Span::dummy(),
         }
     }
 
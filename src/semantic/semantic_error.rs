@@ -3,6 +3,7 @@
 
 use std::fmt;
 use std::fmt::Display;
+use crate::parser::ast::Span as AstSpan;
 
 
 
@@ -32,6 +33,7 @@ pub struct  SemanticError {
     pub error : SemanticErrorType,
     pub message: String,
     pub position : Position,
+    pub span: AstSpan,
     // Type errors
 }
 
@@ -51,6 +53,8 @@ pub enum TypeError {
     UndefinedType(String),
     TypeNotFound(String),
     InvalidTypeParameter(String),
+    IntegerOverflow(String),
+    RefutablePattern(String),
 }
 
 
@@ -120,12 +124,38 @@ impl Display for SemanticErrorType{
             SemanticErrorType::TypeError(TypeError::InvalidTypeParameter(name)) => {
                 write!(f, "Type Error: Invalid type parameter for symbol '{}'", name)
             }
+            SemanticErrorType::TypeError(TypeError::IntegerOverflow(message)) => {
+                write!(f, "Type Error: Integer overflow, {}", message)
+            }
+            SemanticErrorType::TypeError(TypeError::RefutablePattern(context)) => {
+                write!(f, "Type Error: Refutable pattern in {}", context)
+            }
 
         }
     }
 }
 
 
+/// Code stable de l'erreur, les erreurs semantiques sont dans la plage E03xx
+impl SemanticErrorType {
+    pub fn code(&self) -> &'static str {
+        match self {
+            SemanticErrorType::SymbolError(SymbolError::SymbolNotFound(_)) => "E0300",
+            SemanticErrorType::SymbolError(SymbolError::SymbolAlreadyDeclared(_)) => "E0301",
+            SemanticErrorType::SymbolError(SymbolError::InvalidVisibility(_)) => "E0302",
+            SemanticErrorType::SymbolError(SymbolError::InvalidScope) => "E0303",
+            SemanticErrorType::SymbolError(SymbolError::ImportError(_)) => "E0304",
+            SemanticErrorType::TypeError(TypeError::TypeMismatch(_)) => "E0310",
+            SemanticErrorType::TypeError(TypeError::InvalidType(_)) => "E0311",
+            SemanticErrorType::TypeError(TypeError::UndefinedType(_)) => "E0312",
+            SemanticErrorType::TypeError(TypeError::TypeNotFound(_)) => "E0313",
+            SemanticErrorType::TypeError(TypeError::InvalidTypeParameter(_)) => "E0314",
+            SemanticErrorType::TypeError(TypeError::IntegerOverflow(_)) => "E0315",
+            SemanticErrorType::TypeError(TypeError::RefutablePattern(_)) => "E0316",
+        }
+    }
+}
+
 /// implementatique du message l'erreur semantique
 
 impl SemanticError{
@@ -163,6 +193,12 @@ impl SemanticError{
             SemanticErrorType::TypeError(TypeError::InvalidTypeParameter(name)) => {
                 format!("Invalid type parameter for symbol '{}'", name)
             }
+            SemanticErrorType::TypeError(TypeError::IntegerOverflow(message)) => {
+                format!("Integer overflow, {}", message)
+            }
+            SemanticErrorType::TypeError(TypeError::RefutablePattern(context)) => {
+                format!("Refutable pattern in {}", context)
+            }
 
         };
 
@@ -171,6 +207,23 @@ impl SemanticError{
             error,
             message,
             position,
+            span: AstSpan::dummy(),
+        }
+    }
+
+    /// Attache le span du noeud fautif (la position devient l'offset du debut du span)
+    pub fn with_span(mut self, span: AstSpan) -> Self {
+        self.position = Position { index: span.start };
+        self.span = span;
+        self
+    }
+
+    /// Attache le span seulement si l'erreur n'en a pas encore
+    pub fn or_span(self, span: AstSpan) -> Self {
+        if self.span == AstSpan::dummy() {
+            self.with_span(span)
+        } else {
+            self
         }
     }
 }
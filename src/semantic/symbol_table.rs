@@ -140,12 +140,13 @@ impl SymbolTable {
           kind: SymbolKind,
           location: SourceLocation
      ) -> Result<SymbolId, SemanticError> {
+          let position = location.position();
           // Vérifier si le symbole existe déjà dans le scope actuel
           if let Ok(scope) = self.get_current_scope() {
                if scope.lookup_symbol(&name).is_some() {
                     return Err(create_symbol_error(
                          SymbolError::SymbolAlreadyDeclared(name),
-                         Position { index: 0 }
+                         position.clone()
                     ));
                }
           }
@@ -164,7 +165,7 @@ impl SymbolTable {
           // Ajouter le symbole au scope actuel
           if let Some(scope) = self.scopes.get_mut(&self.current_scope) {
                scope.add_symbol(name, symbol_id)
-                   .map_err(|err| create_symbol_error(err, Position { index: 0 }))?;
+                   .map_err(|err| create_symbol_error(err, position.clone()))?;
           }
 
           Ok(symbol_id)
@@ -386,6 +387,9 @@ impl SymbolTable {
                     // Int vers Float
                     (crate::semantic::types::type_system::TypeKind::Int, crate::semantic::types::type_system::TypeKind::Float) => Some(type2_id),
                     (crate::semantic::types::type_system::TypeKind::Float, crate::semantic::types::type_system::TypeKind::Int) => Some(type1_id),
+                    // Int/Float vers Complex
+                    (crate::semantic::types::type_system::TypeKind::Int | crate::semantic::types::type_system::TypeKind::Float, crate::semantic::types::type_system::TypeKind::Complex) => Some(type2_id),
+                    (crate::semantic::types::type_system::TypeKind::Complex, crate::semantic::types::type_system::TypeKind::Int | crate::semantic::types::type_system::TypeKind::Float) => Some(type1_id),
 
                     // Pour d'autres cas, pas de type commun pour l'instant
                     _ => None,
@@ -501,6 +505,7 @@ impl MutabilityManager for SymbolTable {
      }
 
      fn register_read(&mut self, symbol_id: SymbolId, location: SourceLocation) -> Result<(), SemanticError> {
+          let position = location.position();
           // Vérifier si la variable existe
           self.get_symbol(symbol_id)?;
 
@@ -514,7 +519,7 @@ impl MutabilityManager for SymbolTable {
                          format!("Variable {:?} is not initialized", symbol_id)
                     )),
                     "Use of uninitialized variable".to_string(),
-                    Position { index: 0 }
+                    position.clone()
                ));
           }
 
@@ -534,13 +539,14 @@ impl MutabilityManager for SymbolTable {
                               format!("Borrow checker error: {:?}", e)
                          )),
                          "Borrow checker error".to_string(),
-                         Position { index: 0 }
+                         position.clone()
                     ))
                }
           }
      }
 
      fn register_write(&mut self, symbol_id: SymbolId, location: SourceLocation) -> Result<(), SemanticError> {
+          let position = location.position();
           // Vérifier si la variable est mutable (clone les infos nécessaires)
           let is_mutable;
           let symbol_name;
@@ -560,7 +566,7 @@ impl MutabilityManager for SymbolTable {
                          format!("Cannot modify immutable variable {}", symbol_name)
                     )),
                     "Attempt to modify immutable value".to_string(),
-                    Position { index: 0 }
+                    position.clone()
                ));
           }
 
@@ -586,13 +592,14 @@ impl MutabilityManager for SymbolTable {
                               format!("Borrow checker error: {:?}", e)
                          )),
                          "Borrow checker error".to_string(),
-                         Position { index: 0 }
+                         position.clone()
                     ))
                }
           }
      }
 
      fn register_immutable_borrow(&mut self, symbol_id: SymbolId, location: SourceLocation) -> Result<(), SemanticError> {
+          let position = location.position();
           // Vérifier si la variable existe
           self.get_symbol(symbol_id)?;
 
@@ -606,7 +613,7 @@ impl MutabilityManager for SymbolTable {
                          format!("Cannot borrow uninitialized variable {:?}", symbol_id)
                     )),
                     "Borrow of uninitialized variable".to_string(),
-                    Position { index: 0 }
+                    position.clone()
                ));
           }
 
@@ -626,13 +633,14 @@ impl MutabilityManager for SymbolTable {
                               format!("Borrow checker error: {:?}", e)
                          )),
                          "Borrow checker error".to_string(),
-                         Position { index: 0 }
+                         position.clone()
                     ))
                }
           }
      }
 
      fn register_mutable_borrow(&mut self, symbol_id: SymbolId, location: SourceLocation) -> Result<(), SemanticError> {
+          let position = location.position();
           // Vérifier si la variable est mutable (clone les infos nécessaires)
           let is_mutable;
           let is_initialized;
@@ -654,7 +662,7 @@ impl MutabilityManager for SymbolTable {
                          format!("Cannot mutably borrow immutable variable {}", symbol_name)
                     )),
                     "Mutable borrow of immutable value".to_string(),
-                    Position { index: 0 }
+                    position.clone()
                ));
           }
 
@@ -665,7 +673,7 @@ impl MutabilityManager for SymbolTable {
                          format!("Cannot borrow uninitialized variable {}", symbol_name)
                     )),
                     "Borrow of uninitialized variable".to_string(),
-                    Position { index: 0 }
+                    position.clone()
                ));
           }
 
@@ -685,13 +693,14 @@ impl MutabilityManager for SymbolTable {
                               format!("Borrow checker error: {:?}", e)
                          )),
                          "Borrow checker error".to_string(),
-                         Position { index: 0 }
+                         position.clone()
                     ))
                }
           }
      }
 
      fn register_move(&mut self, symbol_id: SymbolId, location: SourceLocation) -> Result<(), SemanticError> {
+          let position = location.position();
           // Vérifier si la variable existe
           self.get_symbol(symbol_id)?;
 
@@ -705,7 +714,7 @@ impl MutabilityManager for SymbolTable {
                          format!("Cannot move uninitialized variable {:?}", symbol_id)
                     )),
                     "Move of uninitialized variable".to_string(),
-                    Position { index: 0 }
+                    position.clone()
                ));
           }
 
@@ -725,7 +734,7 @@ impl MutabilityManager for SymbolTable {
                               format!("Borrow checker error: {:?}", e)
                          )),
                          "Borrow checker error".to_string(),
-                         Position { index: 0 }
+                         position.clone()
                     ))
                }
           }
@@ -1364,6 +1373,7 @@ mod tests {
                file: "test.punk".to_string(),
                line: 1,
                column: 1,
+               span: crate::parser::ast::Span::dummy(),
           }
      }
 
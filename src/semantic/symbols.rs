@@ -6,6 +6,7 @@ use std::collections::HashMap;
 
 use crate::semantic::semantic_error::{Position, SymbolError};
 use crate::semantic::types::type_system::Type;
+use crate::parser::ast::Span;
 
 /// Type pour les identifiants uniques des symboles
 #[allow(dead_code)]
@@ -24,6 +25,24 @@ pub struct SourceLocation{
     pub file: String,
     pub line: usize,
     pub column: usize,
+    pub span: Span,
+}
+
+impl SourceLocation {
+    /// Construit la position a partir du span d'un noeud de l'AST
+    pub fn from_span(file: &str, span: Span) -> Self {
+        SourceLocation {
+            file: file.to_string(),
+            line: span.line,
+            column: span.column,
+            span,
+        }
+    }
+
+    /// Position (offset en octets) utilisee dans les erreurs semantiques
+    pub fn position(&self) -> Position {
+        Position { index: self.span.start }
+    }
 }
 
 /// Type de symbole supporte
@@ -223,6 +242,7 @@ mod tests {
             file: "test.punk".to_string(),
             line: 1,
             column: 1,
+            span: Span::dummy(),
         };
 
         let symbol = Symbol::new(
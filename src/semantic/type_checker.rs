@@ -2,10 +2,12 @@
 
 use std::cell::RefCell;
 use std::rc::Rc;
-use crate::parser::ast::{Expression, Statement, Operator, UnaryOperator, Literal,
-                         VariableDeclaration, FunctionDeclaration, ASTNode, Declaration};
+use crate::lexer::fstring::{format_type_class, FormatTypeClass};
+use crate::tok::NumberSuffix;
+use crate::parser::ast::{ComparisonChain, Expression, FormatString, FormatStringPart, Statement, Operator, UnaryOperator, Literal, StructLiteral,
+                         VariableDeclaration, FunctionDeclaration, ASTNode, Declaration, Type as ASTType};
 
-use crate::semantic::types::type_system::{TypeId, TypeKind, TypeSystem, Mutability};
+use crate::semantic::types::type_system::{EnumVariantInfo, TypeId, TypeKind, TypeSystem, Mutability, VariantPayload};
 use crate::semantic::semantic_error::{SemanticError, TypeError, SemanticErrorType, Position};
 use crate::semantic::symbol_table::SymbolTable;
 use num_bigint::BigInt;
@@ -18,6 +20,20 @@ pub struct TypeChecker {
 
 }
 
+/// Valeur d'un litteral entier, eventuellement precede d'un `-`, avec son suffixe
+fn integer_literal_value(expression: &Expression) -> Option<(BigInt, Option<NumberSuffix>)> {
+    match expression {
+        Expression::Literal(Literal::Integer { value, suffix }, _) => Some((value.clone(), *suffix)),
+        Expression::UnaryOperation(unary) if matches!(unary.operator, UnaryOperator::Negative | UnaryOperator::Negate) => {
+            match unary.operand.as_ref() {
+                Expression::Literal(Literal::Integer { value, suffix }, _) => Some((-value, *suffix)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 impl TypeChecker {
     pub fn new(symbol_table: SymbolTable) -> Self {
         TypeChecker {
@@ -27,15 +43,27 @@ impl TypeChecker {
     }
 
     /// Vérifie et infère le type d'une expression
+    /// les erreurs sans localisation recoivent le span de l'expression la plus interne
     pub fn check_expression(&mut self, expr: &Expression) -> Result<TypeId, SemanticError> {
+        self.check_expression_kind(expr)
+            .map_err(|error| error.or_span(expr.span()))
+    }
+
+    fn check_expression_kind(&mut self, expr: &Expression) -> Result<TypeId, SemanticError> {
         match expr {
-            Expression::Literal(literal) => {
+            Expression::Literal(literal, _) => {
                 self.check_literal(literal)
             },
 
-            Expression::Identifier(name) => {
+            Expression::Identifier(name, span) if name.contains("::") => {
+                self.check_enum_variant_path(name)
+                    .map_err(|error| error.or_span(*span))
+            },
+
+            Expression::Identifier(name, span) => {
                 // Rechercher l'identifiant dans la table des symboles
-                let symbol_id = self.symbol_table.lookup_symbol(name)?;
+                let symbol_id = self.symbol_table.lookup_symbol(name)
+                    .map_err(|error| error.or_span(*span))?;
 
                 // Récupérer le type associé au symbole
                 if let Some(type_obj) = self.symbol_table.get_symbol_type(symbol_id)? {
@@ -44,8 +72,8 @@ impl TypeChecker {
                     Err(create_semantic_error(
                         SemanticErrorType::TypeError(TypeError::UndefinedType(name.clone())),
                         "Variable used before its type is defined".to_string(),
-                        Position { index: 0 }
-                    ))
+                        Position { index: span.start }
+                    ).with_span(*span))
                 }
             },
 
@@ -53,6 +81,19 @@ impl TypeChecker {
                 self.check_binary_expression(&bin_op.left, &bin_op.operator, &bin_op.right)
             },
 
+            Expression::ComparisonChain(chain) => {
+                self.check_comparison_chain(chain)
+            },
+
+            // `-128i8`: la borne est verifiee sur la valeur negative
+            Expression::UnaryOperation(_) if integer_literal_value(expr).is_some() => {
+                let (value, suffix) = integer_literal_value(expr).unwrap();
+                if let Some(width) = suffix {
+                    self.check_integer_range(&value, width)?;
+                }
+                Ok(self.type_system.type_registry.type_int)
+            },
+
             Expression::UnaryOperation(un_op) => {
                 self.check_unary_expression(&un_op.operator, &un_op.operand)
             },
@@ -85,6 +126,18 @@ impl TypeChecker {
                 self.check_type_cast(&cast.expression, &cast.target_type)
             },
 
+            Expression::Tuple(tuple) => {
+                self.check_tuple_expression(&tuple.elements)
+            },
+
+            Expression::StructLiteral(literal) => {
+                self.check_struct_literal(literal)
+            },
+
+            Expression::FormatString(format) => {
+                self.check_format_string(format)
+            },
+
             // Plus de cas selon votre AST...
             _ => {
                 // Cas par défaut pour les expressions non gérées
@@ -100,10 +153,20 @@ impl TypeChecker {
     /// Vérifie le type d'un littéral
     fn check_literal(&mut self, literal: &Literal) -> Result<TypeId, SemanticError> {
         match literal {
-            Literal::Integer { .. } => Ok(self.type_system.type_registry.type_int),
+            Literal::Integer { value, suffix } => {
+                if let Some(width) = suffix {
+                    self.check_integer_range(value, *width)?;
+                }
+                Ok(self.type_system.type_registry.type_int)
+            },
             Literal::Float { .. } => Ok(self.type_system.type_registry.type_float),
+            Literal::Complex { .. } => Ok(self.type_system.type_registry.type_complex),
             Literal::Boolean(_) => Ok(self.type_system.type_registry.type_bool),
             Literal::String(_) => Ok(self.type_system.type_registry.type_string),
+            Literal::Bytes(bytes) => {
+                let type_int = self.type_system.type_registry.type_int;
+                Ok(self.type_system.type_registry.create_array_type(type_int, Some(bytes.len())))
+            },
             Literal::Char(_) => Ok(self.type_system.type_registry.type_char),
             Literal::Array(elements) => {
                 self.check_array_literal(elements)
@@ -120,7 +183,27 @@ impl TypeChecker {
     ) -> Result<TypeId, SemanticError> {
         let left_type_id = self.check_expression(left)?;
         let right_type_id = self.check_expression(right)?;
+        self.check_binary_operands(left_type_id, operator, right_type_id)
+    }
 
+    /// `a < b < c`: chaque operande est verifiee une fois, puis chaque paire voisine
+    fn check_comparison_chain(&mut self, chain: &ComparisonChain) -> Result<TypeId, SemanticError> {
+        let type_ids = chain.operands.iter()
+            .map(|operand| self.check_expression(operand))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (operator, pair) in chain.operators.iter().zip(type_ids.windows(2)) {
+            self.check_binary_operands(pair[0], operator, pair[1])?;
+        }
+        Ok(self.type_system.type_registry.type_bool)
+    }
+
+    /// Type du resultat de `operator` applique a des operandes deja verifiees
+    fn check_binary_operands(
+        &mut self,
+        left_type_id: TypeId,
+        operator: &Operator,
+        right_type_id: TypeId
+    ) -> Result<TypeId, SemanticError> {
         // Récupérer les objets Type
         let left_type = self.type_system.type_registry.get_type(left_type_id)
             .ok_or_else(|| create_semantic_error(
@@ -148,6 +231,11 @@ impl TypeChecker {
                     (TypeKind::Float, TypeKind::Float) => Ok(left_type_id), // float op float -> float
                     (TypeKind::Int, TypeKind::Float) => Ok(right_type_id), // int op float -> float
                     (TypeKind::Float, TypeKind::Int) => Ok(left_type_id), // float op int -> float
+                    // int/float op complex -> complex (pas de modulo sur les complexes)
+                    (TypeKind::Complex, TypeKind::Int | TypeKind::Float | TypeKind::Complex)
+                    | (TypeKind::Int | TypeKind::Float, TypeKind::Complex) if *operator != Operator::Modulo => {
+                        Ok(self.type_system.type_registry.type_complex)
+                    },
                     (TypeKind::String, TypeKind::String) if *operator == Operator::Addition => {
                         // Concaténation de chaînes
                         Ok(left_type_id) // string + string -> string
@@ -216,6 +304,82 @@ impl TypeChecker {
                 }
             },
 
+            Operator::Power => {
+                // Puissance (**)
+                match (&left_type.kind, &right_type.kind) {
+                    (TypeKind::Int, TypeKind::Int) => Ok(left_type_id),
+                    (TypeKind::Float, TypeKind::Float) | (TypeKind::Float, TypeKind::Int) => Ok(left_type_id),
+                    (TypeKind::Int, TypeKind::Float) => Ok(right_type_id),
+                    (TypeKind::Complex, TypeKind::Int | TypeKind::Float | TypeKind::Complex)
+                    | (TypeKind::Int | TypeKind::Float, TypeKind::Complex) => Ok(self.type_system.type_registry.type_complex),
+                    _ => Err(create_semantic_error(
+                        SemanticErrorType::TypeError(TypeError::TypeMismatch(
+                            format!("Cannot apply operator {:?} to types {} and {}",
+                                    operator, left_type, right_type)
+                        )),
+                        "Incompatible types for binary operation".to_string(),
+                        Position { index: 0 }
+                    ))
+                }
+            },
+
+            Operator::BitwiseAnd | Operator::BitwiseOr | Operator::BitwiseXor |
+            Operator::ShiftLeft | Operator::ShiftRight => {
+                // Opérations bit a bit (&, |, ^, <<, >>)
+                match (&left_type.kind, &right_type.kind) {
+                    (TypeKind::Int, TypeKind::Int) => Ok(left_type_id),
+                    (TypeKind::Bool, TypeKind::Bool)
+                        if matches!(operator, Operator::BitwiseAnd | Operator::BitwiseOr | Operator::BitwiseXor) => {
+                        Ok(self.type_system.type_registry.type_bool)
+                    },
+                    _ => Err(create_semantic_error(
+                        SemanticErrorType::TypeError(TypeError::TypeMismatch(
+                            format!("Bitwise operator {:?} requires integer operands, got {} and {}",
+                                    operator, left_type, right_type)
+                        )),
+                        "Incompatible types for bitwise operation".to_string(),
+                        Position { index: 0 }
+                    ))
+                }
+            },
+
+            Operator::MatrixMultiplication => {
+                // Produit matriciel (@), uniquement entre tableaux
+                match (&left_type.kind, &right_type.kind) {
+                    (TypeKind::Array(..), TypeKind::Array(..)) => Ok(left_type_id),
+                    _ => Err(create_semantic_error(
+                        SemanticErrorType::TypeError(TypeError::TypeMismatch(
+                            format!("Cannot apply operator {:?} to types {} and {}",
+                                    operator, left_type, right_type)
+                        )),
+                        "Matrix multiplication requires array operands".to_string(),
+                        Position { index: 0 }
+                    ))
+                }
+            },
+
+            Operator::In | Operator::NotIn => {
+                // Appartenance (in, not in): l'operande droite doit etre une collection
+                match &right_type.kind {
+                    TypeKind::Array(..) | TypeKind::Tuple(_) | TypeKind::String |
+                    TypeKind::Named(..) | TypeKind::Generic(..) | TypeKind::Infer(_) => {
+                        Ok(self.type_system.type_registry.type_bool)
+                    },
+                    _ => Err(create_semantic_error(
+                        SemanticErrorType::TypeError(TypeError::TypeMismatch(
+                            format!("Type {} does not support membership test", right_type)
+                        )),
+                        "Membership test requires a collection".to_string(),
+                        Position { index: 0 }
+                    ))
+                }
+            },
+
+            Operator::Is | Operator::IsNot => {
+                // Identité (is, is not): toujours bool
+                Ok(self.type_system.type_registry.type_bool)
+            },
+
             // Assignment operator
             Operator::Equal => {
                 // Pour l'assignation, vérifier que les types sont compatibles
@@ -273,7 +437,7 @@ impl TypeChecker {
         match operator {
             UnaryOperator::Negate | UnaryOperator::Negative => {
                 match &operand_type.kind {
-                    TypeKind::Int | TypeKind::Float => Ok(operand_type_id),
+                    TypeKind::Int | TypeKind::Float | TypeKind::Complex => Ok(operand_type_id),
                     _ => Err(create_semantic_error(
                         SemanticErrorType::TypeError(TypeError::TypeMismatch(
                             format!("Cannot negate type {}", operand_type)
@@ -333,7 +497,7 @@ impl TypeChecker {
 
             UnaryOperator::Positive => {
                 match &operand_type.kind {
-                    TypeKind::Int | TypeKind::Float => Ok(operand_type_id),
+                    TypeKind::Int | TypeKind::Float | TypeKind::Complex => Ok(operand_type_id),
                     _ => Err(create_semantic_error(
                         SemanticErrorType::TypeError(TypeError::TypeMismatch(
                             format!("Cannot apply unary plus to type {}", operand_type)
@@ -491,6 +655,197 @@ impl TypeChecker {
         Ok(self.type_system.type_registry.create_array_type(first_element_type_id, Some(elements.len())))
     }
 
+    /// Vérifie un tuple, `()` est le type unit
+    fn check_tuple_expression(&mut self, elements: &[Expression]) -> Result<TypeId, SemanticError> {
+        if elements.is_empty() {
+            return Ok(self.type_system.type_registry.type_unit);
+        }
+
+        let mut element_type_ids = Vec::with_capacity(elements.len());
+        for element in elements {
+            element_type_ids.push(self.check_expression(element)?);
+        }
+        Ok(self.type_system.type_registry.create_tuple_type(element_type_ids))
+    }
+
+    /// Verifie qu'un litteral entier tient dans un type de taille fixe (i8..u128)
+    fn check_integer_range(&self, value: &BigInt, width: NumberSuffix) -> Result<(), SemanticError> {
+        let Some((min, max)) = width.integer_bounds() else {
+            return Ok(());
+        };
+        if *value < min || *value > max {
+            let message = format!("literal {} does not fit in {} (range {}..={})", value, width.name(), min, max);
+            return Err(create_semantic_error(
+                SemanticErrorType::TypeError(TypeError::IntegerOverflow(message.clone())),
+                message,
+                Position { index: 0 }
+            ));
+        }
+        Ok(())
+    }
+
+    /// Vérifie chaque expression d'une f-string et sa compatibilite avec le type de la spec
+    fn check_format_string(&mut self, format: &FormatString) -> Result<TypeId, SemanticError> {
+        for part in &format.parts {
+            let FormatStringPart::Expression { expression, format_spec } = part else {
+                continue;
+            };
+            let type_id = self.check_expression(expression)?;
+            let Some(class) = format_spec.as_ref()
+                .and_then(|spec| spec.chars().last())
+                .and_then(format_type_class) else {
+                continue;
+            };
+            let Some(expression_type) = self.type_system.type_registry.get_type(type_id) else {
+                continue;
+            };
+            let rejected = matches!((class, &expression_type.kind),
+                (FormatTypeClass::Integer, TypeKind::Float | TypeKind::Complex | TypeKind::String | TypeKind::Bool)
+                | (FormatTypeClass::Numeric, TypeKind::String | TypeKind::Bool | TypeKind::Char)
+                | (FormatTypeClass::Text, TypeKind::Int | TypeKind::Float | TypeKind::Complex));
+            if rejected {
+                return Err(create_semantic_error(
+                    SemanticErrorType::TypeError(TypeError::TypeMismatch(
+                        format!("Format spec '{}' cannot be applied to type {}",
+                                format_spec.as_deref().unwrap_or_default(), expression_type)
+                    )),
+                    "Incompatible format spec in f-string".to_string(),
+                    Position { index: 0 }
+                ).with_span(expression.span()));
+            }
+        }
+        Ok(self.type_system.type_registry.type_string)
+    }
+
+    /// Vérifie `Enum::Variante`: une variante unit a le type de l'enumeration,
+    /// une variante tuple est un constructeur fn(charge) -> enumeration
+    fn check_enum_variant_path(&mut self, path: &str) -> Result<TypeId, SemanticError> {
+        let (enum_type_id, variant) = self.type_system.type_registry.lookup_enum_variant(path)
+            .map(|(enum_type_id, variant)| (enum_type_id, variant.clone()))
+            .ok_or_else(|| create_semantic_error(
+                SemanticErrorType::TypeError(TypeError::UndefinedType(path.to_string())),
+                format!("Unknown enum variant '{}'", path),
+                Position { index: 0 }
+            ))?;
+
+        match variant.payload {
+            VariantPayload::Unit => Ok(enum_type_id),
+            VariantPayload::Tuple(types) => {
+                let param_type_ids = types.iter().map(|t| t.id).collect();
+                Ok(self.type_system.type_registry.create_function_type(param_type_ids, enum_type_id))
+            },
+            VariantPayload::Struct(_) => Err(create_semantic_error(
+                SemanticErrorType::TypeError(TypeError::InvalidType(
+                    format!("Variant '{}' has named fields and must be built with `{} {{ .. }}`", path, path)
+                )),
+                "Struct variant used as a value".to_string(),
+                Position { index: 0 }
+            )),
+        }
+    }
+
+    /// Vérifie un struct literal contre les champs declares de la structure
+    /// ou de la variante d'enumeration (`Shape::Move { x: 1 }`)
+    fn check_struct_literal(&mut self, literal: &StructLiteral) -> Result<TypeId, SemanticError> {
+        let registry = &self.type_system.type_registry;
+        let target = match registry.struct_types.get(&literal.name) {
+            Some(&struct_type_id) => Some((
+                struct_type_id,
+                registry.get_struct_fields(struct_type_id).cloned().unwrap_or_default()
+            )),
+            None => match registry.lookup_enum_variant(&literal.name) {
+                Some((enum_type_id, EnumVariantInfo { payload: VariantPayload::Struct(fields), .. })) => {
+                    Some((enum_type_id, fields.clone()))
+                },
+                _ => None,
+            },
+        };
+        let (struct_type_id, declared_fields) = target
+            .ok_or_else(|| create_semantic_error(
+                SemanticErrorType::TypeError(TypeError::UndefinedType(literal.name.clone())),
+                format!("Unknown struct '{}'", literal.name),
+                Position { index: literal.span.start }
+            ).with_span(literal.span))?;
+
+        let mut initialized: Vec<&str> = Vec::new();
+        for field in &literal.fields {
+            if initialized.contains(&field.name.as_str()) {
+                return Err(create_semantic_error(
+                    SemanticErrorType::TypeError(TypeError::InvalidType(
+                        format!("Field '{}' is specified more than once", field.name)
+                    )),
+                    "Duplicate struct field".to_string(),
+                    Position { index: field.span.start }
+                ).with_span(field.span));
+            }
+            initialized.push(&field.name);
+
+            let expected_type = declared_fields.iter()
+                .find(|(name, _)| *name == field.name)
+                .map(|(_, field_type)| field_type.clone())
+                .ok_or_else(|| create_semantic_error(
+                    SemanticErrorType::TypeError(TypeError::InvalidType(
+                        format!("Struct '{}' has no field '{}'", literal.name, field.name)
+                    )),
+                    "Unknown struct field".to_string(),
+                    Position { index: field.span.start }
+                ).with_span(field.span))?;
+
+            let value_type_id = self.check_expression(&field.value)?;
+            let value_type = self.type_system.type_registry.get_type(value_type_id)
+                .ok_or_else(|| create_semantic_error(
+                    SemanticErrorType::TypeError(TypeError::TypeNotFound(format!("{:?}", value_type_id))),
+                    "Type not found".to_string(),
+                    Position { index: 0 }
+                ))?;
+            if !value_type.is_compatible_with(&expected_type) {
+                return Err(create_semantic_error(
+                    SemanticErrorType::TypeError(TypeError::TypeMismatch(
+                        format!("Field '{}' expects {}, found {}", field.name, expected_type, value_type)
+                    )),
+                    "Mismatched struct field type".to_string(),
+                    Position { index: field.value.span().start }
+                ).with_span(field.value.span()));
+            }
+        }
+
+        match &literal.base {
+            // ..base fournit les champs restants, il doit etre de la meme structure
+            Some(base) => {
+                let base_type_id = self.check_expression(base)?;
+                if base_type_id != struct_type_id {
+                    let base_type = self.type_system.type_registry.get_type(base_type_id)
+                        .map(|t| t.to_string())
+                        .unwrap_or_else(|| format!("{:?}", base_type_id));
+                    return Err(create_semantic_error(
+                        SemanticErrorType::TypeError(TypeError::TypeMismatch(
+                            format!("Base expression has type {}, expected struct({})", base_type, literal.name)
+                        )),
+                        "Mismatched struct update base".to_string(),
+                        Position { index: base.span().start }
+                    ).with_span(base.span()));
+                }
+            },
+            None => {
+                let missing: Vec<&str> = declared_fields.iter()
+                    .map(|(name, _)| name.as_str())
+                    .filter(|name| !initialized.contains(name))
+                    .collect();
+                if !missing.is_empty() {
+                    return Err(create_semantic_error(
+                        SemanticErrorType::TypeError(TypeError::InvalidType(
+                            format!("Missing fields {} in initializer of '{}'", missing.join(", "), literal.name)
+                        )),
+                        "Missing struct fields".to_string(),
+                        Position { index: literal.span.start }
+                    ).with_span(literal.span));
+                }
+            },
+        }
+
+        Ok(struct_type_id)
+    }
+
     /// Vérifie un accès à un array
     fn check_array_access(
         &mut self,
@@ -555,6 +910,19 @@ impl TypeChecker {
         // Pour l'instant, une implémentation simplifiée
         // Dans une vraie implémentation, il faudrait chercher le membre dans la structure
         match &object_type.kind {
+            TypeKind::Struct(struct_id) if self.type_system.type_registry.get_struct_fields(object_type_id).is_some() => {
+                let struct_name = struct_id.name.clone();
+                self.type_system.type_registry.get_struct_fields(object_type_id)
+                    .and_then(|fields| fields.iter().find(|(name, _)| name == member))
+                    .map(|(_, field_type)| field_type.id)
+                    .ok_or_else(|| create_semantic_error(
+                        SemanticErrorType::TypeError(TypeError::InvalidType(
+                            format!("Struct '{}' has no field '{}'", struct_name, member)
+                        )),
+                        "Unknown struct field".to_string(),
+                        Position { index: 0 }
+                    ))
+            },
             TypeKind::Struct(_) | TypeKind::Named(_, _) => {
                 // Supposer que le membre existe et retourner un type inféré
                 let member_type_var = self.type_system.create_type_variable(Some(format!("{}Member", member)));
@@ -659,6 +1027,7 @@ impl TypeChecker {
             // Casts numériques autorisés
             (TypeKind::Int, TypeKind::Float) |
             (TypeKind::Float, TypeKind::Int) |
+            (TypeKind::Int | TypeKind::Float, TypeKind::Complex) |
             (TypeKind::Int, TypeKind::Char) |
             (TypeKind::Char, TypeKind::Int) => Ok(target_type_id),
 
@@ -680,7 +1049,9 @@ impl TypeChecker {
         &mut self,
         var_decl: &VariableDeclaration
     ) -> Result<TypeId, SemanticError> {
-        let inferred_type = match (&var_decl.variable_type, &var_decl.value) {
+        // Un type `_` laisse par le parser est inféré depuis la valeur
+        let variable_type = var_decl.variable_type.as_ref().filter(|ast_type| **ast_type != ASTType::Infer);
+        let inferred_type = match (variable_type, &var_decl.value) {
             (Some(ast_type), Some(expr)) => {
                 // A la fois un type explicite et un initializer
                 let declared_type_id = self.type_system.type_registry.convert_ast_type(ast_type);
@@ -712,6 +1083,12 @@ impl TypeChecker {
                     ));
                 }
 
+                // `let x: u8 = 300` deborde le type de taille fixe declare
+                if let (Some(width), Some((value, _))) = (ast_type.fixed_width(), integer_literal_value(expr)) {
+                    self.check_integer_range(&value, width)
+                        .map_err(|error| error.or_span(expr.span()))?;
+                }
+
                 declared_type_id
             },
 
@@ -728,7 +1105,7 @@ impl TypeChecker {
             (None, None) => {
                 // Ni type explicite ni initializer - erreur
                 return Err(create_semantic_error(
-                    SemanticErrorType::TypeError(TypeError::UndefinedType(var_decl.name.clone())),
+                    SemanticErrorType::TypeError(TypeError::UndefinedType(var_decl.pattern.name().unwrap_or("_").to_string())),
                     "Variable declaration without type or initializer".to_string(),
                     Position { index: 0 }
                 ));
@@ -800,6 +1177,23 @@ impl TypeChecker {
                 Ok(())
             },
 
+            Statement::RaiseStatement(raise_stmt) => {
+                self.check_expression(&raise_stmt.exception)?;
+                Ok(())
+            },
+
+            Statement::DelStatement(del_stmt) => {
+                self.check_expression(&del_stmt.target)?;
+                Ok(())
+            },
+
+            Statement::YieldStatement(yield_stmt) => {
+                if let Some(value) = &yield_stmt.value {
+                    self.check_expression(value)?;
+                }
+                Ok(())
+            },
+
             // Plus de types de statements...
             _ => {
                 // Pour l'instant, accepter tous les autres statements
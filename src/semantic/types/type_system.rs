@@ -17,6 +17,7 @@ pub enum TypeKind {
 
     Int,
     Float,
+    Complex,
     Bool,
     Char,
     String,
@@ -93,8 +94,11 @@ impl Type {
             (TypeKind::Char, TypeKind::Char) |
             (TypeKind::String, TypeKind::String) => true,
 
-            // Conversion implicite int -> float
+            (TypeKind::Complex, TypeKind::Complex) => true,
+
+            // Conversion implicite int -> float, int/float -> complex
             (TypeKind::Int, TypeKind::Float) => true,
+            (TypeKind::Int | TypeKind::Float, TypeKind::Complex) => true,
 
             // Array compatible si les éléments sont compatibles
             (TypeKind::Array(t1, _), TypeKind::Array(t2, _)) => t1.is_compatible_with(t2),
@@ -120,6 +124,9 @@ impl Type {
                 i1.is_compatible_with(i2)
             },
 
+            // Un parametre generique sans contrainte accepte tout type
+            (_, TypeKind::Generic(_, constraints)) if constraints.is_empty() => true,
+
             // Tout type est compatible avec lui-même
             _ => self.id == other.id,
         }
@@ -132,6 +139,7 @@ impl fmt::Display for Type {
         match &self.kind {
             TypeKind::Int => write!(f, "int"),
             TypeKind::Float => write!(f, "float"),
+            TypeKind::Complex => write!(f, "complex"),
             TypeKind::Bool => write!(f, "bool"),
             TypeKind::Char => write!(f, "char"),
             TypeKind::String => write!(f, "str"),
@@ -243,6 +251,22 @@ pub struct EnumTypeId {
     pub name: String,
 }
 
+/// Variante d'une enumeration enregistree dans le registre
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumVariantInfo {
+    pub name: String,
+    pub payload: VariantPayload,
+    pub discriminant: i64,
+}
+
+/// Donnees portees par une variante
+#[derive(Debug, Clone, PartialEq)]
+pub enum VariantPayload {
+    Unit,
+    Tuple(Vec<Type>),
+    Struct(Vec<(String, Type)>),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypeVarId {
     pub id: TypeId,
@@ -260,6 +284,16 @@ pub struct LifetimeId {
 pub struct TypeRegistry {
     pub types: HashMap<TypeId, Type>,
     pub next_type_id: u32,
+    // Lifetimes nommees rencontrees dans les annotations ('a -> LifetimeId)
+    pub lifetimes: HashMap<String, LifetimeId>,
+    // Structures declarees (nom -> TypeId) et leurs champs dans l'ordre de declaration
+    pub struct_types: HashMap<String, TypeId>,
+    pub struct_fields: HashMap<TypeId, Vec<(String, Type)>>,
+    // Enumerations declarees (nom -> TypeId) et leurs variantes
+    pub enum_types: HashMap<String, TypeId>,
+    pub enum_variants: HashMap<TypeId, Vec<EnumVariantInfo>>,
+    // Parametres generiques visibles pendant la conversion d'un type AST
+    pub generic_scope: Vec<String>,
 
     // Types primitifs pré-définis
     pub type_int: TypeId,
@@ -269,6 +303,7 @@ pub struct TypeRegistry {
     pub type_string: TypeId,
     pub type_unit: TypeId,
     pub type_error: TypeId,
+    pub type_complex: TypeId,
 }
 
 impl TypeRegistry {
@@ -277,6 +312,12 @@ impl TypeRegistry {
         let mut registry = TypeRegistry {
             types: HashMap::new(),
             next_type_id: 1,
+            lifetimes: HashMap::new(),
+            struct_types: HashMap::new(),
+            struct_fields: HashMap::new(),
+            enum_types: HashMap::new(),
+            enum_variants: HashMap::new(),
+            generic_scope: Vec::new(),
             type_int: TypeId(0),
             type_float: TypeId(0),
             type_bool: TypeId(0),
@@ -284,6 +325,7 @@ impl TypeRegistry {
             type_string: TypeId(0),
             type_unit: TypeId(0),
             type_error: TypeId(0),
+            type_complex: TypeId(0),
         };
 
         // Créer les types primitifs
@@ -294,6 +336,7 @@ impl TypeRegistry {
         registry.type_string = registry.register_type(TypeKind::String);
         registry.type_unit = registry.register_type(TypeKind::Unit);
         registry.type_error = registry.register_type(TypeKind::Error);
+        registry.type_complex = registry.register_type(TypeKind::Complex);
 
         registry
     }
@@ -381,6 +424,78 @@ impl TypeRegistry {
         }
     }
 
+    /// Crée le type d'une structure declaree, les champs sont renseignes par set_struct_fields
+    pub fn create_struct_type(&mut self, name: &str) -> TypeId {
+        let id = TypeId(self.next_type_id);
+        self.register_type(TypeKind::Struct(StructTypeId { id, name: name.to_string() }));
+        self.struct_types.insert(name.to_string(), id);
+        id
+    }
+
+    /// Renseigne les champs d'une structure
+    pub fn set_struct_fields(&mut self, struct_type_id: TypeId, fields: Vec<(String, TypeId)>) {
+        let fields = fields.into_iter()
+            .filter_map(|(name, type_id)| self.get_type(type_id).cloned().map(|t| (name, t)))
+            .collect();
+        self.struct_fields.insert(struct_type_id, fields);
+    }
+
+    /// Champs d'une structure, None si le type n'est pas une structure connue
+    pub fn get_struct_fields(&self, struct_type_id: TypeId) -> Option<&Vec<(String, Type)>> {
+        self.struct_fields.get(&struct_type_id)
+    }
+
+    /// Crée le type d'une enumeration declaree, les variantes sont renseignees par set_enum_variants
+    pub fn create_enum_type(&mut self, name: &str) -> TypeId {
+        let id = TypeId(self.next_type_id);
+        self.register_type(TypeKind::Enum(EnumTypeId { id, name: name.to_string() }));
+        self.enum_types.insert(name.to_string(), id);
+        id
+    }
+
+    /// Renseigne les variantes d'une enumeration
+    pub fn set_enum_variants(&mut self, enum_type_id: TypeId, variants: Vec<EnumVariantInfo>) {
+        self.enum_variants.insert(enum_type_id, variants);
+    }
+
+    /// Variantes d'une enumeration, None si le type n'est pas une enumeration connue
+    pub fn get_enum_variants(&self, enum_type_id: TypeId) -> Option<&Vec<EnumVariantInfo>> {
+        self.enum_variants.get(&enum_type_id)
+    }
+
+    /// Resout `Enum::Variante` en (TypeId de l'enumeration, variante)
+    pub fn lookup_enum_variant(&self, path: &str) -> Option<(TypeId, &EnumVariantInfo)> {
+        let (enum_name, variant_name) = path.rsplit_once("::")?;
+        let enum_type_id = *self.enum_types.get(enum_name)?;
+        self.get_enum_variants(enum_type_id)?
+            .iter()
+            .find(|variant| variant.name == variant_name)
+            .map(|variant| (enum_type_id, variant))
+    }
+
+    /// Convertit un type AST en considerant `names` comme des parametres generiques
+    pub fn convert_ast_type_with_generics(&mut self, ast_type: &ASTType, names: &[String]) -> TypeId {
+        let previous_len = self.generic_scope.len();
+        self.generic_scope.extend(names.iter().cloned());
+        let type_id = self.convert_ast_type(ast_type);
+        self.generic_scope.truncate(previous_len);
+        type_id
+    }
+
+    /// Identifiant d'une lifetime nommee (`'a`) dans une annotation de type
+    fn lifetime_id(&mut self, name: &str) -> LifetimeId {
+        if let Some(lifetime) = self.lifetimes.get(name) {
+            return lifetime.clone();
+        }
+        let lifetime = LifetimeId {
+            id: TypeId(self.next_type_id),
+            name: name.to_string(),
+        };
+        self.next_type_id += 1;
+        self.lifetimes.insert(name.to_string(), lifetime.clone());
+        lifetime
+    }
+
     /// Convertit un type AST en TypeId
     pub fn convert_ast_type(&mut self, ast_type: &ASTType) -> TypeId {
         match ast_type {
@@ -398,6 +513,14 @@ impl TypeRegistry {
                 self.create_array_type(elem_type_id, None)
             },
 
+            ASTType::FixedArray(elem_type, size) => {
+                let elem_type_id = self.convert_ast_type(elem_type);
+                self.create_array_type(elem_type_id, Some(*size))
+            },
+
+            // `()` est le type unit
+            ASTType::Tuple(elem_types) if elem_types.is_empty() => self.type_unit,
+
             ASTType::Tuple(elem_types) => {
                 // Convertir tous les types d'éléments d'abord
                 let elem_type_ids: Vec<TypeId> = elem_types.iter()
@@ -421,22 +544,51 @@ impl TypeRegistry {
             },
 
             // Autres cas...
-            ASTType::Reference(inner_type) => {
+            ASTType::Reference(inner_type, lifetime) => {
                 let inner_type_id = self.convert_ast_type(inner_type);
-                self.create_reference_type(inner_type_id, Mutability::Immutable, None)
+                let lifetime = lifetime.as_ref().map(|name| self.lifetime_id(name));
+                self.create_reference_type(inner_type_id, Mutability::Immutable, lifetime)
             },
 
-            ASTType::ReferenceMutable(inner_type) => {
+            ASTType::ReferenceMutable(inner_type, lifetime) => {
                 let inner_type_id = self.convert_ast_type(inner_type);
-                self.create_reference_type(inner_type_id, Mutability::Mutable, None)
+                let lifetime = lifetime.as_ref().map(|name| self.lifetime_id(name));
+                self.create_reference_type(inner_type_id, Mutability::Mutable, lifetime)
+            },
+
+            ASTType::Function(param_types, return_type) => {
+                let param_type_ids: Vec<TypeId> = param_types.iter()
+                    .map(|t| self.convert_ast_type(t))
+                    .collect();
+                let return_type_id = self.convert_ast_type(return_type);
+                self.create_function_type(param_type_ids, return_type_id)
             },
 
-            ASTType::Named(name) => {
-                self.register_type(TypeKind::Named(name.clone(), Vec::new()))
+            // `T?` est un raccourci pour Option<T>
+            ASTType::Optional(inner_type) => {
+                let inner_type_id = self.convert_ast_type(inner_type);
+                match self.get_type(inner_type_id).cloned() {
+                    Some(inner) => self.register_type(TypeKind::Named("Option".to_string(), vec![inner])),
+                    None => self.type_error,
+                }
             },
 
-            ASTType::Custom(name) => {
-                self.register_type(TypeKind::Named(name.clone(), Vec::new()))
+            ASTType::Named(name) | ASTType::Custom(name) => {
+                // Une structure ou une enumeration declaree garde son propre TypeId
+                if self.generic_scope.contains(name) {
+                    self.register_type(TypeKind::Generic(name.clone(), Vec::new()))
+                } else if let Some(&struct_type_id) = self.struct_types.get(name) {
+                    struct_type_id
+                } else if let Some(&enum_type_id) = self.enum_types.get(name) {
+                    enum_type_id
+                } else if let Some(width) = ast_type.fixed_width() {
+                    // i8..u128 et f32/f64 partagent les types int et float, les bornes sont verifiees par le type checker
+                    if width.is_float() { self.type_float } else { self.type_int }
+                } else if ast_type.is_complex() {
+                    self.type_complex
+                } else {
+                    self.register_type(TypeKind::Named(name.clone(), Vec::new()))
+                }
             },
 
             ASTType::Infer => {
@@ -1 +1,368 @@
+//src/utils/error.rs
 
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use crate::lexer::lexer_error::LexerError;
+use crate::parser::ast::Span;
+use crate::parser::parser_error::ParserError;
+use crate::semantic::semantic_error::SemanticError;
+
+// Sous-systeme de diagnostics partage par le lexer, le parser et l'analyse semantique.
+// Un Diagnostic se rend avec la ligne fautive du code source et un soulignement:
+//
+// error[E0205]: Expected expression
+//  --> main.pk:1:9
+//   |
+// 1 | let x = ;
+//   |         ^ Expected expression
+//   |
+//   = note: ...
+
+/// Gravite d'un diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+/// Etiquette attachee a une portion du code source
+/// L'etiquette principale est soulignee avec '^', les secondaires avec '-'
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+    pub primary: bool,
+}
+
+/// Diagnostic complet: gravite, code, message, etiquettes et notes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<String>,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+}
+
+/// Styles ANSI utilises pour la sortie coloree
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const BLUE: &str = "\x1b[1;34m";
+
+impl Severity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+            Severity::Help => "help",
+        }
+    }
+
+    fn color(&self) -> &'static str {
+        match self {
+            Severity::Error => "\x1b[1;31m",
+            Severity::Warning => "\x1b[1;33m",
+            Severity::Note => "\x1b[1;32m",
+            Severity::Help => "\x1b[1;36m",
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Label {
+    pub fn primary(span: Span, message: impl Into<String>) -> Self {
+        Label { span, message: message.into(), primary: true }
+    }
+
+    pub fn secondary(span: Span, message: impl Into<String>) -> Self {
+        Label { span, message: message.into(), primary: false }
+    }
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity,
+            code: None,
+            message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label::primary(span, message));
+        self
+    }
+
+    pub fn with_secondary_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label::secondary(span, message));
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Span de l'etiquette principale (la premiere s'il y en a plusieurs)
+    pub fn primary_span(&self) -> Option<Span> {
+        self.labels.iter().find(|label| label.primary).map(|label| label.span)
+    }
+
+    /// Rendu sans couleur
+    pub fn render(&self, source: &str, file_name: &str) -> String {
+        self.render_with(source, file_name, false)
+    }
+
+    /// Rendu avec les codes couleur ANSI
+    pub fn render_colored(&self, source: &str, file_name: &str) -> String {
+        self.render_with(source, file_name, true)
+    }
+
+    fn render_with(&self, source: &str, file_name: &str, color: bool) -> String {
+        let paint = |style: &str, text: &str| -> String {
+            if color { format!("{}{}{}", style, text, RESET) } else { text.to_string() }
+        };
+
+        let mut out = String::new();
+
+        // En-tete: error[E0205]: message
+        let mut header = self.severity.as_str().to_string();
+        if let Some(code) = &self.code {
+            header.push_str(&format!("[{}]", code));
+        }
+        out.push_str(&paint(self.severity.color(), &header));
+        out.push_str(&paint(BOLD, &format!(": {}", self.message)));
+        out.push('\n');
+
+        // Resolution des etiquettes en (ligne, colonne, largeur)
+        let mut resolved: Vec<(SourcePosition, &Label)> = self.labels.iter()
+            .filter_map(|label| resolve_span(source, label.span).map(|pos| (pos, label)))
+            .collect();
+
+        let primary = resolved.iter()
+            .find(|(_, label)| label.primary)
+            .or_else(|| resolved.first())
+            .map(|(pos, _)| *pos);
+
+        let gutter_width = resolved.iter()
+            .map(|(pos, _)| pos.line.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let pad = " ".repeat(gutter_width);
+
+        match primary {
+            Some(pos) => out.push_str(&format!("{}{} {}:{}:{}\n", pad, paint(BLUE, "-->"), file_name, pos.line, pos.column)),
+            None => out.push_str(&format!("{}{} {}\n", pad, paint(BLUE, "-->"), file_name)),
+        }
+
+        if !resolved.is_empty() {
+            out.push_str(&format!("{} {}\n", pad, paint(BLUE, "|")));
+
+            resolved.sort_by_key(|(pos, label)| (pos.line, !label.primary, pos.column));
+            let mut current_line = 0;
+            for (pos, label) in &resolved {
+                if pos.line != current_line {
+                    let text = source.lines().nth(pos.line - 1).unwrap_or("");
+                    out.push_str(&format!("{:>width$} {} {}\n",
+                                          paint(BLUE, &pos.line.to_string()), paint(BLUE, "|"), text,
+                                          width = if color { gutter_width + BLUE.len() + RESET.len() } else { gutter_width }));
+                    current_line = pos.line;
+                }
+
+                let marker = if label.primary { "^" } else { "-" };
+                let style = if label.primary { self.severity.color() } else { BLUE };
+                let mut underline = marker.repeat(pos.width.max(1));
+                if !label.message.is_empty() {
+                    underline.push(' ');
+                    underline.push_str(&label.message);
+                }
+                out.push_str(&format!("{} {} {}{}\n",
+                                      pad, paint(BLUE, "|"), " ".repeat(pos.column - 1), paint(style, &underline)));
+            }
+        }
+
+        if !self.notes.is_empty() {
+            if !resolved.is_empty() {
+                out.push_str(&format!("{} {}\n", pad, paint(BLUE, "|")));
+            }
+            for note in &self.notes {
+                out.push_str(&format!("{} {} {}\n", pad, paint(BLUE, "="), paint(BOLD, &format!("note: {}", note))));
+            }
+        }
+
+        out
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.severity)?;
+        if let Some(code) = &self.code {
+            write!(f, "[{}]", code)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Position resolue d'une etiquette dans le code source (ligne et colonne a partir de 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SourcePosition {
+    line: usize,
+    column: usize,
+    width: usize,
+}
+
+/// Convertit un span en ligne/colonne/largeur (en caracteres) dans le code source.
+/// Un span sans offsets mais avec une ligne (erreurs du lexer) est utilise tel quel.
+/// Retourne None pour un span fictif qui ne designe aucun endroit du source.
+fn resolve_span(source: &str, span: Span) -> Option<SourcePosition> {
+    if span.start == 0 && span.end == 0 {
+        if span.line == 0 {
+            return None;
+        }
+        let line_len = source.lines().nth(span.line - 1).map(|l| l.chars().count()).unwrap_or(0);
+        let column = span.column.clamp(1, line_len + 1);
+        return Some(SourcePosition { line: span.line, column, width: 1 });
+    }
+
+    let start = span.start.min(source.len());
+    let end = span.end.clamp(start, source.len());
+    if !source.is_char_boundary(start) || !source.is_char_boundary(end) {
+        return None;
+    }
+
+    let line_start = source[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line = source[..start].matches('\n').count() + 1;
+    let column = source[line_start..start].chars().count() + 1;
+
+    // Un span sur plusieurs lignes n'est souligne que jusqu'a la fin de la premiere ligne
+    let line_end = source[start..].find('\n').map(|i| start + i).unwrap_or(source.len());
+    let width = source[start..end.min(line_end)].chars().count();
+
+    Some(SourcePosition { line, column, width })
+}
+
+
+// Conversion des erreurs du compilateur en diagnostics
+
+impl From<LexerError> for Diagnostic {
+    fn from(error: LexerError) -> Self {
+        // Le lexer ne connait que la ligne et la colonne de l'erreur
+        let span = Span { line: error.position.line, column: error.position.column, ..Span::dummy() };
+        Diagnostic::error(error.message.clone())
+            .with_code(error.error.code())
+            .with_label(span, "")
+    }
+}
+
+impl From<ParserError> for Diagnostic {
+    fn from(error: ParserError) -> Self {
+        Diagnostic::error(error.message.clone())
+            .with_code(error.error.code())
+            .with_label(error.span, "")
+    }
+}
+
+impl From<SemanticError> for Diagnostic {
+    fn from(error: SemanticError) -> Self {
+        Diagnostic::error(error.message.clone())
+            .with_code(error.error.code())
+            .with_label(error.span, "")
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ast::FileId;
+    use crate::parser::parser_error::{ParserErrorType, Position};
+    use crate::semantic::semantic_error::{self, SemanticErrorType, SymbolError};
+
+    #[test]
+    fn test_render_primary_and_secondary_labels() {
+        let source = "let x = 1;\nlet y = x + true;\n";
+        let diagnostic = Diagnostic::error("mismatched types")
+            .with_code("E0310")
+            .with_label(Span::new(FileId(0), 23, 27, 2, 13), "expected int")
+            .with_secondary_label(Span::new(FileId(0), 19, 20, 2, 9), "int")
+            .with_note("`+` requires operands of the same type");
+
+        let rendered = diagnostic.render(source, "main.pk");
+        let expected = "\
+error[E0310]: mismatched types
+ --> main.pk:2:13
+  |
+2 | let y = x + true;
+  |             ^^^^ expected int
+  |         - int
+  |
+  = note: `+` requires operands of the same type
+";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_parser_error_conversion() {
+        let source = "let x = ;";
+        let error = ParserError::new(ParserErrorType::ExpectedExpression, Position { index: 3 })
+            .with_span(Span::new(FileId(0), 8, 9, 1, 9));
+        let diagnostic = Diagnostic::from(error);
+        assert_eq!(diagnostic.code.as_deref(), Some("E0205"));
+        assert!(diagnostic.render(source, "main.pk").contains("1 | let x = ;\n  |         ^\n"));
+    }
+
+    #[test]
+    fn test_semantic_error_message_is_not_repeated_in_label() {
+        let source = "let y = x;";
+        let error = SemanticError::new(
+            SemanticErrorType::SymbolError(SymbolError::SymbolNotFound("x".to_string())),
+            String::new(),
+            semantic_error::Position { index: 8 },
+        ).with_span(Span::new(FileId(0), 8, 9, 1, 9));
+        let rendered = Diagnostic::from(error).render(source, "main.pk");
+        assert_eq!(rendered.matches("Symbol 'x' not found").count(), 1);
+        assert!(rendered.contains("1 | let y = x;\n  |         ^\n"));
+    }
+
+    #[test]
+    fn test_colored_output_and_missing_location() {
+        let diagnostic = Diagnostic::warning("unused symbol");
+        let plain = diagnostic.render("", "main.pk");
+        assert_eq!(plain, "warning: unused symbol\n --> main.pk\n");
+        assert!(diagnostic.render_colored("", "main.pk").contains("\x1b[1;33mwarning"));
+    }
+}
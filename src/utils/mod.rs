@@ -1 +1,29 @@
-mod error;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::LazyLock;
+
+pub mod error;
+
+/// Active/desactive les traces de debogage du compilateur (lexer, parser, analyse semantique)
+/// Les traces sont aussi activees par la variable d'environnement PUNK_TRACE
+static TRACE: AtomicBool = AtomicBool::new(false);
+
+/// PUNK_TRACE est lu une seule fois, au premier appel de `trace!`
+static TRACE_ENV: LazyLock<bool> = LazyLock::new(|| std::env::var_os("PUNK_TRACE").is_some());
+
+pub fn set_trace(enabled: bool) {
+    TRACE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn trace_enabled() -> bool {
+    TRACE.load(Ordering::Relaxed) || *TRACE_ENV
+}
+
+/// eprintln! de debogage, silencieux par defaut; ecrit sur stderr pour ne pas
+/// se meler a la sortie du CLI (`--json` notamment)
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::utils::trace_enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
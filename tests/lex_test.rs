@@ -2,7 +2,8 @@
 use num_bigint::BigInt;
 
 use punk::lexer::lex::Lexer;
-use punk::lexer::tok::{Delimiters, Keywords, Operators, StringKind, TokenType};
+use punk::lexer::trivia::{to_source, TriviaKind};
+use punk::lexer::tok::{Delimiters, Keywords, NumberSuffix, Operators, StringKind, TokenType};
 use punk::lexer_error::{LexerError, LexerErrorType, Position};
 
 #[cfg(test)]
@@ -18,10 +19,11 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::INTEGER {
-                value: BigInt::from(123)
+                value: BigInt::from(123),
+                suffix: None
             })
         );
-        assert_eq!(lexer.get_token(), Some(TokenType::FLOAT { value: 3.14 }));
+        assert_eq!(lexer.get_token(), Some(TokenType::FLOAT { value: 3.14, suffix: None }));
     }
 
     #[test]
@@ -30,10 +32,11 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::INTEGER {
-                value: BigInt::from(123)
+                value: BigInt::from(123),
+                suffix: None
             })
         );
-        assert_eq!(lexer.get_token(), Some(TokenType::FLOAT { value: 3.14 }));
+        assert_eq!(lexer.get_token(), Some(TokenType::FLOAT { value: 3.14, suffix: None }));
     }
 
     // Test pour les chaînes avec des séquences d'échappement
@@ -68,7 +71,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "variable".to_string()
+                name: "variable".into()
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::KEYWORD(Keywords::IF)));
@@ -79,7 +82,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "variable".to_string()
+                name: "variable".into()
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::KEYWORD(Keywords::IF)));
@@ -95,7 +98,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "code".to_string()
+                name: "code".into()
             })
         );
     }
@@ -110,7 +113,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "code".to_string()
+                name: "code".into()
             })
         );
     }
@@ -131,7 +134,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "actual_code".to_string()
+                name: "actual_code".into()
             })
         );
     }
@@ -151,7 +154,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "actual_code".to_string()
+                name: "actual_code".into()
             })
         );
     }
@@ -167,7 +170,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "code".to_string()
+                name: "code".into()
             })
         );
     }
@@ -182,7 +185,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "code".to_string()
+                name: "code".into()
             })
         );
     }
@@ -225,7 +228,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "a".to_string()
+                name: "a".into()
             })
         );
         assert_eq!(
@@ -235,14 +238,15 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::INTEGER {
-                value: BigInt::from(1)
+                value: BigInt::from(1),
+                suffix: None
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::AND)));
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "b".to_string()
+                name: "b".into()
             })
         );
         assert_eq!(
@@ -252,14 +256,14 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "c".to_string()
+                name: "c".into()
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::OR)));
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "d".to_string()
+                name: "d".into()
             })
         );
         assert_eq!(
@@ -269,7 +273,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "e".to_string()
+                name: "e".into()
             })
         );
     }
@@ -280,7 +284,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "a".to_string()
+                name: "a".into()
             })
         );
         assert_eq!(
@@ -290,14 +294,15 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::INTEGER {
-                value: BigInt::from(1)
+                value: BigInt::from(1),
+                suffix: None
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::AND)));
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "b".to_string()
+                name: "b".into()
             })
         );
         assert_eq!(
@@ -307,14 +312,14 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "c".to_string()
+                name: "c".into()
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::OR)));
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "d".to_string()
+                name: "d".into()
             })
         );
         assert_eq!(
@@ -324,7 +329,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "e".to_string()
+                name: "e".into()
             })
         );
     }
@@ -470,8 +475,8 @@ mod tests {
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::AT)));
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::ERROR(LexerError::invalid_token(
-                "$",
+            Some(TokenType::ERROR(LexerError::invalid_character(
+                '$',
                 Position { line: 1, column: 4 }
             )))
         );
@@ -483,8 +488,8 @@ mod tests {
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::AT)));
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::ERROR(LexerError::invalid_token(
-                "$",
+            Some(TokenType::ERROR(LexerError::invalid_character(
+                '$',
                 Position { line: 1, column: 4 }
             )))
         );
@@ -497,8 +502,8 @@ mod tests {
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::AT)));
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::ERROR(LexerError::invalid_token(
-                "$",
+            Some(TokenType::ERROR(LexerError::invalid_character(
+                '$',
                 Position { line: 1, column: 3 }
             )))
         );
@@ -520,7 +525,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "sum".to_string()
+                name: "sum".into()
             })
         );
         assert_eq!(
@@ -530,7 +535,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "a".to_string()
+                name: "a".into()
             })
         );
         assert_eq!(
@@ -540,7 +545,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "b".to_string()
+                name: "b".into()
             })
         );
         assert_eq!(
@@ -554,7 +559,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "c".to_string()
+                name: "c".into()
             })
         );
         assert_eq!(
@@ -564,7 +569,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "d".to_string()
+                name: "d".into()
             })
         );
         assert_eq!(
@@ -578,7 +583,7 @@ mod tests {
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "e".to_string()
+                name: "e".into()
             })
         );
         assert_eq!(
@@ -611,7 +616,7 @@ mod tests {
             // TokenType::NEWLINE,
             TokenType::KEYWORD(Keywords::FN),
             TokenType::IDENTIFIER {
-                name: "main".to_string(),
+                name: "main".into(),
             },
             TokenType::DELIMITER(Delimiters::LPAR),
             TokenType::DELIMITER(Delimiters::RPAR),
@@ -619,28 +624,30 @@ mod tests {
             // TokenType::NEWLINE,
             TokenType::KEYWORD(Keywords::LET),
             TokenType::IDENTIFIER {
-                name: "x".to_string(),
+                name: "x".into(),
             },
             TokenType::OPERATOR(Operators::EQUAL),
             TokenType::INTEGER {
                 value: BigInt::from(5),
+                suffix: None,
             },
             TokenType::DELIMITER(Delimiters::SEMICOLON),
             // TokenType::NEWLINE,
             TokenType::KEYWORD(Keywords::IF),
             TokenType::DELIMITER(Delimiters::LPAR),
             TokenType::IDENTIFIER {
-                name: "x".to_string(),
+                name: "x".into(),
             },
             TokenType::OPERATOR(Operators::GREATER),
             TokenType::INTEGER {
                 value: BigInt::from(3),
+                suffix: None,
             },
             TokenType::DELIMITER(Delimiters::RPAR),
             TokenType::DELIMITER(Delimiters::LCURBRACE),
             // TokenType::NEWLINE,
             TokenType::IDENTIFIER {
-                name: "println".to_string(),
+                name: "println".into(),
             },
             TokenType::OPERATOR(Operators::EXCLAMATION),
             TokenType::DELIMITER(Delimiters::LPAR),
@@ -674,7 +681,7 @@ def main():
             TokenType::NEWLINE,
             TokenType::KEYWORD(Keywords::DEF),
             TokenType::IDENTIFIER {
-                name: "main".to_string(),
+                name: "main".into(),
             },
             TokenType::DELIMITER(Delimiters::LPAR),
             TokenType::DELIMITER(Delimiters::RPAR),
@@ -682,26 +689,28 @@ def main():
             TokenType::NEWLINE,
             TokenType::INDENT,
             TokenType::IDENTIFIER {
-                name: "x".to_string(),
+                name: "x".into(),
             },
             TokenType::OPERATOR(Operators::EQUAL),
             TokenType::INTEGER {
                 value: BigInt::from(5),
+                suffix: None,
             },
             TokenType::NEWLINE,
             TokenType::KEYWORD(Keywords::IF),
             TokenType::IDENTIFIER {
-                name: "x".to_string(),
+                name: "x".into(),
             },
             TokenType::OPERATOR(Operators::GREATER),
             TokenType::INTEGER {
                 value: BigInt::from(3),
+                suffix: None,
             },
             TokenType::DELIMITER(Delimiters::COLON),
             TokenType::NEWLINE,
             TokenType::INDENT,
             TokenType::IDENTIFIER {
-                name: "print".to_string(),
+                name: "print".into(),
             },
             TokenType::DELIMITER(Delimiters::LPAR),
             TokenType::STRING {
@@ -718,28 +727,290 @@ def main():
         assert_tokens(input, expected_tokens, SyntaxMode::Indentation);
     }
 
+    fn indentation_errors(input: &str, tab_width: usize) -> Vec<LexerError> {
+        Lexer::new(input, SyntaxMode::Indentation)
+            .with_tab_width(tab_width)
+            .tokenize()
+            .into_iter()
+            .filter_map(|t| match t.token_type {
+                TokenType::ERROR(error) => Some(error),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_indentation_multiple_dedents_and_blank_lines() {
+        let input = "if a:\n    if b:\n        x\n\n  \ny\n";
+        let structure: Vec<TokenType> = Lexer::new(input, SyntaxMode::Indentation)
+            .tokenize()
+            .into_iter()
+            .map(|t| t.token_type)
+            .filter(|t| matches!(t, TokenType::INDENT | TokenType::DEDENT | TokenType::IDENTIFIER { .. }))
+            .collect();
+        let name = |n: &str| TokenType::IDENTIFIER { name: n.into() };
+        assert_eq!(structure, vec![
+            name("a"), TokenType::INDENT, name("b"), TokenType::INDENT, name("x"),
+            TokenType::DEDENT, TokenType::DEDENT, name("y"),
+        ]);
+    }
+
+    #[test]
+    fn test_indentation_tab_error() {
+        // Une tabulation puis quatre espaces: l'ordre depend de la largeur de tabulation
+        let errors = indentation_errors("if a:\n\tx\n    y\n", 4);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error, LexerErrorType::TabError);
+        assert_eq!(errors[0].position, Position { line: 3, column: 5 });
+
+        // Tabulations et espaces dans le meme ordre a chaque ligne: pas d'ambiguite
+        assert!(indentation_errors("if a:\n\tif b:\n\t    x\n\ty\n", 4).is_empty());
+    }
+
+    #[test]
+    fn test_indentation_unmatched_dedent() {
+        let errors = indentation_errors("if a:\n        x\n    y\n", 4);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error, LexerErrorType::InvaliIndentation);
+        assert_eq!(errors[0].position, Position { line: 3, column: 5 });
+        assert!(errors[0].message.contains("does not match any outer indentation level"));
+    }
+
+    #[test]
+    fn test_indentation_unit_and_tab_width() {
+        // Le premier bloc fixe l'unite (4 colonnes), un pas de 6 est refuse
+        let errors = indentation_errors("if a:\n    if b:\n          x\n", 4);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error, LexerErrorType::InvaliIndentation);
+        assert_eq!(errors[0].position.line, 3);
+
+        // Une tabulation de 2 colonnes rend le pas de 2 espaces coherent
+        let input = "if a:\n\tif b:\n\t  x\n";
+        assert_eq!(indentation_errors(input, 4).len(), 1);
+        assert!(indentation_errors(input, 2).is_empty());
+    }
+
+    #[test]
+    fn test_implicit_line_joining_in_brackets() {
+        let input = "x = f(1,\n      [2,\n  3],\n    {4: 5})\ny\n";
+        let tokens: Vec<TokenType> = Lexer::new(input, SyntaxMode::Indentation)
+            .tokenize()
+            .into_iter()
+            .map(|t| t.token_type)
+            .collect();
+        let layout: Vec<&TokenType> = tokens.iter()
+            .filter(|t| matches!(t, TokenType::NEWLINE | TokenType::INDENT | TokenType::DEDENT | TokenType::ERROR(_)))
+            .collect();
+        // Un seul NEWLINE apres `)` et un apres `y`, aucune indentation
+        assert_eq!(layout, vec![&TokenType::NEWLINE, &TokenType::NEWLINE]);
+    }
+
+    #[test]
+    fn test_explicit_line_continuation() {
+        let input = "if a and \\\n        b:\n    x = 1 + \\\r\n2\n";
+        let tokens: Vec<TokenType> = Lexer::new(input, SyntaxMode::Indentation)
+            .tokenize()
+            .into_iter()
+            .map(|t| t.token_type)
+            .collect();
+        let name = |n: &str| TokenType::IDENTIFIER { name: n.into() };
+        let int = |v: i32| TokenType::INTEGER { value: BigInt::from(v), suffix: None };
+        assert_eq!(tokens, vec![
+            TokenType::KEYWORD(Keywords::IF), name("a"), TokenType::KEYWORD(Keywords::AND), name("b"),
+            TokenType::DELIMITER(Delimiters::COLON), TokenType::NEWLINE,
+            TokenType::INDENT, name("x"), TokenType::OPERATOR(Operators::EQUAL), int(1),
+            TokenType::OPERATOR(Operators::PLUS), int(2), TokenType::NEWLINE,
+            TokenType::DEDENT, TokenType::EOF,
+        ]);
+
+        // Un `\` qui n'est pas en fin de ligne reste un caractere invalide
+        let mut lexer = Lexer::new("a \\ b", SyntaxMode::Indentation);
+        lexer.get_token();
+        assert!(matches!(lexer.get_token(), Some(TokenType::ERROR(e)) if e.error == LexerErrorType::InvalidCharacter('\\')));
+    }
+
+    #[test]
+    fn test_unterminated_bracket() {
+        let errors: Vec<LexerError> = Lexer::new("if a:\n    f(1, [2,\n", SyntaxMode::Indentation)
+            .tokenize()
+            .into_iter()
+            .filter_map(|t| match t.token_type {
+                TokenType::ERROR(error) => Some(error),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(errors, vec![LexerError::unterminated_bracket('[', Position { line: 2, column: 10 })]);
+        assert_eq!(errors[0].message, "'[' was never closed");
+
+        // Une parenthese fermee sur une autre ligne ne laisse rien d'ouvert
+        let tokens = Lexer::new("f(\n)\n", SyntaxMode::Indentation).tokenize();
+        assert!(!tokens.iter().any(|t| matches!(t.token_type, TokenType::ERROR(_))));
+    }
+
+    #[test]
+    fn test_token_text_is_source_slice() {
+        let source = "let café = r\"a\\b\" + 0x1F;";
+        let tokens = Lexer::new(source, SyntaxMode::Braces).tokenize();
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text(source)).collect();
+        assert_eq!(texts, vec!["let", "café", "=", "r\"a\\b\"", "+", "0x1F", ";", ""]);
+    }
+
+    #[test]
+    fn test_identifiers_are_interned() {
+        // `cafe\u{301}` (e + accent combinant) et `café` ont la meme forme NFC
+        let mut lexer = Lexer::new("x y x cafe\u{301} café let", SyntaxMode::Braces);
+        lexer.tokenize();
+        let interner = lexer.interner();
+        assert_eq!(interner.len(), 3);
+        let x = interner.get("x").unwrap();
+        assert_eq!(interner.resolve(x), "x");
+        assert!(interner.get("caf\u{e9}").is_some());
+        assert!(interner.get("let").is_none());
+    }
+
+    #[test]
+    fn test_lossless_round_trip() {
+        let braces = [
+            "let x = 1; // fin de ligne\n\n# seul sur sa ligne\nfn f() {\r\n\treturn x \\\n    + 2;\r\n}\n",
+            "/* bloc\n sur deux lignes */ let s = f\"{a} b\"; /// doc\n",
+            "let café = \"é\"; x = $ 1\n   \n",
+            "",
+        ];
+        for source in braces {
+            let tokens = Lexer::new(source, SyntaxMode::Braces).tokenize_lossless();
+            assert_eq!(to_source(&tokens), source);
+        }
+
+        let indentation = [
+            "if a:\n    b = (1,\n  2)  # c\n\n    c\nd\n",
+            "def f():\n\tif x:\n\t\treturn 1 \\\n\t\t\t+ 2\n\n# fin\n",
+            "if a:\r\n    b\r\n        c\r\n  d\r\n",
+            "f(1, [2,\n",
+        ];
+        for source in indentation {
+            let tokens = Lexer::new(source, SyntaxMode::Indentation).tokenize_lossless();
+            assert_eq!(to_source(&tokens), source);
+        }
+    }
+
+    #[test]
+    fn test_lossless_trivia_attachment() {
+        let source = "x = 1 # meme ligne\n\n# ligne seule\ny";
+        let tokens = Lexer::new(source, SyntaxMode::Braces).tokenize_lossless();
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, vec!["x", "=", "1", "y", ""]);
+
+        // Le commentaire de fin de ligne reste avec le token qui le precede
+        let trailing: Vec<(TriviaKind, &str)> = tokens[2].trailing_trivia.iter()
+            .map(|t| (t.kind, t.text.as_str()))
+            .collect();
+        assert_eq!(trailing, vec![
+            (TriviaKind::Whitespace, " "),
+            (TriviaKind::Comment, "# meme ligne"),
+            (TriviaKind::Newline, "\n"),
+        ]);
+
+        // Le commentaire sur sa propre ligne precede le token suivant
+        let leading: Vec<(TriviaKind, &str)> = tokens[3].leading_trivia.iter()
+            .map(|t| (t.kind, t.text.as_str()))
+            .collect();
+        assert_eq!(leading, vec![
+            (TriviaKind::Newline, "\n"),
+            (TriviaKind::Comment, "# ligne seule"),
+            (TriviaKind::Newline, "\n"),
+        ]);
+        assert_eq!(tokens[3].span().line, 4);
+        assert_eq!(tokens[3].full_span().start, source.find("\n\n").unwrap() + 1);
+    }
+
+    #[test]
+    fn test_error_recovery_reports_every_error() {
+        let source = "let a = $;\nlet s = \"ouverte\nlet b = 0b102;\nlet c = 'x' € 1;\n";
+        let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+        let (tokens, errors) = lexer.tokenize_with_errors();
+
+        let kinds: Vec<(LexerErrorType, Position)> = errors.iter().map(|e| (e.error.clone(), e.position.clone())).collect();
+        assert_eq!(kinds, vec![
+            (LexerErrorType::InvalidCharacter('$'), Position { line: 1, column: 10 }),
+            (LexerErrorType::UnterminatedString, Position { line: 2, column: 9 }),
+            (LexerErrorType::InvalidDigit { digit: '2', radix: 2 }, Position { line: 3, column: 13 }),
+            (LexerErrorType::InvalidCharacter('€'), Position { line: 4, column: 14 }),
+        ]);
+        assert_eq!(lexer.errors(), errors.as_slice());
+
+        // La chaine non fermee s'arrete a la fin de sa ligne: les lignes suivantes sont lues
+        assert!(!tokens.iter().any(|t| matches!(t.token_type, TokenType::ERROR(_))));
+        let names: Vec<&str> = tokens.iter()
+            .filter_map(|t| match &t.token_type {
+                TokenType::IDENTIFIER { name } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(names, vec!["a", "s", "b", "c"]);
+    }
+
+    #[test]
+    fn test_unterminated_string_resumes_at_end_of_line() {
+        let tokens: Vec<TokenType> = Lexer::new("x = 'abc\ny = 2\n", SyntaxMode::Indentation)
+            .tokenize()
+            .into_iter()
+            .map(|t| t.token_type)
+            .collect();
+        assert!(matches!(&tokens[2], TokenType::ERROR(e) if e.error == LexerErrorType::UnterminatedString));
+        assert_eq!(tokens[3..], [
+            TokenType::NEWLINE,
+            TokenType::IDENTIFIER { name: "y".into() },
+            TokenType::OPERATOR(Operators::EQUAL),
+            TokenType::INTEGER { value: BigInt::from(2), suffix: None },
+            TokenType::NEWLINE,
+            TokenType::EOF,
+        ]);
+    }
+
+    #[test]
+    fn test_unterminated_string_does_not_reach_next_line() {
+        let mut lexer = Lexer::new("let s = \"ouverte\nlet b = \"x\";", SyntaxMode::Braces);
+        let (tokens, errors) = lexer.tokenize_with_errors();
+
+        let kinds: Vec<(LexerErrorType, Position)> = errors.iter().map(|e| (e.error.clone(), e.position.clone())).collect();
+        assert_eq!(kinds, vec![(LexerErrorType::UnterminatedString, Position { line: 1, column: 9 })]);
+
+        // La chaine de la ligne 2 est lue normalement
+        let strings: Vec<(&str, usize)> = tokens.iter()
+            .filter_map(|t| match &t.token_type {
+                TokenType::STRING { value, .. } => Some((value.as_str(), t.span().line)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(strings, vec![("x", 2)]);
+        assert!(tokens.iter().any(|t| t.token_type == TokenType::IDENTIFIER { name: "b".into() }));
+    }
+
     #[test]
     fn test_mixed_tokens() {
         let input = r#"x = 3.14 + 2 * (5 - 1) # This is a comment"#;
 
         let expected_tokens = vec![
             TokenType::IDENTIFIER {
-                name: "x".to_string(),
+                name: "x".into(),
             },
             TokenType::OPERATOR(Operators::EQUAL),
-            TokenType::FLOAT { value: 3.14 },
+            TokenType::FLOAT { value: 3.14, suffix: None },
             TokenType::OPERATOR(Operators::PLUS),
             TokenType::INTEGER {
                 value: BigInt::from(2),
+                suffix: None,
             },
             TokenType::OPERATOR(Operators::STAR),
             TokenType::DELIMITER(Delimiters::LPAR),
             TokenType::INTEGER {
                 value: BigInt::from(5),
+                suffix: None,
             },
             TokenType::OPERATOR(Operators::MINUS),
             TokenType::INTEGER {
                 value: BigInt::from(1),
+                suffix: None,
             },
             TokenType::DELIMITER(Delimiters::RPAR),
             TokenType::COMMENT(" This is a comment".to_string()),
@@ -756,22 +1027,25 @@ def main():
 
         let expected_tokens = vec![
             TokenType::IDENTIFIER {
-                name: "x".to_string(),
+                name: "x".into(),
             },
             TokenType::OPERATOR(Operators::EQUAL),
-            TokenType::FLOAT { value: 3.14 },
+            TokenType::FLOAT { value: 3.14, suffix: None },
             TokenType::OPERATOR(Operators::PLUS),
             TokenType::INTEGER {
                 value: BigInt::from(2),
+                suffix: None,
             },
             TokenType::OPERATOR(Operators::STAR),
             TokenType::DELIMITER(Delimiters::LPAR),
             TokenType::INTEGER {
                 value: BigInt::from(5),
+                suffix: None,
             },
             TokenType::OPERATOR(Operators::MINUS),
             TokenType::INTEGER {
                 value: BigInt::from(1),
+                suffix: None,
             },
             TokenType::DELIMITER(Delimiters::RPAR),
             TokenType::COMMENT(" This is a comment".to_string()),
@@ -806,14 +1080,15 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::INTEGER {
-                value: BigInt::from(42)
+                value: BigInt::from(42),
+                suffix: None
             })
         );
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::OPERATOR(Operators::MINUS))
         );
-        assert_eq!(lexer.get_token(), Some(TokenType::FLOAT { value: 3.14 }));
+        assert_eq!(lexer.get_token(), Some(TokenType::FLOAT { value: 3.14, suffix: None }));
     }
 
     // Test pour les identifiants avec des underscores
@@ -823,13 +1098,13 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "my_variable".to_string()
+                name: "my_variable".into()
             })
         );
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "_private".to_string()
+                name: "_private".into()
             })
         );
     }
@@ -974,14 +1249,115 @@ def main():
 
     #[test]
     fn test_invalid_escape_sequence() {
-        let mut lexer = Lexer::new(r#""This is a bad escape: \q""#, SyntaxMode::Braces);
+        let mut lexer = Lexer::new(r#""This is a bad escape: \q" x"#, SyntaxMode::Braces);
+        match lexer.get_token() {
+            Some(TokenType::ERROR(error)) => {
+                assert_eq!(error.error, LexerErrorType::InvalidEscape("\\q".to_string()));
+                assert_eq!(error.position, Position { line: 1, column: 24 });
+            }
+            other => panic!("Expected invalid escape error, got {:?}", other),
+        }
+        // La chaine est lue jusqu'au bout malgre l'erreur
+        assert_eq!(lexer.get_token(), Some(TokenType::IDENTIFIER { name: "x".into() }));
+
+        for (source, escape) in [(r#""\x4""#, r"\x4"), (r#""\u{110000}""#, r"\u{110000}"),
+                                 (r#""\u41""#, r"\u"), (r#"b"\u{41}""#, r"\u"),
+                                 // \x80 a \xFF ne sont valides que dans les chaines d'octets
+                                 (r#""\xff""#, r"\xff"), (r#"'\x80'"#, r"\x80"), (r#"f"{x}\xC3""#, r"\xC3")] {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            match lexer.get_token() {
+                Some(TokenType::ERROR(error)) => assert_eq!(error.error, LexerErrorType::InvalidEscape(escape.to_string())),
+                other => panic!("Expected invalid escape error for {}, got {:?}", source, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unicode_and_hex_escapes() {
+        let mut lexer = Lexer::new(r#""\u{1F600}\x41\0" '\u{e9}'"#, SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::STRING {
-                value: "This is a bad escape: q".to_string(),
-                kind: StringKind::NORMAL
-            })
+            Some(TokenType::STRING { value: "\u{1F600}A\0".to_string(), kind: StringKind::NORMAL })
         );
+        assert_eq!(lexer.get_token(), Some(TokenType::CHAR { value: 'é' }));
+    }
+
+    #[test]
+    fn test_lifetimes_and_chars() {
+        let lifetime = |name: &str| TokenType::LIFETIME { name: name.to_string() };
+        let tokens: Vec<TokenType> = Lexer::new("fn f<'a, T: 'a + Clone>(x: &'a T, c: char) -> &'static str", SyntaxMode::Braces)
+            .tokenize()
+            .into_iter()
+            .map(|t| t.token_type)
+            .filter(|t| matches!(t, TokenType::LIFETIME { .. } | TokenType::CHAR { .. } | TokenType::STRING { .. }))
+            .collect();
+        assert_eq!(tokens, vec![lifetime("a"), lifetime("a"), lifetime("a"), lifetime("static")]);
+
+        // Un nom suivi d'une apostrophe, ou sans contexte de lifetime, reste un caractere ou une chaine
+        let mut lexer = Lexer::new("'a' 'ab' &'x' f('hello world', 'a b') x = 'static'", SyntaxMode::Braces);
+        let tokens: Vec<TokenType> = lexer.tokenize().into_iter().map(|t| t.token_type).collect();
+        assert!(!tokens.iter().any(|t| matches!(t, TokenType::LIFETIME { .. })));
+        assert_eq!(tokens[0], TokenType::CHAR { value: 'a' });
+        assert_eq!(tokens[1], TokenType::STRING { value: "ab".to_string(), kind: StringKind::NORMAL });
+        assert_eq!(tokens[3], TokenType::CHAR { value: 'x' });
+
+        // Un lifetime peut porter une borne, dans les parametres ou apres `where`
+        for (source, count) in [("fn f<'a, 'b: 'a>(x: &'b int)", 4), ("fn f<'a, 'b>(x: &'a int) where 'a: 'b, 'b: 'a {}", 7)] {
+            let tokens: Vec<TokenType> = Lexer::new(source, SyntaxMode::Braces).tokenize().into_iter().map(|t| t.token_type).collect();
+            assert!(!tokens.iter().any(|t| matches!(t, TokenType::CHAR { .. } | TokenType::STRING { .. } | TokenType::ERROR(_))), "{source}");
+            assert_eq!(tokens.iter().filter(|t| matches!(t, TokenType::LIFETIME { .. })).count(), count, "{source}");
+        }
+
+        // Une chaine de plusieurs mots apres un operateur n'est pas un lifetime
+        for source in ["let s = a + 'hello world';", "let s = &'hello world';", "let b = a < 'hello world';"] {
+            let tokens: Vec<TokenType> = Lexer::new(source, SyntaxMode::Braces).tokenize().into_iter().map(|t| t.token_type).collect();
+            assert!(!tokens.iter().any(|t| matches!(t, TokenType::LIFETIME { .. } | TokenType::ERROR(_))), "{source}");
+            assert!(tokens.contains(&TokenType::STRING { value: "hello world".to_string(), kind: StringKind::NORMAL }), "{source}");
+            assert_eq!(tokens[tokens.len() - 2], TokenType::DELIMITER(Delimiters::SEMICOLON), "{source}");
+        }
+
+        // Le texte du token garde l'apostrophe
+        let source = "&'é T";
+        let tokens = Lexer::new(source, SyntaxMode::Braces).tokenize();
+        assert_eq!(tokens[1].token_type, lifetime("é"));
+        assert_eq!(tokens[1].text(source), "'é");
+    }
+
+    #[test]
+    fn test_raw_and_byte_strings() {
+        let mut lexer = Lexer::new(r#"r"C:\new\"dir" b"ok\x00\xff" rb"\x" r'\n'"#, SyntaxMode::Braces);
+        assert_eq!(
+            lexer.get_token(),
+            Some(TokenType::STRING { value: r#"C:\new\"dir"#.to_string(), kind: StringKind::RAW })
+        );
+        assert_eq!(lexer.get_token(), Some(TokenType::BYTES { value: vec![b'o', b'k', 0, 255] }));
+        assert_eq!(lexer.get_token(), Some(TokenType::BYTES { value: b"\\x".to_vec() }));
+        assert_eq!(
+            lexer.get_token(),
+            Some(TokenType::STRING { value: r"\n".to_string(), kind: StringKind::RAW })
+        );
+
+        // Un octet doit etre ASCII
+        let mut lexer = Lexer::new(r#"b"é""#, SyntaxMode::Braces);
+        assert!(matches!(lexer.get_token(), Some(TokenType::ERROR(error)) if error.error == LexerErrorType::InvalidCharacter('é')));
+    }
+
+    #[test]
+    fn test_triple_quoted_strings() {
+        let source = "let s = \"\"\"\n    hello\n      world\n\n    \"\"\"\nlet t = '''a \"quote\" \\\n   b'''";
+        let tokens: Vec<TokenType> = Lexer::new(source, SyntaxMode::Indentation)
+            .tokenize()
+            .into_iter()
+            .map(|token| token.token_type)
+            .filter(|token_type| matches!(token_type, TokenType::STRING { .. } | TokenType::ERROR(_)))
+            .collect();
+        assert_eq!(tokens, vec![
+            TokenType::STRING { value: "hello\n  world\n\n".to_string(), kind: StringKind::NORMAL },
+            TokenType::STRING { value: "a \"quote\" b".to_string(), kind: StringKind::NORMAL },
+        ]);
+
+        let mut lexer = Lexer::new("\"\"\"never closed\"\"", SyntaxMode::Braces);
+        assert!(matches!(lexer.get_token(), Some(TokenType::ERROR(error)) if error.error == LexerErrorType::UnterminatedString));
     }
 
     #[test]
@@ -990,7 +1366,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "a".to_string()
+                name: "a".into()
             })
         );
         assert_eq!(
@@ -1004,7 +1380,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "b".to_string()
+                name: "b".into()
             })
         );
         assert_eq!(
@@ -1018,7 +1394,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "c".to_string()
+                name: "c".into()
             })
         );
         assert_eq!(
@@ -1028,7 +1404,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "d".to_string()
+                name: "d".into()
             })
         );
         assert_eq!(
@@ -1042,7 +1418,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "e".to_string()
+                name: "e".into()
             })
         );
         assert_eq!(
@@ -1053,14 +1429,14 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "f".to_string()
+                name: "f".into()
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::OR)));
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "g".to_string()
+                name: "g".into()
             })
         );
     }
@@ -1074,15 +1450,22 @@ def main():
 
     #[test]
     fn test_large_numbers() {
-        let mut lexer = Lexer::new("123456789012345678901234567890", SyntaxMode::Braces);
-        if let Some(TokenType::ERROR(error)) = lexer.get_token() {
-            assert_eq!(
-                error.error,
-                LexerErrorType::InvalidInteger("123456789012345678901234567890".to_string())
-            );
-        } else {
-            panic!("Expected an ERROR token for very large integer");
-        }
+        // Les entiers sont lus sans limite de taille
+        let mut lexer = Lexer::new("123456789012345678901234567890 0xFFFFFFFFFFFFFFFFFFFF", SyntaxMode::Braces);
+        assert_eq!(
+            lexer.get_token(),
+            Some(TokenType::INTEGER {
+                value: "123456789012345678901234567890".parse::<BigInt>().unwrap(),
+                suffix: None
+            })
+        );
+        assert_eq!(
+            lexer.get_token(),
+            Some(TokenType::INTEGER {
+                value: (BigInt::from(1) << 80) - 1,
+                suffix: None
+            })
+        );
     }
 
     #[test]
@@ -1102,11 +1485,11 @@ def main():
         let mut lexer = Lexer::new("0x1A3F 0Xb4", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0x1A3F })
+            Some(TokenType::INTEGER { value: BigInt::from(0x1A3F), suffix: None })
         );
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0xB4 })
+            Some(TokenType::INTEGER { value: BigInt::from(0xB4), suffix: None })
         );
     }
 
@@ -1128,7 +1511,7 @@ def main():
         let mut lexer = Lexer::new("0x1A3F", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0x1A3F })
+            Some(TokenType::INTEGER { value: BigInt::from(0x1A3F), suffix: None })
         );
     }
 
@@ -1137,7 +1520,7 @@ def main():
         let mut lexer = Lexer::new("0X1A3F", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0x1A3F })
+            Some(TokenType::INTEGER { value: BigInt::from(0x1A3F), suffix: None })
         );
     }
 
@@ -1146,7 +1529,7 @@ def main():
         let mut lexer = Lexer::new("0xaBcD", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0xABCD })
+            Some(TokenType::INTEGER { value: BigInt::from(0xABCD), suffix: None })
         );
     }
 
@@ -1155,18 +1538,18 @@ def main():
         let mut lexer = Lexer::new("0x1A3F 0Xb4", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0x1A3F })
+            Some(TokenType::INTEGER { value: BigInt::from(0x1A3F), suffix: None })
         );
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0xB4 })
+            Some(TokenType::INTEGER { value: BigInt::from(0xB4), suffix: None })
         );
     }
 
     #[test]
     fn test_valid_hexadecimal_zero() {
         let mut lexer = Lexer::new("0x0", SyntaxMode::Braces);
-        assert_eq!(lexer.get_token(), Some(TokenType::HEXADECIMAL { value: 0 }));
+        assert_eq!(lexer.get_token(), Some(TokenType::INTEGER { value: BigInt::from(0), suffix: None }));
     }
 
     #[test]
@@ -1174,8 +1557,9 @@ def main():
         let mut lexer = Lexer::new("0xFFFFFFFFFFFFFFFF", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL {
-                value: 0xFFFFFFFFFFFFFFFF
+            Some(TokenType::INTEGER {
+                value: BigInt::from(0xFFFFFFFFFFFFFFFFu64),
+                suffix: None
             })
         );
     }
@@ -1185,7 +1569,7 @@ def main():
         let mut lexer = Lexer::new("0xABC def", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0xABC })
+            Some(TokenType::INTEGER { value: BigInt::from(0xABC), suffix: None })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::KEYWORD(Keywords::DEF)));
     }
@@ -1195,15 +1579,15 @@ def main():
         let mut lexer = Lexer::new("0x123 0x456 0x789", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0x123 })
+            Some(TokenType::INTEGER { value: BigInt::from(0x123), suffix: None })
         );
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0x456 })
+            Some(TokenType::INTEGER { value: BigInt::from(0x456), suffix: None })
         );
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0x789 })
+            Some(TokenType::INTEGER { value: BigInt::from(0x789), suffix: None })
         );
     }
 
@@ -1214,7 +1598,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "a".to_string()
+                name: "a".into()
             })
         );
         assert_eq!(
@@ -1224,14 +1608,15 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::INTEGER {
-                value: BigInt::from(1)
+                value: BigInt::from(1),
+                suffix: None
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::AND)));
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "b".to_string()
+                name: "b".into()
             })
         );
         assert_eq!(
@@ -1241,14 +1626,14 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "c".to_string()
+                name: "c".into()
             })
         );
         assert_eq!(lexer.get_token(), Some(TokenType::OPERATOR(Operators::OR)));
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "d".to_string()
+                name: "d".into()
             })
         );
         assert_eq!(
@@ -1258,7 +1643,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "e".to_string()
+                name: "e".into()
             })
         );
     }
@@ -1269,26 +1654,83 @@ def main():
     fn test_invalid_number() {
         let mut lexer = Lexer::new("123a", SyntaxMode::Braces);
 
-        // Le premier token devrait être un entier valide
-        assert_eq!(
-            lexer.get_token(),
-            Some(TokenType::INTEGER {
-                value: BigInt::from(123)
-            })
-        );
-
-        // Le deuxième token devrait être un identifiant
-        assert_eq!(
-            lexer.get_token(),
-            Some(TokenType::IDENTIFIER {
-                name: "a".to_string()
-            })
-        );
+        // Une lettre collee au nombre est lue comme un suffixe, ici invalide
+        match lexer.get_token() {
+            Some(TokenType::ERROR(error)) => {
+                assert_eq!(error.error, LexerErrorType::InvalidNumberSuffix("a".to_string()));
+                assert_eq!(error.position, Position { line: 1, column: 4 });
+            }
+            other => panic!("Expected an ERROR token for 123a, got {:?}", other),
+        }
 
         // Il ne devrait plus y avoir de tokens
         assert_eq!(lexer.get_token(), Some(TokenType::EOF));
     }
 
+    #[test]
+    fn test_numeric_literal_grammar() {
+        let mut lexer = Lexer::new("1_000_000 0b1010 0o17 0xFF_u8 1e-9 2.5E3 10i32 2.5f32 7f64 1..2", SyntaxMode::Braces);
+        let expected = [
+            TokenType::INTEGER { value: BigInt::from(1_000_000), suffix: None },
+            TokenType::INTEGER { value: BigInt::from(0b1010), suffix: None },
+            TokenType::INTEGER { value: BigInt::from(0o17), suffix: None },
+            TokenType::INTEGER { value: BigInt::from(0xFF), suffix: Some(NumberSuffix::U8) },
+            TokenType::FLOAT { value: 1e-9, suffix: None },
+            TokenType::FLOAT { value: 2.5e3, suffix: None },
+            TokenType::INTEGER { value: BigInt::from(10), suffix: Some(NumberSuffix::I32) },
+            TokenType::FLOAT { value: 2.5, suffix: Some(NumberSuffix::F32) },
+            TokenType::FLOAT { value: 7.0, suffix: Some(NumberSuffix::F64) },
+            TokenType::INTEGER { value: BigInt::from(1), suffix: None },
+            TokenType::OPERATOR(Operators::DOTDOT),
+            TokenType::INTEGER { value: BigInt::from(2), suffix: None },
+        ];
+        for token in expected {
+            assert_eq!(lexer.get_token(), Some(token));
+        }
+    }
+
+    #[test]
+    fn test_imaginary_literals() {
+        let mut lexer = Lexer::new("3j 2.5J 1_0e-1j 4 j", SyntaxMode::Braces);
+        let expected = [
+            TokenType::IMAGINARY { value: 3.0 },
+            TokenType::IMAGINARY { value: 2.5 },
+            TokenType::IMAGINARY { value: 1.0 },
+            TokenType::INTEGER { value: BigInt::from(4), suffix: None },
+            TokenType::IDENTIFIER { name: "j".into() },
+        ];
+        for token in expected {
+            assert_eq!(lexer.get_token(), Some(token));
+        }
+    }
+
+    #[test]
+    fn test_malformed_numeric_literals() {
+        let cases = [
+            ("0b102", LexerErrorType::InvalidDigit { digit: '2', radix: 2 }, 5),
+            ("0o78", LexerErrorType::InvalidDigit { digit: '8', radix: 8 }, 4),
+            ("1__0", LexerErrorType::InvalidNumberSeparator("1__".to_string()), 3),
+            ("10_", LexerErrorType::InvalidNumberSeparator("10_".to_string()), 4),
+            ("0x", LexerErrorType::InvalidHexadecimal("0x".to_string()), 3),
+            ("0b", LexerErrorType::InvalidInteger("0b".to_string()), 3),
+            ("1e+", LexerErrorType::InvalidFloat("1e".to_string()), 2),
+            ("10i33", LexerErrorType::InvalidNumberSuffix("i33".to_string()), 3),
+            ("2.5u8", LexerErrorType::InvalidNumberSuffix("u8".to_string()), 4),
+            ("0b1f32", LexerErrorType::InvalidNumberSuffix("f32".to_string()), 4),
+            ("0x1j", LexerErrorType::InvalidNumberSuffix("j".to_string()), 4),
+        ];
+        for (source, expected, column) in cases {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            match lexer.get_token() {
+                Some(TokenType::ERROR(error)) => {
+                    assert_eq!(error.error, expected, "{}", source);
+                    assert_eq!(error.position.column, column, "{}", source);
+                }
+                other => panic!("Expected an ERROR token for {}, got {:?}", source, other),
+            }
+        }
+    }
+
     //Test pour un float invalide a  revoir
     // #[test]
     // fn test_invalid_float() {
@@ -1302,7 +1744,7 @@ def main():
     //     );
     // }
 
-    // Test pour une chaîne non terminée
+    // Test pour une chaîne non terminée (l'erreur est sur le guillemet ouvrant)
     #[test]
     fn test_unterminated_string() {
         let mut lexer = Lexer::new("\"Hello, world", SyntaxMode::Braces);
@@ -1311,7 +1753,7 @@ def main():
             Some(TokenType::ERROR(LexerError::unterminated_string(
                 Position {
                     line: 1,
-                    column: 14
+                    column: 1
                 }
             )))
         );
@@ -1352,12 +1794,12 @@ def main():
         let mut lexer = Lexer::new("0x1FAb identifier", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
-            Some(TokenType::HEXADECIMAL { value: 0x1FAb })
+            Some(TokenType::INTEGER { value: BigInt::from(0x1FAb), suffix: None })
         );
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "identifier".to_string()
+                name: "identifier".into()
             })
         );
     }
@@ -1365,7 +1807,8 @@ def main():
     // Test pour une chaîne avec un saut de ligne non échappé
     #[test]
     fn test_multiline_string() {
-        let mut lexer = Lexer::new("\"Hello\nWorld\"", SyntaxMode::Braces);
+        // Seule une chaine triple peut contenir un saut de ligne
+        let mut lexer = Lexer::new("\"\"\"Hello\nWorld\"\"\"", SyntaxMode::Braces);
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::STRING {
@@ -1373,6 +1816,12 @@ def main():
                 kind: StringKind::NORMAL
             })
         );
+
+        let mut lexer = Lexer::new("\"Hello\nWorld\"", SyntaxMode::Braces);
+        assert!(matches!(
+            lexer.get_token(),
+            Some(TokenType::ERROR(e)) if e.error == LexerErrorType::UnterminatedString && e.position == Position { line: 1, column: 1 }
+        ));
     }
 
     // Test pour un commentaire multi-ligne non terminé
@@ -1393,6 +1842,63 @@ def main():
         );
     }
 
+    #[test]
+    fn test_unicode_identifiers() {
+        let mut lexer = Lexer::new("déjà_vu π 変数 _x1 if", SyntaxMode::Braces);
+        for name in ["déjà_vu", "π", "変数", "_x1"] {
+            assert_eq!(lexer.get_token(), Some(TokenType::IDENTIFIER { name: name.into() }));
+        }
+        assert_eq!(lexer.get_token(), Some(TokenType::KEYWORD(Keywords::IF)));
+        assert!(lexer.warnings().is_empty());
+
+        // `cafe` + accent combinant (NFD) et `café` precompose (NFC) sont le meme nom
+        let mut lexer = Lexer::new("cafe\u{301} caf\u{e9}", SyntaxMode::Braces);
+        let decomposed = lexer.get_token();
+        assert_eq!(decomposed, Some(TokenType::IDENTIFIER { name: "caf\u{e9}".into() }));
+        assert_eq!(lexer.get_token(), decomposed);
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        for (source, ch) in [("a € b", '€'), ("a\u{200b}b", '\u{200b}'), ("x²", '²'), ("$", '$')] {
+            let tokens: Vec<TokenType> = Lexer::new(source, SyntaxMode::Braces)
+                .tokenize()
+                .into_iter()
+                .map(|token| token.token_type)
+                .collect();
+            let error = tokens.iter().find_map(|token| match token {
+                TokenType::ERROR(error) => Some(error),
+                _ => None,
+            });
+            match error {
+                Some(error) => {
+                    assert_eq!(error.error, LexerErrorType::InvalidCharacter(ch), "{}", source);
+                    assert!(error.message.contains(&format!("U+{:04X}", ch as u32)), "{}", error.message);
+                }
+                None => panic!("Expected an InvalidCharacter error for {:?}, got {:?}", source, tokens),
+            }
+        }
+    }
+
+    #[test]
+    fn test_confusable_identifier_warning() {
+        // `а` cyrillique au milieu d'un nom latin
+        let mut lexer = Lexer::new("let p\u{430}th = 1", SyntaxMode::Braces);
+        lexer.tokenize();
+        assert_eq!(lexer.warnings().len(), 1);
+        let warning = &lexer.warnings()[0];
+        assert_eq!(warning.error, LexerErrorType::ConfusableCharacter { found: '\u{430}', expected: 'a' });
+        assert_eq!(warning.position, Position { line: 1, column: 5 });
+
+        // Un nom cyrillique qui se lit comme de l'ASCII est signale, un mot russe ordinaire non
+        let mut lexer = Lexer::new("\u{440}\u{43e}\u{440}", SyntaxMode::Braces);
+        lexer.tokenize();
+        assert_eq!(lexer.warnings().len(), 1);
+        let mut lexer = Lexer::new("\u{441}\u{43e}\u{43d} \u{3c0}", SyntaxMode::Braces);
+        lexer.tokenize();
+        assert!(lexer.warnings().is_empty());
+    }
+
     #[test]
     fn test_unknown_operator() {
         let mut lexer = Lexer::new("a $$ b", SyntaxMode::Braces);
@@ -1401,14 +1907,14 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "a".to_string()
+                name: "a".into()
             })
         );
 
         // Les deux prochains tokens devraient être des erreurs pour des opérateurs inconnus
         for _ in 0..2 {
             if let Some(TokenType::ERROR(error)) = lexer.get_token() {
-                assert_eq!(error.error, LexerErrorType::InvalidToken("$".to_string()));
+                assert_eq!(error.error, LexerErrorType::InvalidCharacter('$'));
                 // Nous ne vérifions pas la position exacte ici
             } else {
                 panic!("Expected an ERROR token for unknown operator");
@@ -1419,7 +1925,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "b".to_string()
+                name: "b".into()
             })
         );
 
@@ -1504,19 +2010,11 @@ def main():
     fn test_lexer_robustness_very_long_number() {
         let very_long_number = "9".repeat(1000);
         let mut lexer = Lexer::new(&very_long_number, SyntaxMode::Braces);
-        if let Some(TokenType::ERROR(error)) = lexer.get_token() {
-            let expected_error = LexerError::invalid_integer(
-                &very_long_number,
-                Position {
-                    line: 1,
-                    column: 1001,
-                },
-            );
-            assert_eq!(error.error, expected_error.error);
-            assert_eq!(error.message, expected_error.message);
-            assert_eq!(error.position, expected_error.position);
-        } else {
-            panic!("Expected an ERROR token for very long number");
+        match lexer.get_token() {
+            Some(TokenType::INTEGER { value, suffix: None }) => {
+                assert_eq!(value, very_long_number.parse::<BigInt>().unwrap());
+            }
+            other => panic!("Expected a BigInt INTEGER token for very long number, got {:?}", other),
         }
     }
 
@@ -1534,7 +2032,8 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::INTEGER {
-                value: BigInt::from(42)
+                value: BigInt::from(42),
+                suffix: None
             })
         );
         for _ in 0..1000 {
@@ -1555,7 +2054,7 @@ def main():
         assert_eq!(
             lexer.get_token(),
             Some(TokenType::IDENTIFIER {
-                name: "x".to_string()
+                name: "x".into()
             })
         );
         assert_eq!(
@@ -1574,6 +2073,230 @@ def main():
         // Vérifie qu'il n'y a plus de tokens
         assert_eq!(lexer.get_token(), Some(TokenType::EOF));
     }
+
+    // Tests pour la detection du mode syntaxique
+    #[test]
+    fn test_syntax_mode_pragma() {
+        use punk::lexer::syntax_mode::detect_syntax_mode;
+
+        let source = "#Syntaxe_mode = Indentation\nlet x = 5\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Indentation));
+
+        let source = "// header\n# syntax_mode = braces\nlet x = 5;\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Braces));
+
+        // Le pragma doit preceder le code
+        let source = "let x = 5\n#Syntaxe_mode = Indentation\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Braces));
+
+        let error = detect_syntax_mode("#Syntaxe_mode = Tabs\n").unwrap_err();
+        assert_eq!(error.error, LexerErrorType::InvalidPragma("Tabs".to_string()));
+        assert_eq!(error.position, Position { line: 1, column: 17 });
+    }
+
+    #[test]
+    fn test_syntax_mode_heuristic() {
+        use punk::lexer::syntax_mode::detect_syntax_mode;
+
+        let source = "fn add(a: int, b: int) -> int:\n    return a + b\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Indentation));
+
+        let source = "fn add<'a>(a: &'a int) -> int {\n    return a\n}\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Braces));
+
+        // ':' dans une chaine ou un commentaire n'ouvre pas de bloc
+        let source = "if x > 0 {\n    print(\"a:\") // b:\n}\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Braces));
+
+        let mut lexer = Lexer::auto("while x > 0:\n    x = x - 1\n").unwrap();
+        assert_eq!(lexer.syntax_mode(), SyntaxMode::Indentation);
+        assert!(lexer.tokenize().iter().any(|t| t.token_type == TokenType::INDENT));
+    }
+
+    #[test]
+    fn test_syntax_mode_mixed_blocks() {
+        use punk::lexer::syntax_mode::detect_syntax_mode;
+
+        let source = "fn a() {\n    return 1\n}\nfn b():\n    return 2\n";
+        let error = detect_syntax_mode(source).unwrap_err();
+        assert_eq!(error.error, LexerErrorType::MixedSyntaxMode);
+        assert_eq!(error.position.line, 4);
+
+        // Le pragma decide seul, sans heuristique
+        let source = "#Syntaxe_mode = Braces\n".to_string() + source;
+        assert_eq!(Lexer::auto(&source).unwrap().syntax_mode(), SyntaxMode::Braces);
+    }
+
+    #[test]
+    fn test_syntax_mode_struct_braces_are_not_blocks() {
+        use punk::lexer::syntax_mode::detect_syntax_mode;
+
+        let source = "#Syntaxe_mode = Indentation\nstruct Point {x: int, y: int}\nenum E {A, B}\nfn f():\n    return 1\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Indentation));
+
+        // Sans pragma non plus, les accolades d'une declaration n'ouvrent pas de bloc
+        let source = "pub struct Point {\n    x: int\n}\npub fn f():\n    return 1\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Indentation));
+
+        let source = "pub fn f() {\n    return 1\n}\n";
+        assert_eq!(detect_syntax_mode(source), Ok(SyntaxMode::Braces));
+    }
+
+    #[test]
+    fn test_french_keywords() {
+        use punk::lexer::keywords::KeywordSet;
+
+        let english: Vec<TokenType> = Lexer::new("fn f() { while x { if y { return z } else { break } } }", SyntaxMode::Braces)
+            .tokenize()
+            .into_iter()
+            .map(|t| t.token_type)
+            .collect();
+        let french: Vec<TokenType> = Lexer::new("fonction f() { tantque x { si y { retourner z } sinon { arreter } } }", SyntaxMode::Braces)
+            .with_keyword_set(KeywordSet::French)
+            .tokenize()
+            .into_iter()
+            .map(|t| t.token_type)
+            .collect();
+        assert_eq!(english, french);
+
+        // Les mots cles de l'autre dictionnaire sont des identifiants ordinaires
+        let mut lexer = Lexer::new("if si", SyntaxMode::Braces).with_keyword_set(KeywordSet::French);
+        assert_eq!(lexer.get_token(), Some(TokenType::IDENTIFIER { name: "if".into() }));
+        assert_eq!(lexer.get_token(), Some(TokenType::KEYWORD(Keywords::IF)));
+        assert_eq!(KeywordSet::French.spelling(&Keywords::INT), "entier");
+    }
+
+    #[test]
+    fn test_keyword_set_pragma() {
+        use punk::lexer::keywords::{detect_keyword_set, KeywordSet};
+
+        let mut lexer = Lexer::auto("#mots_cles = francais\nsi x:\n    y = vrai\n").unwrap();
+        assert_eq!(lexer.keyword_set(), KeywordSet::French);
+        assert_eq!(lexer.syntax_mode(), SyntaxMode::Indentation);
+        assert!(lexer.tokenize().iter().any(|t| t.token_type == TokenType::KEYWORD(Keywords::TRUE)));
+
+        assert_eq!(detect_keyword_set("# keywords = en\nlet x = 1\n"), Ok(KeywordSet::English));
+        assert_eq!(detect_keyword_set("let x = 1\n"), Ok(KeywordSet::English));
+        let error = detect_keyword_set("#mots_cles = latin\n").unwrap_err();
+        assert_eq!(error.error, LexerErrorType::InvalidPragma("latin".to_string()));
+        assert_eq!(error.position, Position { line: 1, column: 14 });
+    }
+
+    #[test]
+    fn test_translate_keywords() {
+        use punk::lexer::keywords::{translate_keywords, KeywordSet};
+
+        let english = "fn f(x: int) -> bool:\n    # if et return restent dans les commentaires\n    if x > 0:\n        return \"if\" == \"if\"\n    return false\n";
+        let french = translate_keywords(english, SyntaxMode::Indentation, KeywordSet::English, KeywordSet::French).unwrap();
+        assert_eq!(french, "#mots_cles = francais\nfonction f(x: entier) -> booleen:\n    # if et return restent dans les commentaires\n    si x > 0:\n        retourner \"if\" == \"if\"\n    retourner faux\n");
+
+        let back = translate_keywords(&french, SyntaxMode::Indentation, KeywordSet::French, KeywordSet::English).unwrap();
+        assert_eq!(back, "#mots_cles = english\n".to_string() + english);
+
+        // `si` est un identifiant en anglais mais un mot cle en francais
+        let error = translate_keywords("let si = 1;", SyntaxMode::Braces, KeywordSet::English, KeywordSet::French).unwrap_err();
+        assert_eq!(error.error, LexerErrorType::KeywordCollision("si".to_string()));
+        assert_eq!(error.position, Position { line: 1, column: 5 });
+
+        // Les mots cles des trous de f-strings sont traduits, pas le texte autour
+        let french = translate_keywords("let s = f\"not {not x} {f'{true}'}\";", SyntaxMode::Braces, KeywordSet::English, KeywordSet::French).unwrap();
+        assert_eq!(french, "#mots_cles = francais\nsoit s = f\"not {non x} {f'{vrai}'}\";");
+
+        let error = translate_keywords("let a = 1;\nlet s = f\"{si}\";", SyntaxMode::Braces, KeywordSet::English, KeywordSet::French).unwrap_err();
+        assert_eq!(error.position, Position { line: 2, column: 12 });
+    }
+
+    #[test]
+    fn test_lex_format_string() {
+        use punk::lexer::fstring::split_format_string;
+        use punk::lexer::tok::FStringPart;
+        use punk::parser::ast::{FileId, Span};
+
+        let mut lexer = Lexer::new(r#"f"Hello {name}, {x + 1:>8.2} {{ok}}" f"#, SyntaxMode::Braces);
+        let expected = vec![
+            FStringPart::Literal("Hello ".to_string()),
+            FStringPart::Expression { source: "name".to_string(), format_spec: None, span: Span::new(FileId::default(), 9, 13, 1, 10) },
+            FStringPart::Literal(", ".to_string()),
+            FStringPart::Expression { source: "x + 1".to_string(), format_spec: Some(">8.2".to_string()), span: Span::new(FileId::default(), 17, 22, 1, 18) },
+            FStringPart::Literal(" {ok}".to_string()),
+        ];
+        // Les morceaux decoupes par le lexer sont portes par le token
+        assert_eq!(
+            lexer.get_token(),
+            Some(TokenType::STRING {
+                value: "Hello {name}, {x + 1:>8.2} {{ok}}".to_string(),
+                kind: StringKind::FORMATTED(expected),
+            })
+        );
+        // `f` seul reste un identifiant
+        assert_eq!(lexer.get_token(), Some(TokenType::IDENTIFIER { name: "f".into() }));
+
+        // Hors du lexer, les spans sont relatifs au contenu
+        let parts = split_format_string("Hello {name}", Position { line: 1, column: 1 }, false).unwrap();
+        assert_eq!(parts[1], FStringPart::Expression { source: "name".to_string(), format_spec: None, span: Span::new(FileId::default(), 7, 11, 1, 8) });
+
+        // `::` et les accolades imbriquees appartiennent a l'expression
+        let parts = split_format_string("{Color::Red} {p[{1: 2}[1]]}", Position { line: 1, column: 1 }, false).unwrap();
+        assert!(matches!(&parts[0], FStringPart::Expression { source, .. } if source == "Color::Red"));
+        assert!(matches!(&parts[2], FStringPart::Expression { source, span, .. } if source == "p[{1: 2}[1]]" && span.start == 14));
+    }
+
+    #[test]
+    fn test_lex_format_string_spans_in_file() {
+        use punk::lexer::tok::FStringPart;
+        use punk::parser::ast::Span;
+
+        let holes = |source: &str| -> Vec<(String, Span)> {
+            let tokens = Lexer::new(source, SyntaxMode::Braces).tokenize();
+            let Some(TokenType::STRING { kind: StringKind::FORMATTED(parts), .. }) = tokens.into_iter().map(|t| t.token_type).find(|t| matches!(t, TokenType::STRING { .. })) else {
+                panic!("f-string attendue dans {}", source);
+            };
+            parts.into_iter().filter_map(|part| match part {
+                FStringPart::Expression { source, span, .. } => Some((source, span)),
+                FStringPart::Literal(_) => None,
+            }).collect()
+        };
+
+        // Les echappements avant l'expression ne decalent pas sa position
+        let source = "let s = f\"\\t\\u{e9} { total }\";";
+        let found = holes(source);
+        assert_eq!(found.len(), 1, "\\u{{...}} n'ouvre pas d'expression");
+        let (name, span) = &found[0];
+        assert_eq!(name, "total");
+        assert_eq!(&source[span.start..span.end], "total");
+        assert_eq!((span.line, span.column), (1, 22));
+
+        // Chaine triple: l'indentation retiree n'est pas comptee
+        let source = "let s = f\"\"\"\n    a\n    b {x}\n    \"\"\";";
+        let (name, span) = &holes(source)[0];
+        assert_eq!(name, "x");
+        assert_eq!(&source[span.start..span.end], "x");
+        assert_eq!((span.line, span.column), (3, 8));
+    }
+
+    #[test]
+    fn test_lex_format_string_errors() {
+        use punk::lexer_error::FStringErrorType;
+
+        let cases = [
+            (r#"f"a {b""#, FStringErrorType::UnclosedLbrace),
+            (r#"f"a } b""#, FStringErrorType::UnopenedRbrace),
+            (r#"f"{ }""#, FStringErrorType::EmptyExpression),
+            (r#"f"{x:>>>}""#, FStringErrorType::InvalidFormatSpec(">>>".to_string())),
+            (r#"f"{x:.f}""#, FStringErrorType::InvalidFormatSpec(".f".to_string())),
+            (r#"f"{x:{w}}""#, FStringErrorType::InvalidFormatSpec("{".to_string())),
+        ];
+        for (source, expected) in cases {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            match lexer.get_token() {
+                Some(TokenType::ERROR(error)) => {
+                    assert_eq!(error.error, LexerErrorType::FStringError(expected), "{}", source);
+                    assert_eq!(error.position, Position { line: 1, column: 1 });
+                }
+                other => panic!("Expected f-string error for {}, got {:?}", source, other),
+            }
+        }
+    }
 }
 
 
@@ -53,7 +53,167 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_prefixed_numeric_literals() {
+            use punk::parser::ast::Literal;
+
+            for (input, expected) in [("0x1F", 31), ("0b1010", 10), ("0o17", 15), ("1_000", 1000), ("10i32", 10)] {
+                let mut parser = create_parser(input, SyntaxMode::Braces);
+                match parser.parse_expression(0) {
+                    Ok(Expression::Literal(Literal::Integer { value, .. }, _)) => assert_eq!(value, expected.into()),
+                    other => panic!("Expected integer literal for {}, got {:?}", input, other),
+                }
+            }
+            let mut parser = create_parser("0xFF + 2.5e3", SyntaxMode::Braces);
+            assert!(parser.parse_expression(0).is_ok());
+        }
+
+        #[test]
+        fn test_integer_literal_suffix_and_size() {
+            use punk::lexer::tok::NumberSuffix;
+            use punk::parser::ast::Literal;
+
+            let mut parser = create_parser("340282366920938463463374607431768211456u8", SyntaxMode::Braces);
+            match parser.parse_expression(0) {
+                Ok(Expression::Literal(Literal::Integer { value, suffix }, _)) => {
+                    assert_eq!(value.to_string(), "340282366920938463463374607431768211456");
+                    assert_eq!(suffix, Some(NumberSuffix::U8));
+                }
+                other => panic!("Expected integer literal, got {:?}", other),
+            }
+            let mut parser = create_parser("let x: i8 = 5;", SyntaxMode::Braces);
+            assert!(parser.parse_statement().is_ok());
+        }
+
+        #[test]
+        fn test_imaginary_literal() {
+            use num_complex::Complex64;
+            use punk::parser::ast::Literal;
+
+            let mut parser = create_parser("2.5j", SyntaxMode::Braces);
+            match parser.parse_expression(0) {
+                Ok(Expression::Literal(Literal::Complex { value }, _)) => assert_eq!(value, Complex64::new(0.0, 2.5)),
+                other => panic!("Expected complex literal, got {:?}", other),
+            }
+            for input in ["let z = 1 + 2j;", "let z: complex = 1.5;", "let z: complex = 3j;"] {
+                let mut parser = create_parser(input, SyntaxMode::Braces);
+                assert!(parser.parse_statement().is_ok(), "{}", input);
+            }
+        }
+
+
+    }
+
+    mod precedence_tests {
+        use punk::parser::ast::Literal;
+        use super::*;
+
+        // Forme parenthesee de l'arbre: (Operator gauche droite)
+        fn tree(expr: &Expression) -> String {
+            match expr {
+                Expression::Identifier(name, _) => name.clone(),
+                Expression::Literal(Literal::Integer { value, .. }, _) => value.to_string(),
+                Expression::BinaryOperation(binary) => format!(
+                    "({:?} {} {})", binary.operator, tree(&binary.left), tree(&binary.right)
+                ),
+                Expression::UnaryOperation(unary) => format!("({:?} {})", unary.operator, tree(&unary.operand)),
+                Expression::ComparisonChain(chain) => {
+                    let mut text = format!("(Chain {}", tree(&chain.operands[0]));
+                    for (operator, operand) in chain.operators.iter().zip(&chain.operands[1..]) {
+                        text.push_str(&format!(" {:?} {}", operator, tree(operand)));
+                    }
+                    text + ")"
+                }
+                other => format!("{:?}", other),
+            }
+        }
+
+        fn parse(input: &str) -> String {
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            tree(&parser.parse_expression(0).unwrap())
+        }
+
+        #[test]
+        fn test_arithmetic_precedence() {
+            assert_eq!(parse("a * b + c"), "(Addition (Multiplication a b) c)");
+            assert_eq!(parse("a - b - c"), "(Substraction (Substraction a b) c)");
+            assert_eq!(parse("a + b @ c"), "(Addition a (MatrixMultiplication b c))");
+        }
+
+        #[test]
+        fn test_power_is_right_associative() {
+            assert_eq!(parse("2 ** 3 ** 2"), "(Power 2 (Power 3 2))");
+            assert_eq!(parse("a * b ** 2"), "(Multiplication a (Power b 2))");
+            assert_eq!(parse("-x ** 2"), "(Negative (Power x 2))");
+        }
+
+        #[test]
+        fn test_bitwise_precedence() {
+            assert_eq!(parse("a | b ^ c & d"), "(BitwiseOr a (BitwiseXor b (BitwiseAnd c d)))");
+            assert_eq!(parse("a << 1 + b"), "(ShiftLeft a (Addition 1 b))");
+            assert_eq!(parse("a & b == c"), "(EqualEqual (BitwiseAnd a b) c)");
+            assert_eq!(parse("~a & b"), "(BitwiseAnd (BitwiseNot a) b)");
+        }
+
+        #[test]
+        fn test_logical_precedence() {
+            assert_eq!(parse("a || b && c"), "(Or a (And b c))");
+            assert_eq!(parse("a or b and c"), "(Or a (And b c))");
+            assert_eq!(parse("a == b || c != d"), "(Or (EqualEqual a b) (NotEqual c d))");
+            assert_eq!(parse("not a == b and c"), "(And (LogicalNot (EqualEqual a b)) c)");
+        }
+
+        #[test]
+        fn test_membership_and_identity() {
+            assert_eq!(parse("x in items"), "(In x items)");
+            assert_eq!(parse("x not in items"), "(NotIn x items)");
+            assert_eq!(parse("x is y"), "(Is x y)");
+            assert_eq!(parse("x is not y"), "(IsNot x y)");
+            assert_eq!(parse("x + 1 in items and ok"), "(And (In (Addition x 1) items) ok)");
+        }
 
+        #[test]
+        fn test_chained_comparisons() {
+            assert_eq!(parse("a < b < c"), "(Chain a LessThan b LessThan c)");
+            assert_eq!(parse("0 <= x < n == m"), "(Chain 0 LesshanOrEqual x LessThan n EqualEqual m)");
+            assert_eq!(parse("a < b"), "(LessThan a b)");
+            assert_eq!(parse("(a < b) < c"), "(LessThan (LessThan a b) c)");
+
+            // L'operande du milieu n'est pas recopiee: f() n'est appele qu'une fois
+            let mut parser = create_parser("a < f() < c", SyntaxMode::Braces);
+            let expression = parser.parse_expression(0).unwrap();
+            assert_eq!(format!("{:?}", expression).matches("FunctionCall(").count(), 1);
+        }
+
+        #[test]
+        fn test_not_and_bitwise_not_in_let() {
+            use punk::parser::ast::{ASTNode, Declaration, Type};
+            let variable_type = |input: &str| {
+                let mut parser = create_parser(input, SyntaxMode::Braces);
+                match parser.parse_variable_declaration() {
+                    Ok(ASTNode::Declaration(Declaration::Variable(declaration))) => declaration.variable_type,
+                    other => panic!("Expected variable declaration, got {:?}", other),
+                }
+            };
+            assert_eq!(variable_type("let x = not true;"), Some(Type::Bool));
+            assert_eq!(variable_type("let m = ~5;"), Some(Type::Int));
+            assert_eq!(variable_type("let x = not flag;"), Some(Type::Infer));
+            assert_eq!(variable_type("let m = ~bits;"), Some(Type::Infer));
+
+            let mut parser = create_parser("let m = ~true;", SyntaxMode::Braces);
+            assert!(parser.parse_variable_declaration().is_err());
+        }
+
+        #[test]
+        fn test_range_is_lowest() {
+            let mut parser = create_parser("0..n + 1", SyntaxMode::Braces);
+            match parser.parse_expression(0).unwrap() {
+                Expression::RangeExpression(range) => {
+                    assert_eq!(tree(range.right.as_ref().unwrap()), "(Addition n 1)");
+                }
+                other => panic!("Expected RangeExpression, got {:?}", other),
+            }
+        }
     }
 
     mod try_except_statement_tests{
@@ -331,20 +491,20 @@ else :
 
         #[test]
         fn test_divers_variable_declarations_braces(){
-            let input = r#"let x = 10;let mut y:int = 3;const numb = 5;pub const x:int = 5;pub struct Point {x: int,y: int}pub struct Point {height: int,width: int}enum Color {x:int,y:float,z:str}pub enum Color {pub x:int,y:float,z:str}pub fn add(x: int, y: int) -> int {return x + y}pub fn add(x: int, y: int) -> int {let mut result = x + y;}"#;
+            let input = r#"let x = 10;let mut y:int = 3;const numb = 5;pub const x:int = 5;pub struct Point {x: int,y: int}pub struct Point {height: int,width: int}enum Color {Red,Green(int),Blue{z: str}}pub enum Color {pub Red = 1,Green,Blue}pub fn add(x: int, y: int) -> int {return x + y}pub fn add(x: int, y: int) -> int {let mut result = x + y;}"#;
 
             let mut parser = create_parser(input, SyntaxMode::Braces);
-            let result = parser.parse_program();
-            assert!(result.is_ok());
+            let (_, errors) = parser.parse_program();
+            assert!(errors.is_empty(), "{:?}", errors);
         }
 
 
         #[test]
         fn test_divers_variable_declarations_indent(){
-            let input = "let x = 10\nlet mut y:int = 3\nconst numb = 5\npub const x:int = 5\nstruct Point {x: int,y: int}pub struct Point {height: int,width: int} enum Color {x:int,y:float,z:str}pub enum Color {pub x:int,y:float,z:str}";
+            let input = "let x = 10\nlet mut y:int = 3\nconst numb = 5\npub const x:int = 5\nstruct Point {x: int,y: int}pub struct Point {height: int,width: int} enum Color {Red,Green(int),Blue{z: str}}pub enum Color {pub Red = 1,Green,Blue}";
             let mut parser = create_parser(input, SyntaxMode::Indentation);
-            let result = parser.parse_program();
-            assert!(result.is_ok());
+            let (_, errors) = parser.parse_program();
+            assert!(errors.is_empty(), "{:?}", errors);
         }
     }
 
@@ -413,6 +573,7 @@ else :
 
     mod lambda_tests{
         use super::*;
+        use punk::parser::ast::ASTNode;
 
         #[test]
         fn test_lambda_braces() {
@@ -425,16 +586,21 @@ else :
         }
 
 
-        // Test unitaire pour la fonction lambda n'est pas encore bien  implemente.
-        // Je vais y remedier plus tard
-
         #[test]
         fn test_lambda_indent() {
             let input = r#"lambda (x: int, y: int) -> int:{x + y}"#;
             let mut parser = create_parser(input, SyntaxMode::Indentation);
             // let result = parser.parse_lambda_expression();
-            let result = parser.parse_program();
-            assert!(result.is_ok());
+            let (program, errors) = parser.parse_program();
+            assert!(errors.is_empty(), "{errors:?}");
+            let ASTNode::Program(nodes) = program else { panic!("Program attendu") };
+            assert!(matches!(&nodes[..], [ASTNode::Expression(Expression::LambdaExpression(lambda))] if lambda.body.len() == 1));
+
+            // Apres `:`, une expression seule ou un bloc indente
+            for input in ["lambda (x: int) -> int: x + 1", "lambda (x: int) -> int:\n    let y = x + 1\n    return y\n"] {
+                let mut parser = create_parser(input, SyntaxMode::Indentation);
+                assert!(matches!(parser.parse_lambda_expression(), Ok(Expression::LambdaExpression(_))), "{input:?}");
+            }
     }
 
 
@@ -478,6 +644,7 @@ if x > 10:
 
     mod error_tests {
         use super::*;
+        use punk::parser::ast::{ASTNode, Declaration};
 
         #[test]
         fn test_syntax_errors() {
@@ -496,13 +663,98 @@ if x > 10:
 
         #[test]
         fn test_recovery() {
+            // Le parser ne voit pas les commentaires (filtres par le frontend)
             let input = r#"
-            let x = ; // Error
-            let y = 42; // Should parse correctly
+            let x = ;
+            let y = 42;
             "#;
             let mut parser = create_parser(input, SyntaxMode::Braces);
-            assert!(parser.parse_program().is_ok());
-            // Test error recovery
+            let (program, errors) = parser.parse_program();
+            assert_eq!(errors.len(), 1);
+            let ASTNode::Program(nodes) = program else { panic!("Program attendu") };
+            assert_eq!(nodes.len(), 2);
+            assert!(matches!(nodes[0], ASTNode::Error(_)));
+            assert!(matches!(nodes[1], ASTNode::Declaration(Declaration::Variable(_))));
+        }
+
+        #[test]
+        fn test_recovery_collects_every_error() {
+            let input = "let x = ;\nprint(x);\nfn f(x: ) { let a = 1; }\nlet y = 42;";
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            let (program, errors) = parser.parse_program();
+            assert_eq!(errors.len(), 2);
+            let ASTNode::Program(nodes) = program else { panic!("Program attendu") };
+            assert_eq!(nodes.len(), 4);
+            assert!(matches!(nodes[0], ASTNode::Error(_)));
+            assert!(matches!(nodes[1], ASTNode::Expression(Expression::FunctionCall(_))));
+            assert!(matches!(nodes[2], ASTNode::Error(_)));
+            assert!(matches!(nodes[3], ASTNode::Declaration(Declaration::Variable(_))));
+        }
+
+        #[test]
+        fn test_recovery_inside_block_braces() {
+            let input = "fn f() {\n    let a = ;\n    let b = 2;\n}\nlet z = 1;";
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            let (program, errors) = parser.parse_program();
+            assert_eq!(errors.len(), 1);
+            let ASTNode::Program(nodes) = program else { panic!("Program attendu") };
+            assert_eq!(nodes.len(), 2);
+            let ASTNode::Declaration(Declaration::Function(function)) = &nodes[0] else { panic!("fonction attendue") };
+            assert_eq!(function.body.len(), 2);
+            assert!(matches!(function.body[0], ASTNode::Error(_)));
+        }
+
+        #[test]
+        fn test_recovery_inside_block_indent() {
+            let input = "fn f():\n    let a =\n    let b = 2\nlet z = 1\n";
+            let mut parser = create_parser(input, SyntaxMode::Indentation);
+            let (program, errors) = parser.parse_program();
+            assert_eq!(errors.len(), 1);
+            let ASTNode::Program(nodes) = program else { panic!("Program attendu") };
+            assert_eq!(nodes.len(), 2);
+            let ASTNode::Declaration(Declaration::Function(function)) = &nodes[0] else { panic!("fonction attendue") };
+            assert_eq!(function.body.len(), 2);
+            assert!(matches!(function.body[0], ASTNode::Error(_)));
+            assert!(matches!(nodes[1], ASTNode::Declaration(Declaration::Variable(_))));
+        }
+
+        #[test]
+        fn test_nested_blocks_indent() {
+            use punk::parser::ast::Statement;
+
+            // Chaque bloc consomme son propre DEDENT, avec ou sans saut de ligne final
+            for input in ["fn f():\n    if x:\n        y = 1\nlet z = 1", "fn f():\n    if x:\n        return\nlet z = 1\n"] {
+                let mut parser = create_parser(input, SyntaxMode::Indentation);
+                let (program, errors) = parser.parse_program();
+                assert!(errors.is_empty(), "{input:?}: {errors:?}");
+                let ASTNode::Program(nodes) = program else { panic!("Program attendu") };
+                assert_eq!(nodes.len(), 2, "{input:?}");
+                assert!(matches!(nodes[1], ASTNode::Declaration(Declaration::Variable(_))));
+            }
+
+            // Une erreur dans le bloc interieur ne deplace pas la fin de la fonction
+            let input = "fn f():\n    if x:\n        y =\n        w = 2\n    return w\nlet z = 1";
+            let mut parser = create_parser(input, SyntaxMode::Indentation);
+            let (program, errors) = parser.parse_program();
+            assert_eq!(errors.len(), 1);
+            let ASTNode::Program(nodes) = program else { panic!("Program attendu") };
+            assert_eq!(nodes.len(), 2);
+            let ASTNode::Declaration(Declaration::Function(function)) = &nodes[0] else { panic!("fonction attendue") };
+            assert_eq!(function.body.len(), 2);
+            assert!(matches!(function.body[1], ASTNode::Statement(Statement::ReturnStatement(_))));
+            assert!(matches!(nodes[1], ASTNode::Declaration(Declaration::Variable(_))));
+        }
+
+        #[test]
+        fn test_recovery_unterminated_bracket() {
+            // Le parsing se termine meme si un `[` n'est jamais ferme
+            for input in ["let d = [1\nlet e = 2;", "fn f() { let d = [1, 2; }"] {
+                for mode in [SyntaxMode::Braces, SyntaxMode::Indentation] {
+                    let mut parser = create_parser(input, mode);
+                    let (_, errors) = parser.parse_program();
+                    assert!(!errors.is_empty(), "{input:?}");
+                }
+            }
         }
     }
 
@@ -526,7 +778,8 @@ if x > 10:
             }
             "#;
             let mut parser = create_parser(input, SyntaxMode::Braces);
-            assert!(parser.parse_program().is_ok());
+            let (_, errors) = parser.parse_program();
+            assert!(errors.is_empty(), "{:?}", errors);
         }
 
         #[test]
@@ -552,7 +805,18 @@ match x:
 
 
             let mut parser = create_parser(input, SyntaxMode::Indentation);
-            assert!(parser.parse_program().is_ok());
+            let (_, errors) = parser.parse_program();
+            assert!(errors.is_empty(), "{:?}", errors);
+        }
+
+        #[test]
+        fn test_line_continuation_indent() {
+            let input = "let total = add(1,\n        2)\nlet point = {\n    \"x\": 1,\n    \"y\": [2,\n          3],\n}\nlet ok = 1 > 0 and \\\n    2 > 0\nprint(total)\n";
+            let mut parser = create_parser(input, SyntaxMode::Indentation);
+            for _ in 0..4 {
+                assert!(parser.parse_statement().is_ok());
+            }
+            assert!(parser.is_at_end());
         }
     }
 
@@ -652,6 +916,7 @@ match x:
 
     mod destructuring_and_compound_tests{
         use super::*;
+        use punk::parser::ast::{ASTNode, Declaration, Pattern, Statement, Type};
 
 
         #[test]
@@ -708,6 +973,67 @@ match x:
             assert!(result.is_ok());
         }
 
+        fn bindings(pattern: &Pattern) -> Vec<&str> {
+            pattern.bindings().into_iter().map(|(name, _)| name).collect()
+        }
+
+        #[test]
+        fn test_let_patterns(){
+            for (input, expected) in [
+                ("let (a, b) = pair;", vec!["a", "b"]),
+                ("let mut [first, ..rest] = items;", vec!["first", "rest"]),
+                ("let Point { x, y: (dy, _) } = p;", vec!["x", "dy"]),
+                ("let total = 1;", vec!["total"]),
+            ] {
+                let mut parser = create_parser(input, SyntaxMode::Braces);
+                match parser.parse_statement() {
+                    Ok(ASTNode::Declaration(Declaration::Variable(decl))) => assert_eq!(bindings(&decl.pattern), expected),
+                    other => panic!("Expected variable declaration for {:?}, got {:?}", input, other),
+                }
+            }
+        }
+
+        #[test]
+        fn test_for_patterns(){
+            for (input, mode) in [
+                ("for (k, v) in d.items() { print(k); }", SyntaxMode::Braces),
+                ("for (k, v) in d.items():\n    print(k)\n", SyntaxMode::Indentation),
+            ] {
+                let mut parser = create_parser(input, mode);
+                match parser.parse_statement() {
+                    Ok(ASTNode::Statement(Statement::ForStatement(for_stmt))) => {
+                        assert!(matches!(&for_stmt.pattern, Pattern::Tuple(patterns, _) if patterns.len() == 2));
+                        assert_eq!(bindings(&for_stmt.pattern), vec!["k", "v"]);
+                    }
+                    other => panic!("Expected for statement for {:?}, got {:?}", input, other),
+                }
+            }
+
+            let mut parser = create_parser("for [x, ..rest] in rows { pass; }", SyntaxMode::Braces);
+            match parser.parse_statement() {
+                Ok(ASTNode::Statement(Statement::ForStatement(for_stmt))) => match &for_stmt.pattern {
+                    Pattern::Array(patterns, _) => {
+                        assert!(matches!(&patterns[1], Pattern::Rest(Some(name), _) if name == "rest"));
+                    }
+                    other => panic!("Expected array pattern, got {:?}", other),
+                },
+                other => panic!("Expected for statement, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_parameter_patterns(){
+            let mut parser = create_parser("fn f((x, y): (int, int), [a, ..]: [int]) -> int { return x }", SyntaxMode::Braces);
+            match parser.parse_statement() {
+                Ok(ASTNode::Declaration(Declaration::Function(function))) => {
+                    assert_eq!(bindings(&function.parameters[0].pattern), vec!["x", "y"]);
+                    assert_eq!(function.parameters[0].parameter_type, Type::Tuple(vec![Type::Int, Type::Int]));
+                    assert_eq!(bindings(&function.parameters[1].pattern), vec!["a"]);
+                }
+                other => panic!("Expected function declaration, got {:?}", other),
+            }
+        }
+
     }
 
     mod trait_tests {
@@ -792,12 +1118,12 @@ match x:
     }
 
     mod enum_tests {
-        use punk::parser::ast::Visibility;
+        use punk::parser::ast::{ASTNode, ConstructorArguments, ConstructorPattern, Declaration, EnumDeclaration, EnumVariantKind, Pattern, Statement, Type, Visibility};
         use super::*;
 
         #[test]
         fn test_enum_declaration_braces() {
-            let input = r#"enum Color {x:int,y:float,z:str}"#;
+            let input = r#"enum Color {Red,Green,Blue}"#;
 
             let mut parser = create_parser(input, SyntaxMode::Braces);
             let result = parser.parse_enum_declaration(Visibility::Public);
@@ -806,11 +1132,602 @@ match x:
 
         #[test]
         fn test_enum_declaration_indent() {
-            let input = r#"enum Color {x:int,y:float,z:str}"#;
+            let input = r#"enum Color {Red,Green,Blue}"#;
 
             let mut parser = create_parser(input, SyntaxMode::Indentation);
             let result = parser.parse_enum_declaration(Visibility::Public);
             assert!(result.is_ok());
         }
+
+        fn parse_enum(input: &str) -> EnumDeclaration {
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            match parser.parse_enum_declaration(Visibility::Private) {
+                Ok(ASTNode::Declaration(Declaration::Enum(enum_decl))) => enum_decl,
+                other => panic!("Expected enum declaration, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_enum_variant_forms() {
+            let enum_decl = parse_enum("enum Message {Quit, Write(str), Move {x: int, y: int}, Color(int, int, int)}");
+            assert_eq!(enum_decl.variantes.len(), 4);
+            assert!(matches!(enum_decl.variantes[0].kind, EnumVariantKind::Unit));
+            assert!(matches!(&enum_decl.variantes[1].kind, EnumVariantKind::Tuple(types) if types == &vec![Type::String]));
+            match &enum_decl.variantes[2].kind {
+                EnumVariantKind::Struct(fields) => {
+                    let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+                    assert_eq!(names, vec!["x", "y"]);
+                }
+                other => panic!("Expected struct variant, got {:?}", other),
+            }
+            assert!(matches!(&enum_decl.variantes[3].kind, EnumVariantKind::Tuple(types) if types.len() == 3));
+        }
+
+        #[test]
+        fn test_enum_discriminants_and_generics() {
+            let enum_decl = parse_enum("enum Status {Ok = 200, NotFound = 404, Unknown}");
+            assert!(enum_decl.variantes[0].discriminant.is_some());
+            assert!(enum_decl.variantes[2].discriminant.is_none());
+
+            let enum_decl = parse_enum("enum Option<T> {Some(T), None}");
+            let generics = enum_decl.generic_parameters.expect("generic parameters");
+            assert_eq!(generics[0].name, "T");
+            assert!(matches!(&enum_decl.variantes[0].kind, EnumVariantKind::Tuple(types) if types.len() == 1));
+
+            assert!(parse_enum("enum Empty {}").variantes.is_empty());
+        }
+
+        #[test]
+        fn test_constructor_patterns() {
+            let input = "match m {\n Message::Move {x, y: 0, ..} => x,\n Message::Write(text) => text,\n Some(Point(a, _)) => a,\n Message::Quit => 0,\n}";
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            let patterns: Vec<Pattern> = match parser.parse_statement() {
+                Ok(ASTNode::Statement(Statement::MatchStatement(match_statement))) => {
+                    match_statement.arms.into_iter().map(|arm| arm.pattern).collect()
+                }
+                other => panic!("Expected match statement, got {:?}", other),
+            };
+            let constructors: Vec<ConstructorPattern> = patterns.into_iter().map(|pattern| match pattern {
+                Pattern::Constructor(constructor) => constructor,
+                other => panic!("Expected constructor pattern, got {:?}", other),
+            }).collect();
+
+            assert_eq!(constructors[0].path, vec!["Message", "Move"]);
+            match &constructors[0].arguments {
+                ConstructorArguments::Struct { fields, rest } => {
+                    assert_eq!(fields.len(), 2);
+                    assert!(matches!(&fields[0].1, Pattern::Identifier(name, _) if name == "x"));
+                    assert!(*rest);
+                }
+                other => panic!("Expected struct arguments, got {:?}", other),
+            }
+            assert!(matches!(&constructors[1].arguments, ConstructorArguments::Tuple(args) if args.len() == 1));
+            assert!(matches!(&constructors[2].arguments, ConstructorArguments::Tuple(args) if matches!(args[0], Pattern::Constructor(_))));
+            assert!(matches!(constructors[3].arguments, ConstructorArguments::Unit));
+        }
+    }
+    mod generic_declaration_tests {
+        use punk::parser::ast::{ASTNode, Declaration, GenericParameter, Type, TypeBound};
+        use super::*;
+
+        fn parse_declaration(input: &str, mode: SyntaxMode) -> Declaration {
+            let mut parser = create_parser(input, mode);
+            match parser.parse_statement() {
+                Ok(ASTNode::Declaration(declaration)) => declaration,
+                other => panic!("Expected declaration for {:?}, got {:?}", input, other),
+            }
+        }
+
+        fn names(generic_parameters: &Option<Vec<GenericParameter>>) -> Vec<&str> {
+            generic_parameters.iter().flatten().map(|param| param.name.as_str()).collect()
+        }
+
+        #[test]
+        fn test_generic_function_braces() {
+            match parse_declaration("fn max<T: Ord, U>(a: T, b: U) -> T where U: Display + Clone { return a }", SyntaxMode::Braces) {
+                Declaration::Function(function) => {
+                    assert_eq!(names(&function.generic_parameters), vec!["T", "U"]);
+                    let bounds = &function.generic_parameters.as_ref().unwrap()[0].bounds;
+                    assert!(matches!(&bounds[0], TypeBound::TraitBound(name) if name == "Ord"));
+                    assert_eq!(function.where_clause.len(), 1);
+                    assert_eq!(function.where_clause[0].type_name, "U");
+                    assert_eq!(function.where_clause[0].bounds.len(), 2);
+                }
+                other => panic!("Expected function, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_generic_function_indent() {
+            match parse_declaration("fn first<T>(items: [T]) -> T where T: Clone:\n    pass\n", SyntaxMode::Indentation) {
+                Declaration::Function(function) => {
+                    assert_eq!(names(&function.generic_parameters), vec!["T"]);
+                    assert_eq!(function.where_clause.len(), 1);
+                }
+                other => panic!("Expected function, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_non_generic_function_has_no_parameters() {
+            match parse_declaration("fn noop() { pass; }", SyntaxMode::Braces) {
+                Declaration::Function(function) => {
+                    assert!(function.generic_parameters.is_none());
+                    assert!(function.where_clause.is_empty());
+                }
+                other => panic!("Expected function, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_generic_lifetime_parameters() {
+            match parse_declaration("fn longest<'a, T: 'a + Clone>(x: &'a T, y: &'a T) -> &'a T { return x }", SyntaxMode::Braces) {
+                Declaration::Function(function) => {
+                    assert_eq!(names(&function.generic_parameters), vec!["'a", "T"]);
+                    let bounds = &function.generic_parameters.as_ref().unwrap()[1].bounds;
+                    assert!(matches!(&bounds[0], TypeBound::Lifetime(name) if name == "'a"));
+                    assert!(matches!(&bounds[1], TypeBound::TraitBound(name) if name == "Clone"));
+                    assert_eq!(function.parameters[0].parameter_type, Type::Reference(Box::new(Type::Named("T".to_string())), Some("'a".to_string())));
+                }
+                other => panic!("Expected function, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_lifetime_bounds() {
+            match parse_declaration("fn f<'a, 'b: 'a, T>(x: &'b T) -> &'a T where 'a: 'b, T: 'a + Clone { return x }", SyntaxMode::Braces) {
+                Declaration::Function(function) => {
+                    assert_eq!(names(&function.generic_parameters), vec!["'a", "'b", "T"]);
+                    let bounds = &function.generic_parameters.as_ref().unwrap()[1].bounds;
+                    assert!(matches!(&bounds[..], [TypeBound::Lifetime(name)] if name == "'a"));
+                    assert_eq!(function.where_clause[0].type_name, "'a");
+                    assert!(matches!(&function.where_clause[0].bounds[..], [TypeBound::Lifetime(name)] if name == "'b"));
+                    assert!(matches!(&function.where_clause[1].bounds[0], TypeBound::Lifetime(name) if name == "'a"));
+                }
+                other => panic!("Expected function, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_generic_struct_enum_class() {
+            for mode in [SyntaxMode::Braces, SyntaxMode::Indentation] {
+                match parse_declaration("struct Pair<A, B> {first: A, second: B}", mode) {
+                    Declaration::Structure(structure) => assert_eq!(names(&structure.generic_parameters), vec!["A", "B"]),
+                    other => panic!("Expected struct, got {:?}", other),
+                }
+                match parse_declaration("enum Option<T> {Some(T), None}", mode) {
+                    Declaration::Enum(enumeration) => assert_eq!(names(&enumeration.generic_parameters), vec!["T"]),
+                    other => panic!("Expected enum, got {:?}", other),
+                }
+            }
+            match parse_declaration("class Box<T>(Container) { }", SyntaxMode::Braces) {
+                Declaration::Class(class) => {
+                    assert_eq!(names(&class.generic_parameters), vec!["T"]);
+                    assert_eq!(class.parent_classes, vec!["Container"]);
+                }
+                other => panic!("Expected class, got {:?}", other),
+            }
+        }
+    }
+
+    mod type_tests {
+        use punk::parser::ast::{GenericType, Type};
+        use punk::tok::{Keywords, Operators, TokenType};
+        use super::*;
+
+        fn parse_type(input: &str) -> Type {
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            parser.parse_type().unwrap_or_else(|e| panic!("{:?} for {:?}", e, input))
+        }
+
+        fn boxed(t: Type) -> Box<Type> {
+            Box::new(t)
+        }
+
+        #[test]
+        fn test_array_types() {
+            assert_eq!(parse_type("[int]"), Type::Array(boxed(Type::Int)));
+            assert_eq!(parse_type("[float; 4]"), Type::FixedArray(boxed(Type::Float), 4));
+            assert_eq!(parse_type("[[int; 2]; 3]"), Type::FixedArray(boxed(Type::FixedArray(boxed(Type::Int), 2)), 3));
+        }
+
+        #[test]
+        fn test_tuple_types() {
+            assert_eq!(parse_type("(int, str)"), Type::Tuple(vec![Type::Int, Type::String]));
+            assert_eq!(parse_type("(int,)"), Type::Tuple(vec![Type::Int]));
+            assert_eq!(parse_type("()"), Type::Tuple(vec![]));
+            assert_eq!(parse_type("(int)"), Type::Int);
+        }
+
+        #[test]
+        fn test_reference_types() {
+            assert_eq!(parse_type("&int"), Type::Reference(boxed(Type::Int), None));
+            assert_eq!(parse_type("&mut [int]"), Type::ReferenceMutable(boxed(Type::Array(boxed(Type::Int))), None));
+            assert_eq!(parse_type("&&str"), Type::Reference(boxed(Type::Reference(boxed(Type::String), None)), None));
+        }
+
+        #[test]
+        fn test_reference_with_lifetime() {
+            assert_eq!(
+                parse_type("&'a mut int"),
+                Type::ReferenceMutable(boxed(Type::Int), Some("'a".to_string()))
+            );
+            assert_eq!(
+                parse_type("&'static str"),
+                Type::Reference(boxed(Type::String), Some("'static".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_function_types() {
+            assert_eq!(
+                parse_type("fn(int, float) -> bool"),
+                Type::Function(vec![Type::Int, Type::Float], boxed(Type::Bool))
+            );
+            assert_eq!(parse_type("fn()"), Type::Function(vec![], boxed(Type::Tuple(vec![]))));
+            assert_eq!(
+                parse_type("fn(fn(int) -> int) -> &Self"),
+                Type::Function(
+                    vec![Type::Function(vec![Type::Int], boxed(Type::Int))],
+                    boxed(Type::Reference(boxed(Type::SelfType), None))
+                )
+            );
+        }
+
+        #[test]
+        fn test_optional_types() {
+            assert_eq!(parse_type("int?"), Type::Optional(boxed(Type::Int)));
+            assert_eq!(parse_type("[str]?"), Type::Optional(boxed(Type::Array(boxed(Type::String)))));
+        }
+
+        #[test]
+        fn test_nested_generic_types() {
+            assert_eq!(
+                parse_type("Vec<Vec<int>>"),
+                Type::Generic(GenericType {
+                    base: "Vec".to_string(),
+                    type_parameters: vec![Type::Generic(GenericType {
+                        base: "Vec".to_string(),
+                        type_parameters: vec![Type::Int],
+                    })],
+                })
+            );
+        }
+
+        #[test]
+        fn test_rich_types_in_declarations() {
+            let input = "struct Holder {f: fn(int) -> int, values: &[int; 3], pair: (int, bool)?}";
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            assert!(parser.parse_statement().is_ok());
+        }
+    }
+
+    mod raise_del_with_yield_pass_tests {
+        use punk::parser::ast::{ASTNode, Statement};
+        use punk::parser::parser_error::ParserErrorType;
+        use super::*;
+
+        fn parse_statement(input: &str, mode: SyntaxMode) -> Statement {
+            let mut parser = create_parser(input, mode);
+            match parser.parse_statement() {
+                Ok(ASTNode::Statement(statement)) => statement,
+                other => panic!("Expected statement for {:?}, got {:?}", input, other),
+            }
+        }
+
+        #[test]
+        fn test_raise_braces() {
+            let statement = parse_statement("raise ValueError(\"bad\");", SyntaxMode::Braces);
+            assert!(matches!(statement, Statement::RaiseStatement(_)));
+        }
+
+        #[test]
+        fn test_raise_indent() {
+            let statement = parse_statement("raise ValueError(\"bad\")\n", SyntaxMode::Indentation);
+            assert!(matches!(statement, Statement::RaiseStatement(_)));
+        }
+
+        #[test]
+        fn test_del_braces() {
+            for input in ["del x;", "del obj.field;", "del items[0];"] {
+                let statement = parse_statement(input, SyntaxMode::Braces);
+                assert!(matches!(statement, Statement::DelStatement(_)), "{}", input);
+            }
+        }
+
+        #[test]
+        fn test_del_indent() {
+            let statement = parse_statement("del x\n", SyntaxMode::Indentation);
+            assert!(matches!(statement, Statement::DelStatement(_)));
+        }
+
+        #[test]
+        fn test_del_invalid_target() {
+            let mut parser = create_parser("del 1 + 2;", SyntaxMode::Braces);
+            let error = parser.parse_statement().unwrap_err();
+            assert_eq!(error.error, ParserErrorType::InvalidDelTarget);
+        }
+
+        #[test]
+        fn test_with_as_braces() {
+            let input = r#"with open("data.txt") as f {
+    process(f);
+}"#;
+            match parse_statement(input, SyntaxMode::Braces) {
+                Statement::WithStatement(with) => {
+                    assert_eq!(with.alias.as_deref(), Some("f"));
+                    assert_eq!(with.body.len(), 1);
+                }
+                other => panic!("Expected WithStatement, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_with_as_indent() {
+            let input = "with open(\"data.txt\") as f:\n    process(f)\n    pass\n";
+            match parse_statement(input, SyntaxMode::Indentation) {
+                Statement::WithStatement(with) => {
+                    assert_eq!(with.alias.as_deref(), Some("f"));
+                    assert_eq!(with.body.len(), 2);
+                }
+                other => panic!("Expected WithStatement, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_with_without_alias() {
+            match parse_statement("with lock {\n    work();\n}", SyntaxMode::Braces) {
+                Statement::WithStatement(with) => assert!(with.alias.is_none()),
+                other => panic!("Expected WithStatement, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_yield() {
+            match parse_statement("yield x + 1;", SyntaxMode::Braces) {
+                Statement::YieldStatement(yield_statement) => assert!(yield_statement.value.is_some()),
+                other => panic!("Expected YieldStatement, got {:?}", other),
+            }
+            match parse_statement("yield;", SyntaxMode::Braces) {
+                Statement::YieldStatement(yield_statement) => assert!(yield_statement.value.is_none()),
+                other => panic!("Expected YieldStatement, got {:?}", other),
+            }
+            match parse_statement("yield\n", SyntaxMode::Indentation) {
+                Statement::YieldStatement(yield_statement) => assert!(yield_statement.value.is_none()),
+                other => panic!("Expected YieldStatement, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_pass() {
+            assert!(matches!(parse_statement("pass;", SyntaxMode::Braces), Statement::Pass(_)));
+            assert!(matches!(parse_statement("pass\n", SyntaxMode::Indentation), Statement::Pass(_)));
+        }
+
+        #[test]
+        fn test_statements_in_function_body_indent() {
+            let input = "fn gen(n: int) -> int:\n    yield n\n    yield\n    pass\n";
+            let mut parser = create_parser(input, SyntaxMode::Indentation);
+            assert!(parser.parse_statement().is_ok());
+        }
+    }
+
+    mod struct_literal_tuple_tests {
+        use punk::parser::ast::{ASTNode, Declaration, Statement};
+        use super::*;
+
+        fn parse_expr(input: &str) -> Expression {
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            parser.parse_expression(0).unwrap_or_else(|e| panic!("{:?} for {:?}", e, input))
+        }
+
+        #[test]
+        fn test_struct_literal() {
+            match parse_expr("Point { x: 1, y: 2 }") {
+                Expression::StructLiteral(literal) => {
+                    assert_eq!(literal.name, "Point");
+                    let names: Vec<&str> = literal.fields.iter().map(|f| f.name.as_str()).collect();
+                    assert_eq!(names, vec!["x", "y"]);
+                    assert!(literal.base.is_none());
+                }
+                other => panic!("Expected StructLiteral, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_struct_literal_shorthand_and_update() {
+            match parse_expr("Point { x, ..origin }") {
+                Expression::StructLiteral(literal) => {
+                    assert_eq!(literal.fields.len(), 1);
+                    assert!(matches!(&literal.fields[0].value, Expression::Identifier(name, _) if name == "x"));
+                    assert!(matches!(literal.base.as_deref(), Some(Expression::Identifier(name, _)) if name == "origin"));
+                }
+                other => panic!("Expected StructLiteral, got {:?}", other),
+            }
+            assert!(matches!(parse_expr("Empty {}"), Expression::StructLiteral(_)));
+        }
+
+        #[test]
+        fn test_struct_literal_base_must_be_last() {
+            let mut parser = create_parser("Point { ..origin, x: 1 }", SyntaxMode::Braces);
+            assert!(parser.parse_expression(0).is_err());
+        }
+
+        #[test]
+        fn test_condition_block_is_not_struct_literal() {
+            let mut parser = create_parser("if ready { go }", SyntaxMode::Braces);
+            match parser.parse_statement().unwrap() {
+                ASTNode::Statement(Statement::IfStatement(if_statement)) => {
+                    assert!(matches!(if_statement.condition, Expression::Identifier(ref name, _) if name == "ready"));
+                    assert_eq!(if_statement.then_block.len(), 1);
+                }
+                other => panic!("Expected IfStatement, got {:?}", other),
+            }
+
+            // Entre parentheses le struct literal redevient possible
+            let mut parser = create_parser("while p == (Point { x: 1 }) { step }", SyntaxMode::Braces);
+            assert!(parser.parse_statement().is_ok());
+        }
+
+        #[test]
+        fn test_struct_literal_in_condition_brackets() {
+            let mut parser = create_parser("if p[Point { x: 1 }] { go }", SyntaxMode::Braces);
+            match parser.parse_statement().unwrap() {
+                ASTNode::Statement(Statement::IfStatement(if_statement)) => {
+                    match if_statement.condition {
+                        Expression::IndexAccess(access) => assert!(matches!(*access.index, Expression::StructLiteral(_))),
+                        other => panic!("Expected IndexAccess, got {:?}", other),
+                    }
+                    assert_eq!(if_statement.then_block.len(), 1);
+                }
+                other => panic!("Expected IfStatement, got {:?}", other),
+            }
+
+            let mut parser = create_parser("if f(Point { x: 1 }) { go }", SyntaxMode::Braces);
+            assert!(parser.parse_statement().is_ok());
+        }
+
+        #[test]
+        fn test_dict_literal_unchanged() {
+            assert!(matches!(parse_expr("{a: 1, b: 2}"), Expression::DictLiteral(_)));
+            let mut parser = create_parser("let d = {x: 1};", SyntaxMode::Braces);
+            match parser.parse_statement().unwrap() {
+                ASTNode::Declaration(Declaration::Variable(decl)) => {
+                    assert!(matches!(decl.value, Some(Expression::DictLiteral(_))));
+                }
+                other => panic!("Expected variable declaration, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_tuples() {
+            match parse_expr("(1, x, 3)") {
+                Expression::Tuple(tuple) => assert_eq!(tuple.elements.len(), 3),
+                other => panic!("Expected Tuple, got {:?}", other),
+            }
+            match parse_expr("(1,)") {
+                Expression::Tuple(tuple) => assert_eq!(tuple.elements.len(), 1),
+                other => panic!("Expected Tuple, got {:?}", other),
+            }
+            match parse_expr("()") {
+                Expression::Tuple(tuple) => assert!(tuple.elements.is_empty()),
+                other => panic!("Expected Tuple, got {:?}", other),
+            }
+            assert!(matches!(parse_expr("(1 + 2)"), Expression::BinaryOperation(_)));
+        }
+
+        #[test]
+        fn test_format_string() {
+            use punk::parser::ast::FormatStringPart;
+
+            match parse_expr(r#"f"Hello {name}, {x + 1:>8.2}!""#) {
+                Expression::FormatString(format) => {
+                    assert_eq!(format.parts.len(), 5);
+                    assert!(matches!(&format.parts[0], FormatStringPart::Literal(text) if text == "Hello "));
+                    assert!(matches!(&format.parts[1],
+                        FormatStringPart::Expression { expression, format_spec: None }
+                            if matches!(expression.as_ref(), Expression::Identifier(name, _) if name == "name")));
+                    assert!(matches!(&format.parts[3],
+                        FormatStringPart::Expression { expression, format_spec: Some(spec) }
+                            if spec == ">8.2" && matches!(expression.as_ref(), Expression::BinaryOperation(_))));
+                    assert!(matches!(&format.parts[4], FormatStringPart::Literal(text) if text == "!"));
+                }
+                other => panic!("Expected FormatString, got {:?}", other),
+            }
+
+            let mut parser = create_parser(r#"f"{x +}""#, SyntaxMode::Braces);
+            assert!(parser.parse_expression(0).is_err());
+            let mut parser = create_parser(r#"f"{x y}""#, SyntaxMode::Braces);
+            assert!(parser.parse_expression(0).is_err());
+        }
+
+        #[test]
+        fn test_format_string_hole_spans() {
+            use punk::parser::ast::FormatStringPart;
+
+            // Les spans des expressions sont ceux du fichier, pas ceux de l'accolade
+            let input = "let s = 1;\nf\"total: {a + count}\"";
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            parser.parse_statement().unwrap();
+            match parser.parse_expression(0).unwrap() {
+                Expression::FormatString(format) => match &format.parts[1] {
+                    FormatStringPart::Expression { expression, .. } => {
+                        let span = expression.span();
+                        assert_eq!(&input[span.start..span.end], "a + count");
+                        assert_eq!((span.line, span.column), (2, 11));
+                    }
+                    other => panic!("Expected expression part, got {:?}", other),
+                },
+                other => panic!("Expected FormatString, got {:?}", other),
+            }
+
+            let input = "f\"ok {x +}\"";
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            let error = parser.parse_expression(0).unwrap_err();
+            assert_eq!(&input[error.span.start..error.span.end], "x +");
+        }
+
+        #[test]
+        fn test_format_string_holes_use_file_keywords() {
+            use punk::lexer::keywords::KeywordSet;
+            use punk::parser::ast::{FormatStringPart, Operator, UnaryOperator};
+
+            // Les trous sont relus avec le dictionnaire et le mode du fichier
+            for mode in [SyntaxMode::Braces, SyntaxMode::Indentation] {
+                let tokens = Lexer::new("f\"{non x} {a et vrai}\"", mode).with_keyword_set(KeywordSet::French).tokenize();
+                let mut parser = Parser::new(tokens, mode).with_keyword_set(KeywordSet::French);
+                match parser.parse_expression(0).unwrap() {
+                    Expression::FormatString(format) => {
+                        assert!(matches!(&format.parts[0], FormatStringPart::Expression { expression, .. }
+                            if matches!(expression.as_ref(), Expression::UnaryOperation(op) if matches!(op.operator, UnaryOperator::LogicalNot))));
+                        assert!(matches!(&format.parts[2], FormatStringPart::Expression { expression, .. }
+                            if matches!(expression.as_ref(), Expression::BinaryOperation(op) if matches!(op.operator, Operator::And))));
+                    }
+                    other => panic!("Expected FormatString, got {:?}", other),
+                }
+            }
+        }
+
+        #[test]
+        fn test_bytes_and_raw_string_literals() {
+            use punk::parser::ast::Literal;
+
+            assert!(matches!(parse_expr(r#"b"ab\x00""#),
+                Expression::Literal(Literal::Bytes(bytes), _) if bytes == vec![b'a', b'b', 0]));
+            assert!(matches!(parse_expr(r#"r"\d+""#),
+                Expression::Literal(Literal::String(text), _) if text == r"\d+"));
+        }
+    }
+
+    mod span_tests {
+        use super::*;
+
+        #[test]
+        fn test_expression_spans() {
+            let input = "let x = 1 + 42;";
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            let result = parser.parse_statement().unwrap();
+            let span = result.span();
+            assert_eq!(span.start, 0);
+            assert_eq!(&input[span.start..span.end], "let x = 1 + 42");
+
+            let mut parser = create_parser("foo + 42", SyntaxMode::Braces);
+            let expr = parser.parse_expression(0).unwrap();
+            let span = expr.span();
+            assert_eq!((span.start, span.end), (0, 8));
+            if let Expression::BinaryOperation(binary) = expr {
+                assert_eq!((binary.right.span().start, binary.right.span().end), (6, 8));
+            } else {
+                panic!("Expected BinaryOperation");
+            }
+        }
+
+        #[test]
+        fn test_error_span() {
+            let input = "let x = ;";
+            let mut parser = create_parser(input, SyntaxMode::Braces);
+            let error = parser.parse_statement().unwrap_err();
+            assert_eq!(error.span.start, 8);
+        }
     }
 }
@@ -17,7 +17,7 @@ use punk::semantic::symbols::{ScopeKind, SymbolKind};
 
 use punk::parser::ast::{ASTNode, Expression, ReturnStatement};
 use punk::semantic::analyser::SemanticAnalyzer;
-use punk::parser::ast::{Declaration, Literal, Mutability, VariableDeclaration};
+use punk::parser::ast::{Declaration, Literal, Mutability, Pattern, VariableDeclaration, Span};
 
 use punk::parser::ast::Type as ASTType;
 
@@ -92,6 +92,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rich_ast_type_conversion() {
+        let mut type_system = TypeSystem::new();
+        let type_registry = &mut type_system.type_registry;
+
+        let fixed = type_registry.convert_ast_type(&ast::Type::FixedArray(Box::new(ast::Type::Int), 3));
+        assert!(matches!(&type_registry.get_type(fixed).unwrap().kind, TypeKind::Array(_, Some(3))));
+
+        let unit = type_registry.convert_ast_type(&ast::Type::Tuple(vec![]));
+        assert_eq!(unit, type_registry.type_unit);
+
+        let function = type_registry.convert_ast_type(&ast::Type::Function(
+            vec![ast::Type::Int, ast::Type::Float],
+            Box::new(ast::Type::Bool),
+        ));
+        match &type_registry.get_type(function).unwrap().kind {
+            TypeKind::Function(function_type) => {
+                assert_eq!(function_type.params.len(), 2);
+                assert_eq!(function_type.return_type.kind, TypeKind::Bool);
+            }
+            other => panic!("Expected Function type, got {:?}", other),
+        }
+
+        let optional = type_registry.convert_ast_type(&ast::Type::Optional(Box::new(ast::Type::String)));
+        match &type_registry.get_type(optional).unwrap().kind {
+            TypeKind::Named(name, args) => {
+                assert_eq!(name, "Option");
+                assert_eq!(args[0].kind, TypeKind::String);
+            }
+            other => panic!("Expected Option type, got {:?}", other),
+        }
+
+        // Une meme lifetime nommee donne le meme identifiant
+        let first = type_registry.convert_ast_type(&ast::Type::Reference(Box::new(ast::Type::Int), Some("'a".to_string())));
+        let second = type_registry.convert_ast_type(&ast::Type::ReferenceMutable(Box::new(ast::Type::Char), Some("'a".to_string())));
+        let lifetime = |kind: &TypeKind| match kind {
+            TypeKind::Reference(_, _, lifetime) => lifetime.clone(),
+            _ => None,
+        };
+        let first_lifetime = lifetime(&type_registry.get_type(first).unwrap().kind);
+        assert!(first_lifetime.is_some());
+        assert_eq!(first_lifetime, lifetime(&type_registry.get_type(second).unwrap().kind));
+    }
+
     #[test]
     fn test_type_inference() {
         let mut type_system = TypeSystem::new();
@@ -134,6 +178,7 @@ mod tests {
             file: "test.rs".to_string(),
             line: 1,
             column: 1,
+            span: Span::dummy(),
         };
 
         let symbol_id = symbol_table.declare_symbol(
@@ -157,6 +202,7 @@ mod tests {
             file: "test.rs".to_string(),
             line: 1,
             column: 1,
+            span: Span::dummy(),
         };
 
         symbol_table.declare_symbol(
@@ -182,6 +228,7 @@ mod tests {
             file: "test.rs".to_string(),
             line: 1,
             column: 1,
+            span: Span::dummy(),
         };
 
         symbol_table.declare_symbol(
@@ -223,6 +270,7 @@ mod tests {
             file: "test.rs".to_string(),
             line: 1,
             column: 1,
+            span: Span::dummy(),
         };
 
         // Déclarer un symbole avec un type
@@ -248,10 +296,11 @@ mod tests {
 
         // Créer une déclaration de variable simple
         let var_decl = VariableDeclaration {
-            name: "x".to_string(),
+            pattern: Pattern::Identifier("x".to_string(), Span::dummy()),
             variable_type: Some(ASTType::Int),
-            value: Some(Expression::Literal(Literal::Integer { value: 42.into()})),
-            mutability: Mutability::Immutable
+            value: Some(Expression::Literal(Literal::Integer { value: 42.into(), suffix: None }, Span::dummy())),
+            mutability: Mutability::Immutable,
+            span: Span::dummy(),
         };
 
         let ast_node = ASTNode::Declaration(Declaration::Variable(var_decl));
@@ -275,10 +324,11 @@ mod tests {
 
         // Créer une déclaration de variable avec un type explicite et une valeur incompatible
         let var_decl = VariableDeclaration {
-            name: "x".to_string(),
+            pattern: Pattern::Identifier("x".to_string(), Span::dummy()),
             variable_type: Some(ASTType::Int),
-            value: Some(Expression::Literal(Literal::Boolean(true))),
-            mutability: Mutability::Immutable
+            value: Some(Expression::Literal(Literal::Boolean(true), Span::dummy())),
+            mutability: Mutability::Immutable,
+            span: Span::dummy(),
         };
 
         let ast_node = ASTNode::Declaration(Declaration::Variable(var_decl));
@@ -295,9 +345,10 @@ mod tests {
         let expr = Expression::BinaryOperation(
             *Box::new(
                 ast::BinaryOperation {
-                    left: Box::new(Expression::Literal(Literal::Integer { value: 5.into() })),
+                    left: Box::new(Expression::Literal(Literal::Integer { value: 5.into(), suffix: None }, Span::dummy())),
                     operator: ast::Operator::Addition,
-                    right: Box::new(Expression::Literal(Literal::Integer { value: 3.into()}))
+                    right: Box::new(Expression::Literal(Literal::Integer { value: 3.into(), suffix: None }, Span::dummy())),
+                    span: Span::dummy(),
                 }
             )
         );
@@ -313,6 +364,352 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bitwise_and_power_type_inference() {
+        let mut analyzer = SemanticAnalyzer::new();
+        let int = |value: i32| Box::new(Expression::Literal(Literal::Integer { value: value.into(), suffix: None }, Span::dummy()));
+        let binary = |operator: ast::Operator, left: Box<Expression>, right: Box<Expression>| {
+            Expression::BinaryOperation(ast::BinaryOperation { left, operator, right, span: Span::dummy() })
+        };
+
+        for operator in [ast::Operator::Power, ast::Operator::BitwiseAnd, ast::Operator::ShiftLeft] {
+            let type_id = analyzer.analyze_expression(&binary(operator, int(6), int(3))).unwrap();
+            let type_obj = analyzer.type_checker.type_system.type_registry.get_type(type_id).unwrap();
+            assert_eq!(type_obj.kind, TypeKind::Int);
+        }
+
+        // 1.5 << 2 n'est pas valide
+        let float = Box::new(Expression::Literal(Literal::Float { value: 1.5 }, Span::dummy()));
+        assert!(analyzer.analyze_expression(&binary(ast::Operator::ShiftLeft, float, int(2))).is_err());
+    }
+
+    #[test]
+    fn test_struct_literal_and_tuple_checking() {
+        use punk::parser::parser::Parser;
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            Parser::new(lexer.tokenize(), SyntaxMode::Braces)
+        };
+        let declaration = parse("struct Point { x: int, y: float }").parse_statement().unwrap();
+
+        let mut analyzer = SemanticAnalyzer::new();
+        assert!(analyzer.analyze(&[declaration]).is_ok());
+
+        let point = analyzer.analyze_expression(&parse("Point { x: 1, y: 2.5 }").parse_expression(0).unwrap()).unwrap();
+        let registry = &analyzer.type_checker.type_system.type_registry;
+        assert!(matches!(&registry.get_type(point).unwrap().kind, TypeKind::Struct(id) if id.name == "Point"));
+
+        // int -> float est accepte, les autres erreurs sont signalees
+        assert!(analyzer.analyze_expression(&parse("Point { x: 1, y: 2 }").parse_expression(0).unwrap()).is_ok());
+        for invalid in ["Point { x: 1 }", "Point { x: 1, y: 2.0, z: 3 }", "Point { x: 1, x: 2, y: 2.0 }",
+                        "Point { x: 1.5, y: 2.0 }", "Nowhere { x: 1 }", "Point { x: 1, ..(1, 2) }"] {
+            let expression = parse(invalid).parse_expression(0).unwrap();
+            assert!(analyzer.analyze_expression(&expression).is_err(), "{} should be rejected", invalid);
+        }
+
+        let tuple = analyzer.analyze_expression(&parse("(1, 2.5, true)").parse_expression(0).unwrap()).unwrap();
+        match &analyzer.type_checker.type_system.type_registry.get_type(tuple).unwrap().kind {
+            TypeKind::Tuple(elements) => {
+                let kinds: Vec<&TypeKind> = elements.iter().map(|t| &t.kind).collect();
+                assert_eq!(kinds, vec![&TypeKind::Int, &TypeKind::Float, &TypeKind::Bool]);
+            }
+            other => panic!("Expected Tuple type, got {:?}", other),
+        }
+        let unit = analyzer.analyze_expression(&parse("()").parse_expression(0).unwrap()).unwrap();
+        assert_eq!(unit, analyzer.type_checker.type_system.type_registry.type_unit);
+    }
+
+    #[test]
+    fn test_enum_variants_registration_and_checking() {
+        use punk::parser::parser::Parser;
+        use punk::semantic::types::type_system::VariantPayload;
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            Parser::new(lexer.tokenize(), SyntaxMode::Braces)
+        };
+        let declarations: Vec<ASTNode> = [
+            "enum Shape {Empty, Circle(float), Rect {w: int, h: int}}",
+            "enum Status {Ok = 200, Created, NotFound = 404}",
+            "enum Maybe<T> {Just(T), Nothing}",
+            "enum Signed {Low = -1, Zero, High = 2}",
+        ].iter().map(|source| parse(source).parse_statement().unwrap()).collect();
+
+        let mut analyzer = SemanticAnalyzer::new();
+        assert!(analyzer.analyze(&declarations).is_ok(), "{:?}", analyzer.errors);
+
+        let registry = &analyzer.symbol_table.type_system().type_registry;
+        let (status_id, created) = registry.lookup_enum_variant("Status::Created").unwrap();
+        assert_eq!(created.discriminant, 201);
+        assert_eq!(registry.lookup_enum_variant("Signed::Low").unwrap().1.discriminant, -1);
+        assert_eq!(registry.lookup_enum_variant("Signed::Zero").unwrap().1.discriminant, 0);
+        assert!(matches!(&registry.get_type(status_id).unwrap().kind, TypeKind::Enum(id) if id.name == "Status"));
+        let (_, just) = registry.lookup_enum_variant("Maybe::Just").unwrap();
+        assert!(matches!(&just.payload, VariantPayload::Tuple(types) if matches!(types[0].kind, TypeKind::Generic(..))));
+
+        let shape_id = *registry.enum_types.get("Shape").unwrap();
+        for valid in ["Shape::Empty", "Shape::Circle(2.0)", "Shape::Rect { w: 1, h: 2 }", "Maybe::Just(true)"] {
+            let type_id = analyzer.analyze_expression(&parse(valid).parse_expression(0).unwrap())
+                .unwrap_or_else(|e| panic!("{} should be accepted: {:?}", valid, e));
+            if valid.starts_with("Shape") {
+                assert_eq!(type_id, shape_id);
+            }
+        }
+        for invalid in ["Shape::Circle(true)", "Shape::Rect { w: 1 }", "Shape::Triangle", "Shape::Rect"] {
+            let expression = parse(invalid).parse_expression(0).unwrap();
+            assert!(analyzer.analyze_expression(&expression).is_err(), "{} should be rejected", invalid);
+        }
+
+        // Discriminants dupliques ou non entiers
+        for invalid in ["enum Twice {A = 1, B = 1}", "enum NotInt {A = 1.5}", "enum Repeated {A, A}"] {
+            let mut analyzer = SemanticAnalyzer::new();
+            let declaration = parse(invalid).parse_statement().unwrap();
+            assert!(analyzer.analyze(&[declaration]).is_err(), "{} should be rejected", invalid);
+        }
+    }
+
+    #[test]
+    fn test_generic_parameters_are_declared() {
+        use punk::parser::parser::Parser;
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            Parser::new(lexer.tokenize(), SyntaxMode::Braces)
+        };
+        let declarations: Vec<ASTNode> = [
+            "fn pick<T: Ord>(a: T, b: T) -> T { return a }",
+            "struct Pair<A, B> {first: A, second: B}",
+            "class Holder<V> { }",
+        ].iter().map(|source| parse(source).parse_statement().unwrap()).collect();
+
+        let mut analyzer = SemanticAnalyzer::new();
+        assert!(analyzer.analyze(&declarations).is_ok(), "{:?}", analyzer.errors);
+
+        let mut generics: Vec<&str> = analyzer.symbol_table.symbols.values()
+            .filter(|symbol| symbol.kind == SymbolKind::Generic)
+            .map(|symbol| symbol.name.as_str())
+            .collect();
+        generics.sort();
+        assert_eq!(generics, vec!["A", "B", "T", "V"]);
+
+        // Les champs et parametres generiques ont le type Generic
+        let registry = &analyzer.symbol_table.type_system().type_registry;
+        let pair_id = *registry.struct_types.get("Pair").unwrap();
+        let fields = registry.get_struct_fields(pair_id).unwrap();
+        assert!(matches!(&fields[0].1.kind, TypeKind::Generic(name, _) if name == "A"));
+
+        let pick = analyzer.analyze_expression(&parse("pick(1, 2)").parse_expression(0).unwrap());
+        assert!(pick.is_ok(), "{:?}", pick);
+
+        // Une clause where doit porter sur un parametre declare
+        let mut analyzer = SemanticAnalyzer::new();
+        let declaration = parse("fn show<T>(a: T) where U: Display { return a }").parse_statement().unwrap();
+        assert!(analyzer.analyze(&[declaration]).is_err());
+    }
+
+    #[test]
+    fn test_scope_is_closed_after_declaration_error() {
+        use punk::parser::parser::Parser;
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            Parser::new(lexer.tokenize(), SyntaxMode::Braces).parse_statement().unwrap()
+        };
+        // Chaque declaration echoue apres l'ouverture de son scope
+        let declarations: Vec<ASTNode> = [
+            "struct Pair<T, T> {first: T}",
+            "enum Choice<E, E> {Left(E)}",
+            "class Box<V, V> { }",
+            "fn show<T>(a: T) where U: Display { return a }",
+            "let later = 1;",
+        ].iter().map(|source| parse(source)).collect();
+
+        let mut analyzer = SemanticAnalyzer::new();
+        assert!(analyzer.analyze(&declarations).is_err());
+        assert_eq!(analyzer.symbol_table.current_scope, ScopeId(0));
+        let later = analyzer.symbol_table.symbols.values().find(|symbol| symbol.name == "later").unwrap();
+        assert_eq!(later.scope_id, ScopeId(0));
+    }
+
+    #[test]
+    fn test_destructuring_patterns_bind_every_name() {
+        use punk::parser::parser::Parser;
+        use punk::semantic::semantic_error::{SemanticErrorType, TypeError};
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            Parser::new(lexer.tokenize(), SyntaxMode::Braces).parse_statement().unwrap()
+        };
+        let declarations = vec![
+            parse("struct Point {x: int, y: bool}"),
+            parse("fn f((a, b): (int, bool), rows: [[int]], p: Point) { let Point { x, y: flag } = p; for [first, ..rest] in rows { pass; } }"),
+        ];
+
+        let mut analyzer = SemanticAnalyzer::new();
+        assert!(analyzer.analyze(&declarations).is_ok(), "{:?}", analyzer.errors);
+
+        let registry = &analyzer.symbol_table.type_system().type_registry;
+        let kind = |name: &str| {
+            let symbol = analyzer.symbol_table.symbols.values().find(|symbol| symbol.name == name).unwrap();
+            analyzer.symbol_table.get_symbol_type(symbol.id).unwrap().map(|symbol_type| symbol_type.kind.clone())
+        };
+        assert_eq!(kind("a"), Some(TypeKind::Int));
+        assert_eq!(kind("b"), Some(TypeKind::Bool));
+        assert_eq!(kind("x"), Some(TypeKind::Int));
+        assert_eq!(kind("flag"), Some(TypeKind::Bool));
+        assert_eq!(kind("first"), Some(TypeKind::Int));
+        assert!(matches!(kind("rest"), Some(TypeKind::Array(element, _)) if element.id == registry.type_int));
+
+        // Un pattern refutable est refuse dans un let, un for ou un parametre
+        for source in [
+            "fn g(o: int) { let Some(v) = o; }",
+            "fn g(o: [int]) { for (1, v) in o { pass; } }",
+            "fn g((0, v): (int, int)) { pass; }",
+        ] {
+            let mut analyzer = SemanticAnalyzer::new();
+            let errors = analyzer.analyze(&[parse(source)]).unwrap_err();
+            assert!(matches!(errors[0].error, SemanticErrorType::TypeError(TypeError::RefutablePattern(_))), "{:?}", errors);
+        }
+    }
+
+    #[test]
+    fn test_unicode_identifiers_are_normalized() {
+        use punk::parser::parser::Parser;
+        use punk::{Lexer, SyntaxMode};
+
+        // Declare en NFC, utilise en NFD: un seul symbole
+        let source = "let mut caf\u{e9} = 1; cafe\u{301} = cafe\u{301} + 1;";
+        let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+        let mut parser = Parser::new(lexer.tokenize(), SyntaxMode::Braces);
+        let statements = vec![parser.parse_statement().unwrap(), parser.parse_statement().unwrap()];
+
+        let mut analyzer = SemanticAnalyzer::new();
+        assert!(analyzer.analyze(&statements).is_ok(), "{:?}", analyzer.errors);
+    }
+
+    #[test]
+    fn test_complex_arithmetic() {
+        use punk::parser::parser::Parser;
+        use punk::semantic::semantic_error::{SemanticErrorType, TypeError};
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            Parser::new(lexer.tokenize(), SyntaxMode::Braces).parse_statement().unwrap()
+        };
+
+        let valid = ["let a = 3j;", "let b = 1 + 2.5j;", "let c = 1.5 * 2j - 4;", "let d: complex = 1.5;",
+                     "let e = -(2j / 2);", "let f = 3j == 3j;"];
+        let mut analyzer = SemanticAnalyzer::new();
+        let declarations: Vec<ASTNode> = valid.iter().map(|source| parse(source)).collect();
+        assert!(analyzer.analyze(&declarations).is_ok(), "{:?}", analyzer.errors);
+
+        for source in ["let a = 3j % 2;", "let b = 1j < 2j;", "let c = \"x\" + 1j;"] {
+            let mut analyzer = SemanticAnalyzer::new();
+            assert!(analyzer.analyze(&[parse(source)]).is_err(), "{} should be rejected", source);
+            assert!(matches!(analyzer.errors[0].error, SemanticErrorType::TypeError(TypeError::TypeMismatch(_))), "{:?}", analyzer.errors);
+        }
+    }
+
+    #[test]
+    fn test_integer_literal_overflow() {
+        use punk::parser::parser::Parser;
+        use punk::semantic::semantic_error::{SemanticErrorType, TypeError};
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            Parser::new(lexer.tokenize(), SyntaxMode::Braces).parse_statement().unwrap()
+        };
+
+        let valid = ["let a: i8 = -128;", "let b: u8 = 255;", "let c = 127i8;", "let d = -128i8;",
+                     "let e: u128 = 340282366920938463463374607431768211455;",
+                     "let f = 123456789012345678901234567890;"];
+        let mut analyzer = SemanticAnalyzer::new();
+        let declarations: Vec<ASTNode> = valid.iter().map(|source| parse(source)).collect();
+        assert!(analyzer.analyze(&declarations).is_ok(), "{:?}", analyzer.errors);
+
+        for (source, start) in [("let a: i8 = 128;", 12), ("let b: u8 = -1;", 12), ("let c = 300u8;", 8),
+                                ("let d = -129i8;", 8), ("let e: u64 = 18446744073709551616;", 13)] {
+            let mut analyzer = SemanticAnalyzer::new();
+            assert!(analyzer.analyze(&[parse(source)]).is_err(), "{} should overflow", source);
+            let error = &analyzer.errors[0];
+            assert!(matches!(error.error, SemanticErrorType::TypeError(TypeError::IntegerOverflow(_))), "{:?}", error);
+            assert_eq!(error.span.start, start, "{}", source);
+        }
+    }
+
+    #[test]
+    fn test_format_string_checking() {
+        use punk::parser::parser::Parser;
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            Parser::new(lexer.tokenize(), SyntaxMode::Braces)
+        };
+        let declarations: Vec<ASTNode> = ["let x: int = 1;", "let name: str = \"punk\";"]
+            .iter().map(|source| parse(source).parse_statement().unwrap()).collect();
+
+        let mut analyzer = SemanticAnalyzer::new();
+        assert!(analyzer.analyze(&declarations).is_ok(), "{:?}", analyzer.errors);
+
+        let format = parse(r#"f"Hello {name}, {x + 1:>8.2f} {x:#x}""#).parse_expression(0).unwrap();
+        let type_id = analyzer.analyze_expression(&format).unwrap();
+        assert_eq!(type_id, analyzer.type_checker.type_system.type_registry.type_string);
+
+        // Chaque expression est verifiee, ainsi que la compatibilite de la spec
+        for invalid in [r#"f"{x + name}""#, r#"f"{name:d}""#, r#"f"{x:s}""#, r#"f"{missing}""#] {
+            let expression = parse(invalid).parse_expression(0).unwrap();
+            assert!(analyzer.analyze_expression(&expression).is_err(), "{} should be rejected", invalid);
+        }
+    }
+
+    #[test]
+    fn test_with_alias_and_new_statements_are_checked() {
+        use punk::parser::parser::Parser;
+        use punk::semantic::semantic_error::{SemanticErrorType, SymbolError};
+        use punk::{Lexer, SyntaxMode};
+
+        let parse = |source: &str| {
+            let mut lexer = Lexer::new(source, SyntaxMode::Braces);
+            let mut parser = Parser::new(lexer.tokenize(), SyntaxMode::Braces);
+            let mut statements = Vec::new();
+            while !parser.is_at_end() {
+                statements.push(parser.parse_statement().unwrap());
+            }
+            statements
+        };
+
+        // L'alias a le type de l'expression et n'est visible que dans le corps
+        let mut analyzer = SemanticAnalyzer::new();
+        let statements = parse("let f: int = 1; with f as handle { let g: int = handle + 1; }");
+        assert!(analyzer.analyze(&statements).is_ok(), "{:?}", analyzer.errors);
+        let handle = analyzer.symbol_table.symbols.values().find(|symbol| symbol.name == "handle").unwrap();
+        let handle_type = analyzer.symbol_table.get_symbol_type(handle.id).unwrap().map(|symbol_type| symbol_type.kind.clone());
+        assert_eq!(handle_type, Some(TypeKind::Int));
+
+        // Les expressions de with, raise, del et yield sont verifiees, comme le corps du with
+        for source in [
+            "let f = 1; with f as handle { pass; } let g = handle;",
+            "with missing as handle { pass; }",
+            "let f = 1; with f as handle { let g = missing; }",
+            "raise missing;",
+            "del missing;",
+            "fn gen() { yield missing; }",
+        ] {
+            let mut analyzer = SemanticAnalyzer::new();
+            assert!(analyzer.analyze(&parse(source)).is_err(), "{} should be rejected", source);
+            assert!(analyzer.errors.iter().any(|error| matches!(error.error, SemanticErrorType::SymbolError(SymbolError::SymbolNotFound(_)))), "{:?}", analyzer.errors);
+        }
+    }
+
     // #[test]
     // fn test_function_declaration_analysis() {
     //     let mut analyzer = SemanticAnalyzer::new();
@@ -378,6 +775,7 @@ mod tests {
             file: "test.rs".to_string(),
             line: 1,
             column: 1,
+            span: Span::dummy(),
         };
 
         // Enregistrer un emprunt de lecture
@@ -418,6 +816,7 @@ mod tests {
             file: "test.rs".to_string(),
             line: 1,
             column: 1,
+            span: Span::dummy(),
         };
 
         // Enregistrer un emprunt mutable
@@ -459,6 +858,7 @@ mod tests {
             file: "test.rs".to_string(),
             line: 1,
             column: 1,
+            span: Span::dummy(),
         };
 
         // Enregistrer un emprunt dans le scope enfant